@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate bencher;
+extern crate hey_listen;
+
+use bencher::Bencher;
+use hey_listen::{EventDispatcher, PriorityEventDispatcher, SyncDispatcherRequest,
+                 TopicDispatcher};
+
+const LISTENERS: usize = 100;
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum Event {
+    Variant,
+}
+
+fn dispatch_to_fns(bench: &mut Bencher) {
+    let mut dispatcher: EventDispatcher<Event, SyncDispatcherRequest> = EventDispatcher::default();
+
+    for _ in 0..LISTENERS {
+        dispatcher.add_fn(Event::Variant, Box::new(|_| None));
+    }
+
+    bench.iter(|| dispatcher.dispatch_event(&Event::Variant));
+}
+
+fn dispatch_to_global_fns(bench: &mut Bencher) {
+    let mut dispatcher: EventDispatcher<Event, SyncDispatcherRequest> = EventDispatcher::default();
+
+    for _ in 0..LISTENERS {
+        dispatcher.add_global_fn(Box::new(|_| None));
+    }
+
+    bench.iter(|| dispatcher.dispatch_event(&Event::Variant));
+}
+
+fn dispatch_to_prioritised_fns(bench: &mut Bencher) {
+    let mut dispatcher: PriorityEventDispatcher<usize, Event, SyncDispatcherRequest> =
+        PriorityEventDispatcher::default();
+
+    for priority in 0..LISTENERS {
+        dispatcher.add_fn(Event::Variant, Box::new(|_| None), priority % 10);
+    }
+
+    bench.iter(|| dispatcher.dispatch_event(&Event::Variant));
+}
+
+fn dispatch_to_topic_fns(bench: &mut Bencher) {
+    let mut dispatcher: TopicDispatcher<SyncDispatcherRequest> = TopicDispatcher::default();
+
+    for _ in 0..LISTENERS {
+        dispatcher
+            .add_fn("sensors/+/temperature", Box::new(|_| None))
+            .unwrap();
+    }
+
+    bench.iter(|| dispatcher.dispatch_event("sensors/kitchen/temperature"));
+}
+
+benchmark_group!(
+    benches,
+    dispatch_to_fns,
+    dispatch_to_global_fns,
+    dispatch_to_prioritised_fns,
+    dispatch_to_topic_fns
+);
+benchmark_main!(benches);
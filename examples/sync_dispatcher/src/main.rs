@@ -12,7 +12,7 @@
 //! closures can also become a listener.
 
 extern crate hey_listen;
-// `hey_listen` uses parking_lot's `Mutex` instead of `std::sync::Mutex`.
+// `hey_listen` accepts every lock implementing `ListenerLock`, e.g. parking_lot's `Mutex`.
 extern crate parking_lot;
 
 use hey_listen::{EventDispatcher, Listener, SyncDispatcherRequest};
@@ -0,0 +1,455 @@
+//! Dispatching follow-up events from inside listeners.
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Weak};
+
+use failure::Fail;
+
+use super::{ExecuteRequestsResult, ListenerId};
+use envelope::{CorrelationId, Envelope};
+use trace::{TracedListener, Tracer};
+
+/// The maximum depth of nested dispatches, unless configured otherwise.
+pub(crate) const DEFAULT_MAX_DISPATCH_DEPTH: usize = 32;
+
+/// Errors for follow-up events rejected by a [`DispatchContext`],
+/// or for event hierarchies cut off while dispatching.
+///
+/// [`DispatchContext`]: struct.DispatchContext.html
+#[derive(Debug, PartialEq)]
+pub enum DispatchError {
+    DepthExceeded(usize),
+    Cycle,
+}
+
+impl fmt::Display for DispatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DispatchError::DepthExceeded(depth) => write!(
+                f,
+                "Dispatching the event exceeds the maximum depth of {}.",
+                depth
+            ),
+            DispatchError::Cycle => write!(f, "Dispatching the event would cause a cycle."),
+        }
+    }
+}
+
+impl Fail for DispatchError {}
+
+/// Passed to listeners while they receive an event, allowing them to
+/// dispatch follow-up events on the same dispatcher.
+///
+/// Follow-up events are either dispatched immediately, meaning
+/// once the emitting listener returns and before the current event is
+/// dispatched to the next listener, or queued until the current event
+/// has been dispatched to all listeners.
+///
+/// Follow-up events nest at most up to the dispatcher's maximum depth.
+/// A listener must not dispatch an event equal to one it dispatched before,
+/// if the latter is still being dispatched, as this would cause a cycle.
+/// Therefore, a listener may dispatch the event it received once, but not
+/// once more when receiving the dispatched event.
+pub struct DispatchContext<'a, T: 'a> {
+    causes: &'a [Cause<T>],
+    /// The listener receiving this context.
+    causer: Causer,
+    max_depth: usize,
+    immediate: Vec<T>,
+    queued: Vec<T>,
+}
+
+impl<'a, T> DispatchContext<'a, T>
+where
+    T: PartialEq + Clone,
+{
+    pub(crate) fn new(causes: &'a [Cause<T>], causer: Causer, max_depth: usize) -> Self {
+        DispatchContext {
+            causes,
+            causer,
+            max_depth,
+            immediate: vec![],
+            queued: vec![],
+        }
+    }
+
+    /// Returns the event currently being dispatched followed by
+    /// all events it has been caused by, the top-level event being last.
+    pub fn causes(&self) -> impl Iterator<Item = &T> {
+        self.causes.iter().rev().map(|cause| cause.envelope.event())
+    }
+
+    /// Returns the [`Envelope`] of the event currently being dispatched.
+    ///
+    /// [`Envelope`]: struct.Envelope.html
+    pub fn envelope(&self) -> &Envelope<T> {
+        &self.causes
+            .last()
+            .expect("Contexts are created while dispatching an event.")
+            .envelope
+    }
+
+    /// Returns how deep the current event is nested,
+    /// `0` for an event dispatched by the dispatcher's owner.
+    pub fn depth(&self) -> usize {
+        self.causes.len().saturating_sub(1)
+    }
+
+    /// Dispatches `event` once the current listener returns,
+    /// before the current event is passed to the next listener.
+    ///
+    /// Returns [`DispatchError`] if `event` would exceed the maximum depth
+    /// or cause a cycle, as described at [`DispatchContext`].
+    ///
+    /// [`DispatchError`]: enum.DispatchError.html
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    pub fn dispatch(&mut self, event: T) -> Result<(), DispatchError> {
+        self.check(&event)?;
+        self.immediate.push(event);
+
+        Ok(())
+    }
+
+    /// Dispatches `event` once the current event has been dispatched
+    /// to all listeners.
+    ///
+    /// Returns [`DispatchError`] if `event` would exceed the maximum depth
+    /// or cause a cycle, as described at [`DispatchContext`].
+    ///
+    /// [`DispatchError`]: enum.DispatchError.html
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    pub fn enqueue(&mut self, event: T) -> Result<(), DispatchError> {
+        self.check(&event)?;
+        self.queued.push(event);
+
+        Ok(())
+    }
+
+    fn check(&self, event: &T) -> Result<(), DispatchError> {
+        check_follow_up(self.causes, self.max_depth, event, self.causer)
+    }
+}
+
+/// Identifies the listener a follow-up event has been dispatched by.
+///
+/// Cycles are detected by the dispatching listener, hence events whose
+/// `PartialEq` only compares their variant do not cause false positives
+/// when dispatched by different listeners.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Causer {
+    /// A listener the dispatcher holds a `Weak`-reference to,
+    /// identified by the address of its `Arc`.
+    Referenced(usize),
+    Owned(ListenerId),
+    /// A closure, identified by the address of its `Arc`.
+    Fn(usize),
+}
+
+impl Causer {
+    pub(crate) fn referenced<L: ?Sized>(listener: &Weak<L>) -> Self {
+        Causer::Referenced(Weak::as_ptr(listener) as *const u8 as usize)
+    }
+
+    pub(crate) fn function<F: ?Sized>(function: &Arc<F>) -> Self {
+        Causer::Fn(Arc::as_ptr(function) as *const u8 as usize)
+    }
+
+    /// Returns how a [`Tracer`] records the listener.
+    ///
+    /// [`Tracer`]: ../trace/struct.Tracer.html
+    fn traced(self) -> TracedListener {
+        match self {
+            Causer::Referenced(address) => TracedListener::Referenced(address),
+            Causer::Owned(id) => TracedListener::Owned(id),
+            Causer::Fn(_) => TracedListener::Fn,
+        }
+    }
+}
+
+/// An event being dispatched and the listener it has been dispatched by,
+/// `None` for an event dispatched by the dispatcher's owner.
+#[derive(Clone)]
+pub(crate) struct Cause<T> {
+    pub(crate) envelope: Envelope<T>,
+    causer: Option<Causer>,
+}
+
+/// Checks whether `causer` may dispatch `event`, caused by `causes`.
+fn check_follow_up<T: PartialEq>(
+    causes: &[Cause<T>],
+    max_depth: usize,
+    event: &T,
+    causer: Causer,
+) -> Result<(), DispatchError> {
+    if causes.len() > max_depth {
+        Err(DispatchError::DepthExceeded(max_depth))
+    } else if causes
+        .iter()
+        .any(|cause| cause.causer == Some(causer) && cause.envelope.event() == event)
+    {
+        Err(DispatchError::Cycle)
+    } else {
+        Ok(())
+    }
+}
+
+/// An event queued by a [`DispatchContext`], remembering its causes
+/// and the correlation it belongs to.
+///
+/// [`DispatchContext`]: struct.DispatchContext.html
+pub(crate) struct QueuedEvent<T> {
+    pub(crate) event: T,
+    pub(crate) causes: Vec<Cause<T>>,
+    /// The listener it has been queued by.
+    pub(crate) causer: Option<Causer>,
+    pub(crate) correlation_id: CorrelationId,
+    /// The traced event and listener it has been queued by, if traced.
+    pub(crate) caused_by: Option<(usize, TracedListener)>,
+}
+
+impl<T> QueuedEvent<T> {
+    /// Creates a queued event without any causes, starting a new correlation.
+    pub(crate) fn new(event: T) -> Self {
+        QueuedEvent {
+            event,
+            causes: vec![],
+            causer: None,
+            correlation_id: CorrelationId::new(),
+            caused_by: None,
+        }
+    }
+}
+
+/// State of a dispatch, shared by all events it causes.
+pub(crate) struct DispatchChain<T> {
+    /// The events currently being dispatched,
+    /// the top-level event being first.
+    pub(crate) causes: Vec<Cause<T>>,
+    pub(crate) queue: VecDeque<QueuedEvent<T>>,
+    pub(crate) max_depth: usize,
+    /// The correlation of the events currently being dispatched.
+    pub(crate) correlation_id: CorrelationId,
+    /// Records the dispatched events, if tracing is enabled.
+    pub(crate) tracer: Option<Tracer<T>>,
+    /// The listener dispatching the next entered event.
+    causer: Option<Causer>,
+    /// The cause of the next entered event, if traced.
+    caused_by: Option<(usize, TracedListener)>,
+    /// Whether a listener stopped the propagation of the top-level event.
+    stopped: bool,
+}
+
+impl<T> DispatchChain<T>
+where
+    T: PartialEq + Clone,
+{
+    pub(crate) fn new(max_depth: usize, correlation_id: CorrelationId) -> Self {
+        DispatchChain {
+            causes: vec![],
+            queue: VecDeque::new(),
+            max_depth,
+            correlation_id,
+            tracer: None,
+            causer: None,
+            caused_by: None,
+            stopped: false,
+        }
+    }
+
+    /// Starts dispatching the event wrapped by `envelope`.
+    pub(crate) fn enter(&mut self, envelope: Envelope<T>) {
+        if let Some(ref mut tracer) = self.tracer {
+            tracer.enter(envelope.event(), self.caused_by.take());
+        }
+
+        self.causes.push(Cause {
+            envelope,
+            causer: self.causer.take(),
+        });
+    }
+
+    /// Finishes dispatching the current event.
+    pub(crate) fn leave(&mut self) {
+        if let Some(ref mut tracer) = self.tracer {
+            tracer.leave();
+        }
+
+        self.causes.pop();
+    }
+
+    /// Records that a listener stopped the propagation of the current event,
+    /// reported by [`dispatch_chain`] for the top-level event.
+    ///
+    /// [`dispatch_chain`]: fn.dispatch_chain.html
+    pub(crate) fn stop_propagation(&mut self) {
+        if self.causes.len() == 1 {
+            self.stopped = true;
+        }
+    }
+
+    /// Records `listener` receiving the current event, returns the cause
+    /// of follow-up events dispatched by it if traced.
+    fn record(&mut self, listener: Causer) -> Option<(usize, TracedListener)> {
+        self.tracer
+            .as_mut()
+            .and_then(|tracer| tracer.record(listener.traced()))
+    }
+
+    /// Calls `listener` via `function` with a fresh [`DispatchContext`],
+    /// queues the context's queued events and returns its
+    /// immediate events alongside `function`'s request.
+    ///
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    pub(crate) fn call<R, F>(&mut self, listener: Causer, function: F) -> (R, Vec<T>)
+    where
+        F: FnOnce(&mut DispatchContext<T>) -> R,
+    {
+        let caused_by = self.record(listener);
+        let (request, immediate, queued) = {
+            let mut context = DispatchContext::new(&self.causes, listener, self.max_depth);
+            let request = function(&mut context);
+
+            (request, context.immediate, context.queued)
+        };
+
+        for event in queued {
+            self.queue.push_back(QueuedEvent {
+                event,
+                causes: self.causes.clone(),
+                causer: Some(listener),
+                correlation_id: self.correlation_id,
+                caused_by,
+            });
+        }
+
+        (request, immediate)
+    }
+
+    /// Checks whether `listener` may queue `event` via [`enqueue`].
+    ///
+    /// [`enqueue`]: #method.enqueue
+    pub(crate) fn check(&self, event: &T, listener: Causer) -> Result<(), DispatchError> {
+        check_follow_up(&self.causes, self.max_depth, event, listener)
+    }
+
+    /// Queues `event` as if it has been queued by `listener`
+    /// via [`DispatchContext::enqueue`], without checking it.
+    ///
+    /// [`DispatchContext::enqueue`]: struct.DispatchContext.html#method.enqueue
+    pub(crate) fn enqueue(&mut self, event: T, listener: Causer) {
+        let caused_by = self.tracer
+            .as_ref()
+            .and_then(|tracer| tracer.current())
+            .map(|id| (id, listener.traced()));
+        self.queue.push_back(QueuedEvent {
+            event,
+            causes: self.causes.clone(),
+            causer: Some(listener),
+            correlation_id: self.correlation_id,
+            caused_by,
+        });
+    }
+}
+
+/// Implemented by dispatchers supporting follow-up events.
+pub(crate) trait NestedDispatch<T> {
+    /// Dispatches `event_identifier` to all its listeners,
+    /// `chain` contains all events causing `event_identifier`.
+    fn dispatch_nested(&mut self, event_identifier: &T, chain: &mut DispatchChain<T>);
+
+    /// Reports an `event` emitted via an `Emit`-request
+    /// that has been rejected due to `error`.
+    fn report_emit_error(&self, event: &T, error: &DispatchError);
+}
+
+/// Dispatches `event_identifier` and all follow-up events it causes,
+/// all of them belonging to `correlation_id`, recorded by `tracer`.
+///
+/// Returns whether a listener stopped the propagation of `event_identifier`
+/// alongside `tracer`, once all events have been dispatched.
+pub(crate) fn dispatch_chain<T, D>(
+    dispatcher: &mut D,
+    event_identifier: &T,
+    max_depth: usize,
+    correlation_id: CorrelationId,
+    tracer: Option<Tracer<T>>,
+) -> (ExecuteRequestsResult, Option<Tracer<T>>)
+where
+    T: PartialEq + Clone,
+    D: NestedDispatch<T>,
+{
+    let mut chain = DispatchChain::new(max_depth, correlation_id);
+    chain.tracer = tracer;
+    dispatcher.dispatch_nested(event_identifier, &mut chain);
+
+    let result = if chain.stopped {
+        ExecuteRequestsResult::Stopped
+    } else {
+        ExecuteRequestsResult::Finished
+    };
+    let queue = ::std::mem::take(&mut chain.queue);
+    let (_, tracer) = flush_queue(dispatcher, queue, max_depth, usize::MAX, chain.tracer);
+
+    (result, tracer)
+}
+
+/// Dispatches the events of `queue` in FIFO order, appending events
+/// queued by listeners, until `queue` is empty or `limit` events have
+/// been taken from `queue`.
+///
+/// Returns all events left in `queue` alongside `tracer`.
+pub(crate) fn flush_queue<T, D>(
+    dispatcher: &mut D,
+    queue: VecDeque<QueuedEvent<T>>,
+    max_depth: usize,
+    limit: usize,
+    tracer: Option<Tracer<T>>,
+) -> (VecDeque<QueuedEvent<T>>, Option<Tracer<T>>)
+where
+    T: PartialEq + Clone,
+    D: NestedDispatch<T>,
+{
+    let mut chain = DispatchChain::new(max_depth, CorrelationId::from(0));
+    chain.queue = queue;
+    chain.tracer = tracer;
+
+    for _ in 0..limit {
+        match chain.queue.pop_front() {
+            Some(queued) => {
+                chain.causes = queued.causes;
+                chain.causer = queued.causer;
+                chain.correlation_id = queued.correlation_id;
+                chain.caused_by = queued.caused_by;
+                dispatcher.dispatch_nested(&queued.event, &mut chain);
+            }
+            None => break,
+        }
+    }
+
+    (chain.queue, chain.tracer)
+}
+
+/// Calls `listener` via `function` and immediately dispatches
+/// all follow-up events it requested.
+pub(crate) fn call_listener<T, D, R, F>(
+    dispatcher: &mut D,
+    chain: &mut DispatchChain<T>,
+    listener: Causer,
+    function: F,
+) -> R
+where
+    T: PartialEq + Clone,
+    D: NestedDispatch<T>,
+    F: FnOnce(&mut DispatchContext<T>) -> R,
+{
+    let current = chain.tracer.as_ref().and_then(Tracer::current);
+    let (request, immediate) = chain.call(listener, function);
+
+    for event in immediate {
+        chain.causer = Some(listener);
+        chain.caused_by = current.map(|id| (id, listener.traced()));
+        dispatcher.dispatch_nested(&event, chain);
+    }
+
+    request
+}
@@ -0,0 +1,50 @@
+//! Keeping events no listener has been registered for.
+use std::collections::VecDeque;
+
+/// A bounded buffer of unhandled events, dropping the oldest event
+/// once full.
+pub(crate) struct DeadLetters<T> {
+    events: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T> DeadLetters<T> {
+    /// Creates a buffer keeping no events until a capacity is set.
+    pub(crate) fn new() -> Self {
+        DeadLetters {
+            events: VecDeque::new(),
+            capacity: 0,
+        }
+    }
+
+    /// Sets the maximum amount of kept events, dropping the oldest
+    /// events exceeding `capacity`.
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+
+        while self.events.len() > capacity {
+            self.events.pop_front();
+        }
+    }
+
+    /// Keeps a clone of `event`, dropping the oldest event if the buffer is full.
+    pub(crate) fn push(&mut self, event: &T)
+    where
+        T: Clone,
+    {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+
+        self.events.push_back(event.clone());
+    }
+
+    /// Removes all kept events, the oldest event being first.
+    pub(crate) fn take(&mut self) -> Vec<T> {
+        self.events.drain(..).collect()
+    }
+}
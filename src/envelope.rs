@@ -0,0 +1,300 @@
+//! Events wrapped in envelopes carrying metadata about their dispatch.
+use std::hash::Hash;
+use std::sync::{Arc, Weak};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
+
+use super::{DispatchContext, ListenerLock, LockedListener, SyncDispatcherRequest,
+            UnsubscribeReason};
+
+/// Source of [`CorrelationId`]s for dispatches not continuing a correlation.
+///
+/// [`CorrelationId`]: struct.CorrelationId.html
+static NEXT_CORRELATION_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Correlates an event with all follow-up events it causes.
+///
+/// Each dispatch starts a new correlation, unless it continues an
+/// existing one via a dispatcher's `dispatch_correlated`-method,
+/// e.g. with a correlation received from another service.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct CorrelationId(u64);
+
+impl CorrelationId {
+    /// Creates a new correlation, unique among all correlations
+    /// created by this process.
+    pub fn new() -> Self {
+        CorrelationId(NEXT_CORRELATION_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Returns the raw value, e.g. to pass it on to another service.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Default for CorrelationId {
+    fn default() -> Self {
+        CorrelationId::new()
+    }
+}
+
+impl From<u64> for CorrelationId {
+    fn from(value: u64) -> Self {
+        CorrelationId(value)
+    }
+}
+
+/// Provides the timestamps of [`Envelope`]s.
+///
+/// It is implemented for all `Fn() -> SystemTime`, dispatchers use
+/// [`SystemClock`] unless configured otherwise.
+///
+/// [`Envelope`]: struct.Envelope.html
+/// [`SystemClock`]: struct.SystemClock.html
+pub trait Clock: Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> SystemTime;
+}
+
+impl<F> Clock for F
+where
+    F: Fn() -> SystemTime + Send + Sync,
+{
+    fn now(&self) -> SystemTime {
+        self()
+    }
+}
+
+/// A [`Clock`] returning the system's time.
+///
+/// [`Clock`]: trait.Clock.html
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Wraps a dispatched event, carrying metadata about its dispatch.
+///
+/// Sync dispatchers wrap every dispatched event, including follow-up
+/// events, which share the [`CorrelationId`] of the event causing them.
+/// The `ParallelEventDispatcher` does not wrap events.
+/// Envelopes are received by [`EnvelopeListener`]s, closures added via
+/// `add_envelope_fn` and via [`DispatchContext::envelope`].
+///
+/// # Examples
+///
+/// ```rust
+/// extern crate hey_listen;
+///
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+///
+/// use hey_listen::{DispatchContext, Envelope, EventDispatcher, SyncDispatcherRequest};
+///
+/// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Ordered,
+///     Shipped,
+/// }
+///
+/// fn main() {
+///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+///
+///     dispatcher.clock(|| UNIX_EPOCH + Duration::from_secs(42));
+///     dispatcher.source_id("shop");
+///
+///     dispatcher.add_context_fn(Event::Ordered, Box::new(|_: &Event, context: &mut DispatchContext<Event>| {
+///         context.dispatch(Event::Shipped).expect("Shipped causes no cycle.");
+///
+///         None
+///     }));
+///
+///     dispatcher.add_envelope_fn(Event::Shipped, Box::new(|envelope: &Envelope<Event>| {
+///         assert_eq!(envelope.sequence(), 1);
+///         assert_eq!(envelope.source_id(), Some("shop"));
+///         assert_eq!(envelope.timestamp(), UNIX_EPOCH + Duration::from_secs(42));
+///         println!("Shipped in correlation {:?}.", envelope.correlation_id());
+///
+///         None
+///     }));
+///
+///     dispatcher.dispatch_event(&Event::Ordered);
+/// }
+/// ```
+///
+/// [`CorrelationId`]: struct.CorrelationId.html
+/// [`EnvelopeListener`]: trait.EnvelopeListener.html
+/// [`DispatchContext::envelope`]: struct.DispatchContext.html#method.envelope
+#[derive(Clone, Debug)]
+pub struct Envelope<T> {
+    event: T,
+    sequence: u64,
+    timestamp: SystemTime,
+    source_id: Option<Arc<str>>,
+    correlation_id: CorrelationId,
+}
+
+impl<T> Envelope<T> {
+    /// Returns the wrapped event.
+    pub fn event(&self) -> &T {
+        &self.event
+    }
+
+    /// Returns the position of the event among all events dispatched
+    /// by its dispatcher, starting at `0`.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Returns when the event has been dispatched,
+    /// as reported by the dispatcher's [`Clock`].
+    ///
+    /// [`Clock`]: trait.Clock.html
+    pub fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    /// Returns the ID of the dispatcher's source, if set.
+    pub fn source_id(&self) -> Option<&str> {
+        self.source_id.as_deref()
+    }
+
+    /// Returns the correlation the event belongs to.
+    pub fn correlation_id(&self) -> CorrelationId {
+        self.correlation_id
+    }
+}
+
+/// Wraps the events of a dispatcher in [`Envelope`]s.
+///
+/// [`Envelope`]: struct.Envelope.html
+pub(crate) struct Stamper {
+    next_sequence: AtomicU64,
+    clock: Box<Clock>,
+    source_id: Option<Arc<str>>,
+}
+
+impl Stamper {
+    pub(crate) fn new() -> Self {
+        Stamper {
+            next_sequence: AtomicU64::new(0),
+            clock: Box::new(SystemClock),
+            source_id: None,
+        }
+    }
+
+    pub(crate) fn set_clock(&mut self, clock: Box<Clock>) {
+        self.clock = clock;
+    }
+
+    pub(crate) fn set_source_id(&mut self, source_id: String) {
+        self.source_id = Some(Arc::from(source_id));
+    }
+
+    /// Wraps a clone of `event` belonging to `correlation_id`,
+    /// taking the next sequence number.
+    pub(crate) fn stamp<T: Clone>(&self, event: &T, correlation_id: CorrelationId) -> Envelope<T> {
+        Envelope {
+            event: event.clone(),
+            sequence: self.next_sequence.fetch_add(1, Ordering::Relaxed),
+            timestamp: self.clock.now(),
+            source_id: self.source_id.clone(),
+            correlation_id,
+        }
+    }
+}
+
+/// Receives dispatched events wrapped in their [`Envelope`].
+/// `R` being the request type, translated by the dispatcher's
+/// [`RequestHandler`].
+///
+/// Only sync dispatchers support envelope listeners,
+/// see [`Envelope`].
+///
+/// [`Envelope`]: struct.Envelope.html
+/// [`RequestHandler`]: trait.RequestHandler.html
+pub trait EnvelopeListener<T, R = SyncDispatcherRequest>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// This function will be called once a listened event-type `T` has
+    /// been dispatched, passing its envelope.
+    fn on_envelope(&mut self, envelope: &Envelope<T>) -> Option<R>;
+
+    /// See [`Listener::on_subscribe`].
+    ///
+    /// [`Listener::on_subscribe`]: trait.Listener.html#method.on_subscribe
+    fn on_subscribe(&mut self, _event: &T) {}
+
+    /// See [`Listener::on_unsubscribe`].
+    ///
+    /// [`Listener::on_unsubscribe`]: trait.Listener.html#method.on_unsubscribe
+    fn on_unsubscribe(&mut self, _event: &T, _reason: UnsubscribeReason) {}
+
+    /// See [`Listener::on_dispatcher_dropped`].
+    ///
+    /// [`Listener::on_dispatcher_dropped`]: trait.Listener.html#method.on_dispatcher_dropped
+    fn on_dispatcher_dropped(&mut self) {}
+}
+
+/// Holds a [`Weak`]-reference to the lock of an [`EnvelopeListener`],
+/// passing it the envelope of each dispatched event.
+///
+/// Once the listener has been dropped, the dispatcher removes it.
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+/// [`EnvelopeListener`]: trait.EnvelopeListener.html
+pub(crate) struct EnvelopedListener<L> {
+    listener: Weak<L>,
+}
+
+impl<L: ListenerLock> EnvelopedListener<L> {
+    pub(crate) fn new(listener: &Arc<L>) -> Self {
+        EnvelopedListener {
+            listener: Arc::downgrade(listener),
+        }
+    }
+
+    /// Locks the listener and passes it to `function`,
+    /// unless the listener has been dropped.
+    fn with_listener<O, G>(&self, function: G) -> Option<O>
+    where
+        G: FnOnce(&mut L::Listener) -> O,
+    {
+        self.listener
+            .upgrade()
+            .map(|listener| listener.with_listener(function))
+    }
+}
+
+impl<T, R, L> LockedListener<T, R> for EnvelopedListener<L>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    L: ListenerLock,
+    L::Listener: EnvelopeListener<T, R>,
+{
+    fn on_event(&self, _event: &T, context: &mut DispatchContext<T>) -> Option<R> {
+        self.with_listener(|listener| listener.on_envelope(context.envelope()))
+            .and_then(|request| request)
+    }
+
+    fn on_subscribe(&self, event: &T) {
+        self.with_listener(|listener| listener.on_subscribe(event));
+    }
+
+    fn on_unsubscribe(&self, event: &T, reason: UnsubscribeReason) {
+        self.with_listener(|listener| listener.on_unsubscribe(event, reason));
+    }
+
+    fn on_dispatcher_dropped(&self) {
+        self.with_listener(|listener| listener.on_dispatcher_dropped());
+    }
+
+    fn is_dead(&self) -> bool {
+        self.listener.upgrade().is_none()
+    }
+}
@@ -0,0 +1,135 @@
+//! Listeners only receiving events accepted by a predicate.
+use std::hash::Hash;
+use std::sync::{Arc, Weak};
+
+use super::{DispatchContext, Listener, ListenerLock, LockedListener, ParallelListener,
+            ParallelLockedListener, UnsubscribeReason};
+use lock::{call_accepting, check_accepts};
+
+/// Holds a [`Weak`]-reference to a listener's lock, evaluating `predicate`
+/// before locking it, hence rejected events are dispatched without locking.
+///
+/// Once the listener has been dropped, the dispatcher removes it.
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+pub(crate) struct FilteredListener<L, F> {
+    listener: Weak<L>,
+    predicate: F,
+}
+
+impl<L, F> FilteredListener<L, F>
+where
+    L: ListenerLock,
+{
+    pub(crate) fn new(listener: &Arc<L>, predicate: F) -> Self {
+        FilteredListener {
+            listener: Arc::downgrade(listener),
+            predicate,
+        }
+    }
+
+    /// Locks the listener and passes it to `function`,
+    /// unless the listener has been dropped.
+    fn with_listener<O, G>(&self, function: G) -> Option<O>
+    where
+        G: FnOnce(&mut L::Listener) -> O,
+    {
+        self.listener
+            .upgrade()
+            .map(|listener| listener.with_listener(function))
+    }
+
+    /// Calls `function` with the listener if `event` is accepted by
+    /// `predicate` and `accepts`, as done by `call_accepting`,
+    /// unless the listener has been dropped.
+    fn call<T, O, A, G>(&self, event: &T, accepts: A, function: G) -> Option<O>
+    where
+        F: Fn(&T) -> bool,
+        A: Fn(&L::Listener) -> bool,
+        G: FnOnce(&mut L::Listener) -> Option<O>,
+    {
+        if !(self.predicate)(event) {
+            return None;
+        }
+
+        self.listener
+            .upgrade()
+            .and_then(|listener| call_accepting(&*listener, accepts, function))
+    }
+}
+
+impl<T, R, L, F> LockedListener<T, R> for FilteredListener<L, F>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    L: ListenerLock,
+    L::Listener: Listener<T, R>,
+    F: Fn(&T) -> bool + Send + Sync,
+{
+    fn on_event(&self, event: &T, context: &mut DispatchContext<T>) -> Option<R> {
+        self.call(
+            event,
+            |listener| listener.accepts(event),
+            |listener| listener.on_event_with_context(event, context),
+        )
+    }
+
+    fn on_subscribe(&self, event: &T) {
+        self.with_listener(|listener| listener.on_subscribe(event));
+    }
+
+    fn on_unsubscribe(&self, event: &T, reason: UnsubscribeReason) {
+        self.with_listener(|listener| listener.on_unsubscribe(event, reason));
+    }
+
+    fn on_dispatcher_dropped(&self) {
+        self.with_listener(|listener| listener.on_dispatcher_dropped());
+    }
+
+    fn is_dead(&self) -> bool {
+        self.listener.upgrade().is_none()
+    }
+}
+
+impl<T, R, L, F> ParallelLockedListener<T, R> for FilteredListener<L, F>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    L: ListenerLock,
+    L::Listener: ParallelListener<T, R>,
+    F: Fn(&T) -> bool + Send + Sync,
+{
+    fn on_event(&self, event: &T) -> Option<R> {
+        self.call(
+            event,
+            |listener| listener.accepts(event),
+            |listener| listener.on_event(event),
+        )
+    }
+
+    fn accepts(&self, event: &T) -> bool {
+        if !(self.predicate)(event) {
+            return false;
+        }
+
+        // Dead listeners are accepted, hence they get reported and removed.
+        match self.listener.upgrade() {
+            Some(listener) => check_accepts(&*listener, |listener| listener.accepts(event)),
+            None => true,
+        }
+    }
+
+    fn on_subscribe(&self, event: &T) {
+        self.with_listener(|listener| listener.on_subscribe(event));
+    }
+
+    fn on_unsubscribe(&self, event: &T, reason: UnsubscribeReason) {
+        self.with_listener(|listener| listener.on_unsubscribe(event, reason));
+    }
+
+    fn on_dispatcher_dropped(&self) {
+        self.with_listener(|listener| listener.on_dispatcher_dropped());
+    }
+
+    fn is_dead(&self) -> bool {
+        self.listener.upgrade().is_none()
+    }
+}
@@ -0,0 +1,136 @@
+//! Events organised in categories, dispatched to listeners of
+//! each category an event belongs to.
+use std::borrow::Cow;
+
+use context::DispatchError;
+
+/// Lets an event report the key of the category it belongs to,
+/// forming a hierarchy of event-keys.
+///
+/// Once enabled via a dispatcher's `use_event_hierarchy`-method, a
+/// dispatched event is received by the listeners of its own key first,
+/// followed by the listeners of each ancestor, e.g.
+/// `Input::Key(Key::Down)` → `Input::Key(Key::Any)` → `Input::Any`.
+/// Listeners of an ancestor receive the dispatched event itself.
+///
+/// # Examples
+///
+/// ```rust
+/// extern crate hey_listen;
+///
+/// use hey_listen::{EventDispatcher, EventHierarchy};
+///
+/// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// enum Input {
+///     Any,
+///     KeyDown,
+///     KeyUp,
+/// }
+///
+/// impl EventHierarchy for Input {
+///     fn parent(&self) -> Option<Input> {
+///         match *self {
+///             Input::Any => None,
+///             Input::KeyDown | Input::KeyUp => Some(Input::Any),
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     let mut dispatcher: EventDispatcher<Input> = EventDispatcher::default();
+///     dispatcher.use_event_hierarchy();
+///
+///     dispatcher.add_fn(Input::Any, Box::new(|event: &Input| {
+///         println!("Received {:?}.", event);
+///
+///         None
+///     }));
+///
+///     dispatcher.dispatch_event(&Input::KeyDown);
+/// }
+/// ```
+pub trait EventHierarchy: Sized {
+    /// Returns the key of the category `self` belongs to,
+    /// `None` if `self` is a root of the hierarchy.
+    ///
+    /// **Note**: Following parents should end at a root eventually.
+    /// Dispatchers stop following parents once a key repeats or after
+    /// their maximum dispatch depth of ancestors, and report a
+    /// [`DispatchError`] to their emit-error handler, if any.
+    ///
+    /// [`DispatchError`]: enum.DispatchError.html
+    fn parent(&self) -> Option<Self>;
+}
+
+/// Returns the parent of an event, as implemented by [`EventHierarchy`].
+///
+/// [`EventHierarchy`]: trait.EventHierarchy.html
+pub(crate) type ParentFn<T> = fn(&T) -> Option<T>;
+
+/// Iterates over an event followed by its ancestors,
+/// the most specific key being first.
+///
+/// Without a [`ParentFn`], only the event itself is yielded.
+/// Stops once a key repeats or after `max_ancestors` ancestors,
+/// recording the [`DispatchError`] taken via [`take_error`].
+///
+/// [`ParentFn`]: type.ParentFn.html
+/// [`DispatchError`]: enum.DispatchError.html
+/// [`take_error`]: #method.take_error
+pub(crate) struct Lineage<'a, T: 'a + Clone> {
+    event: &'a T,
+    next: Option<Cow<'a, T>>,
+    parent: Option<ParentFn<T>>,
+    /// The ancestors yielded so far.
+    ancestors: Vec<T>,
+    max_ancestors: usize,
+    error: Option<DispatchError>,
+}
+
+impl<'a, T: PartialEq + Clone> Lineage<'a, T> {
+    pub(crate) fn new(event: &'a T, parent: Option<ParentFn<T>>, max_ancestors: usize) -> Self {
+        Lineage {
+            event,
+            next: Some(Cow::Borrowed(event)),
+            parent,
+            ancestors: vec![],
+            max_ancestors,
+            error: None,
+        }
+    }
+
+    /// Takes the error the iteration stopped with, if any.
+    pub(crate) fn take_error(&mut self) -> Option<DispatchError> {
+        self.error.take()
+    }
+
+    /// Returns `ancestor` unless it has been yielded before or
+    /// exceeds the maximum number of ancestors.
+    fn check(&mut self, ancestor: T) -> Option<T> {
+        if *self.event == ancestor || self.ancestors.contains(&ancestor) {
+            self.error = Some(DispatchError::Cycle);
+        } else if self.ancestors.len() >= self.max_ancestors {
+            self.error = Some(DispatchError::DepthExceeded(self.max_ancestors));
+        } else {
+            self.ancestors.push(ancestor.clone());
+
+            return Some(ancestor);
+        }
+
+        None
+    }
+}
+
+impl<'a, T: PartialEq + Clone> Iterator for Lineage<'a, T> {
+    type Item = Cow<'a, T>;
+
+    fn next(&mut self) -> Option<Cow<'a, T>> {
+        let current = self.next.take()?;
+        self.next = self.parent
+            .and_then(|parent| parent(&current))
+            .and_then(|ancestor| self.check(ancestor))
+            .map(Cow::Owned);
+
+        Some(current)
+    }
+}
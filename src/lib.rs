@@ -11,16 +11,20 @@
 //! ```toml
 //! [dependencies]
 //! hey_listen = "0.2.0"
-//! parking_lot = "^0.5"
 //! ```
 //!
 //! and this to your crate's root:
 //!
 //! ```rust,ignore
 //! extern crate hey_listen;
-//! extern crate parking_lot;
 //! ```
 //!
+//! # Locks
+//! Listeners are registered behind a lock implementing [`ListenerLock`].
+//! `std`'s `Mutex` and `RwLock` are always supported, the locks of
+//! `parking_lot` and `spin` are supported via the equally named features,
+//! `parking_lot` being enabled by default.
+//!
 //! # Example
 //! Here is a quick example on how to use the sync event-dispatcher:
 //!
@@ -54,51 +58,224 @@
 //!
 //! ```
 //! [`examples`]: https://github.com/Lakelezz/hey_listen/tree/master/examples
+//! [`ListenerLock`]: trait.ListenerLock.html
 extern crate failure;
 #[macro_use]
 extern crate failure_derive;
-#[cfg(feature = "default")]
+#[cfg(feature = "parking_lot")]
 extern crate parking_lot;
 extern crate rayon;
+#[cfg(feature = "spin")]
+extern crate spin;
+
+mod context;
+mod dead_letters;
+mod envelope;
+mod filtered;
+mod hierarchy;
+mod lock;
+mod range;
+mod shared_dispatcher;
+mod topic;
+mod trace;
+mod tree;
+mod veto;
+mod weak_fn;
+
+pub use context::{DispatchContext, DispatchError};
+pub use envelope::{Clock, CorrelationId, Envelope, EnvelopeListener, SystemClock};
+pub use hierarchy::EventHierarchy;
+pub use lock::ListenerLock;
+pub use shared_dispatcher::SharedEventDispatcher;
+pub use topic::{FilterError, TopicDispatcher};
+pub use trace::{Trace, TracedEvent, TracedListener};
+pub use tree::{DispatcherTree, NodeId};
+pub use veto::{Veto, VetoListener};
 
 use std::error::Error;
+use std::fmt;
 use std::sync::{Arc, Weak};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::hash::Hash;
-use std::collections::{BTreeMap, HashMap};
-#[cfg(feature = "default")]
+use std::ops::RangeBounds;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+// The dispatchers' internal `Mutex` is selected by the enabled features,
+// `parking_lot` taking precedence over `spin` and `spin` over `std`,
+// `std`'s lock being used if neither of the others is enabled.
+#[cfg(feature = "parking_lot")]
 use parking_lot::Mutex;
-#[cfg(not(feature = "default"))]
+#[cfg(all(feature = "spin", not(feature = "parking_lot")))]
+use spin::Mutex;
+#[cfg(not(any(feature = "parking_lot", feature = "spin")))]
 use std::sync::Mutex;
+use dead_letters::DeadLetters;
+use envelope::{EnvelopedListener, Stamper};
+use filtered::FilteredListener;
+use hierarchy::{Lineage, ParentFn};
+use lock::{call_accepting, check_accepts};
+use range::{EventRange, RangeIndex, RangeListeners};
+use trace::Tracer;
+use veto::VetoListeners;
+use weak_fn::WeakFn;
+use context::{call_listener, dispatch_chain, flush_queue, Causer, DispatchChain, NestedDispatch,
+              QueuedEvent, DEFAULT_MAX_DISPATCH_DEPTH};
 use rayon::{join, ThreadPool,
             prelude::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator}};
 
-type ListenerMap<T> = HashMap<T, FnsAndTraits<T>>;
-type PriorityListenerMap<P, T> = HashMap<T, BTreeMap<P, FnsAndTraits<T>>>;
-type EventFunction<T> = Vec<Box<Fn(&T) -> Option<SyncDispatcherRequest> + Send + Sync>>;
-type ParallelListenerMap<T> = HashMap<T, ParallelFnsAndTraits<T>>;
-type ParallelEventFunction<T> = Vec<Box<Fn(&T) -> Option<ParallelDispatcherRequest> + Send + Sync>>;
+type ListenerMap<T, R> = HashMap<T, Arc<FnsAndTraits<T, (), R>>>;
+type PriorityLevels<T, P, R> = BTreeMap<P, Arc<FnsAndTraits<T, P, R>>>;
+type PriorityListenerMap<P, T, R> = HashMap<T, Arc<PriorityLevels<T, P, R>>>;
+type EventFunction<T, R> = Vec<Arc<ContextFn<T, R>>>;
+type ContextFn<T, R> = Fn(&T, &mut DispatchContext<T>) -> Option<R> + Send + Sync;
+type BoxedContextFn<T, R> = Box<ContextFn<T, R>>;
+type BoxedFn<T, R> = Box<Fn(&T) -> Option<R> + Send + Sync>;
+type BoxedEnvelopeFn<T, R> = Box<Fn(&Envelope<T>) -> Option<R> + Send + Sync>;
+type BoxedVetoFn<T> = Box<Fn(&T) -> Result<(), Veto> + Send + Sync>;
+type BoxedListener<T, P> = Box<Listener<T, ListenerRequest<T, P>> + Send + Sync + 'static>;
+type SyncRequestHandler<T, P, R> = RequestHandler<R, ListenerRequest<T, P>>;
+type ParallelListenerMap<T, R> = HashMap<T, ParallelFnsAndTraits<T, R>>;
+type ParallelEventFunction<T, R> = Vec<Arc<ParallelFn<T, R>>>;
+type ParallelFn<T, R> = Fn(&T) -> Option<R> + Send + Sync;
+type ParallelRequestHandler<R> = RequestHandler<R, ParallelDispatcherRequest>;
+type DeadListenerHandler<T> = Box<Fn(&DeadListener<T>) + Send + Sync>;
+type UnhandledHandler<T> = Box<Fn(&T) + Send + Sync>;
+type EmitErrorHandler<T> = Box<Fn(&T, &DispatchError) + Send + Sync>;
+
+/// Source of unique [`ListenerId`]s, shared by all dispatchers.
+///
+/// [`ListenerId`]: struct.ListenerId.html
+static NEXT_LISTENER_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Identifies a listener owned by a dispatcher.
+/// It is returned when handing a listener over to a dispatcher
+/// and can be used to remove the listener again.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct ListenerId(usize);
+
+impl ListenerId {
+    fn new() -> Self {
+        ListenerId(NEXT_LISTENER_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
 
 /// An `enum` returning a request from a listener to its `sync` event-dispatcher.
 /// A request will be processed by the event-dispatcher depending on the variant:
 ///
-/// `StopListening` will remove your listener from the event-dispatcher.
+/// `StopListening` will remove your listener from the event-dispatcher,
+/// for the currently dispatched event only.
+///
+/// `StopListeningToAll` will remove your listener from every event and
+/// every priority-level it has been registered for on the event-dispatcher.
 ///
 /// `StopPropagation` will stop dispatching of the current `Event` instance.
 /// Therefore, a listener issuing this is the last receiver.
 ///
 /// `StopListeningAndPropagation` a combination of first `StopListening`
 /// and then `StopPropagation`.
+///
+/// Listeners needing further requests, e.g. to emit events, return
+/// [`ListenerRequest`]s instead.
+///
+/// [`ListenerRequest`]: enum.ListenerRequest.html
 #[derive(Debug)]
 pub enum SyncDispatcherRequest {
     StopListening,
+    StopListeningToAll,
+    StopPropagation,
+    StopListeningAndPropagation,
+}
+
+/// An `enum` returning a request from a listener to its `sync` event-dispatcher,
+/// extending [`SyncDispatcherRequest`] by requests referring to events `T`
+/// and priority-levels `P`.
+///
+/// Listeners opt in by returning it as their request type, e.g.
+/// `Listener<T, ListenerRequest<T>>`, on a dispatcher created via
+/// `EventDispatcher<T, ListenerRequest<T>>::default()`.
+///
+/// `StopListening`, `StopListeningToAll`, `StopPropagation` and
+/// `StopListeningAndPropagation` act like in [`SyncDispatcherRequest`].
+///
+/// `Emit` will dispatch the passed event once the current event has been
+/// dispatched, like [`DispatchContext::enqueue`].
+/// An event that would exceed the maximum depth or cause a cycle is not
+/// dispatched, instead it is passed alongside its [`DispatchError`] to the
+/// handler set via `on_emit_error`.
+///
+/// `ChangePriority` will move your listener to the passed priority-level,
+/// starting with the next dispatch.
+/// Only [`PriorityEventDispatcher`] has priority-levels, other dispatchers
+/// keep the listener where it is.
+///
+/// `Replace` will remove your listener and hand the passed [`Listener`] over
+/// to the event-dispatcher instead, listening to the same event and priority-level.
+/// If your listener is owned by the dispatcher, its [`ListenerId`] identifies
+/// the new listener.
+///
+/// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
+/// [`DispatchContext::enqueue`]: struct.DispatchContext.html#method.enqueue
+/// [`DispatchError`]: enum.DispatchError.html
+/// [`PriorityEventDispatcher`]: struct.PriorityEventDispatcher.html
+/// [`Listener`]: trait.Listener.html
+/// [`ListenerId`]: struct.ListenerId.html
+pub enum ListenerRequest<T, P = ()>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    StopListening,
+    StopListeningToAll,
     StopPropagation,
     StopListeningAndPropagation,
+    Emit(T),
+    ChangePriority(P),
+    Replace(BoxedListener<T, P>),
+}
+
+impl<T, P> From<SyncDispatcherRequest> for ListenerRequest<T, P>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn from(request: SyncDispatcherRequest) -> Self {
+        match request {
+            SyncDispatcherRequest::StopListening => ListenerRequest::StopListening,
+            SyncDispatcherRequest::StopListeningToAll => ListenerRequest::StopListeningToAll,
+            SyncDispatcherRequest::StopPropagation => ListenerRequest::StopPropagation,
+            SyncDispatcherRequest::StopListeningAndPropagation => {
+                ListenerRequest::StopListeningAndPropagation
+            }
+        }
+    }
+}
+
+impl<T, P> fmt::Debug for ListenerRequest<T, P>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + fmt::Debug + 'static,
+    P: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ListenerRequest::StopListening => f.write_str("StopListening"),
+            ListenerRequest::StopListeningToAll => f.write_str("StopListeningToAll"),
+            ListenerRequest::StopPropagation => f.write_str("StopPropagation"),
+            ListenerRequest::StopListeningAndPropagation => {
+                f.write_str("StopListeningAndPropagation")
+            }
+            ListenerRequest::Emit(ref event) => f.debug_tuple("Emit").field(event).finish(),
+            ListenerRequest::ChangePriority(ref priority) => {
+                f.debug_tuple("ChangePriority").field(priority).finish()
+            }
+            ListenerRequest::Replace(_) => f.write_str("Replace(..)"),
+        }
+    }
 }
 
 /// An `enum` returning a request from a [`Listener`] to its `async` event-dispatcher.
 ///
 /// `StopListening` will remove your [`Listener`] from the
-/// event-dispatcher.
+/// event-dispatcher, for the currently dispatched event only.
+///
+/// `StopListeningToAll` will remove your [`Listener`] from every event
+/// it has been registered for on the event-dispatcher.
 ///
 /// **Note**:
 /// Opposed to `SyncDispatcherRequest` a [`Listener`] cannot
@@ -108,6 +285,178 @@ pub enum SyncDispatcherRequest {
 #[derive(Debug)]
 pub enum ParallelDispatcherRequest {
     StopListening,
+    StopListeningToAll,
+}
+
+impl ParallelDispatcherRequest {
+    /// Returns why a listener issuing `self` stops listening.
+    fn reason(&self) -> UnsubscribeReason {
+        match *self {
+            ParallelDispatcherRequest::StopListening => UnsubscribeReason::StopListening,
+            ParallelDispatcherRequest::StopListeningToAll => UnsubscribeReason::StopListeningToAll,
+        }
+    }
+}
+
+/// Reports a listener that has been dropped by its owner without
+/// unsubscribing, passed to the handler set via a dispatcher's
+/// `on_dead_listener`.
+#[derive(Debug)]
+pub struct DeadListener<'a, T: 'a> {
+    event: &'a T,
+    missed_dispatch: bool,
+}
+
+impl<'a, T> DeadListener<'a, T> {
+    /// Returns the event the dropped listener has been listening for.
+    pub fn event(&self) -> &T {
+        self.event
+    }
+
+    /// Returns whether the dropped listener has been found while
+    /// dispatching [`event`], hence it missed an event it has been
+    /// expected to receive.
+    /// Otherwise, it has been found while the dispatcher dropped.
+    ///
+    /// [`event`]: #method.event
+    pub fn missed_dispatch(&self) -> bool {
+        self.missed_dispatch
+    }
+}
+
+/// Passes a [`DeadListener`] to `handler` for each of `count` listeners
+/// of `event` that have been dropped without unsubscribing.
+///
+/// [`DeadListener`]: struct.DeadListener.html
+fn report_dead_listeners<T>(
+    handler: &Option<DeadListenerHandler<T>>,
+    event: &T,
+    count: usize,
+    missed_dispatch: bool,
+) {
+    if let Some(ref handler) = *handler {
+        for _ in 0..count {
+            handler(&DeadListener {
+                event,
+                missed_dispatch,
+            });
+        }
+    }
+}
+
+/// Counts a listener that has been dropped without unsubscribing
+/// via `dead`, requesting its removal.
+fn remove_dead<T, P>(dead: &mut usize) -> Option<ListenerRequest<T, P>>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    *dead += 1;
+
+    Some(ListenerRequest::StopListening)
+}
+
+/// Passes `event`, which no listener has been registered for,
+/// to `handler` and keeps it in `dead_letters`.
+fn report_unhandled<T: Clone>(
+    handler: &Option<UnhandledHandler<T>>,
+    dead_letters: &mut DeadLetters<T>,
+    event: &T,
+) {
+    if let Some(ref handler) = *handler {
+        handler(event);
+    }
+
+    dead_letters.push(event);
+}
+
+/// Returns whether `first` and `second` point to the same closure,
+/// which is the case if it has been registered for multiple events.
+fn is_same_fn<F: ?Sized>(first: &Arc<F>, second: &Arc<F>) -> bool {
+    Arc::as_ptr(first) as *const () == Arc::as_ptr(second) as *const ()
+}
+
+/// The reason passed to a listener's `on_unsubscribe`, once
+/// it stops listening to an event.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnsubscribeReason {
+    /// The listener requested `StopListening` or `StopListeningAndPropagation`.
+    StopListening,
+    /// The listener requested `StopListeningToAll`.
+    StopListeningToAll,
+    /// The listener has been replaced via `Replace`.
+    Replaced,
+    /// The listener has been removed via `remove_listener`.
+    Removed,
+}
+
+/// Translates requests of your own type `R`, returned by your listeners,
+/// into requests `O` a dispatcher executes, e.g. [`SyncDispatcherRequest`],
+/// [`ListenerRequest`] or [`ParallelDispatcherRequest`].
+/// Returning `None` lets the dispatcher continue as if no request has been made.
+///
+/// It is implemented for all `Fn(R) -> Option<O>`.
+///
+/// # Examples
+///
+/// Marking events as handled:
+///
+/// ```rust
+/// extern crate hey_listen;
+///
+/// use hey_listen::{EventDispatcher, ListenerRequest};
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Event {
+///     EventType,
+/// }
+///
+/// enum Request {
+///     MarkHandled,
+///     Retry,
+/// }
+///
+/// fn main() {
+///     let mut dispatcher = EventDispatcher::<Event, Request>::new_with_request_handler(
+///         |request: Request| match request {
+///             Request::MarkHandled => Some(ListenerRequest::StopPropagation),
+///             Request::Retry => Some(ListenerRequest::Emit(Event::EventType)),
+///         },
+///     );
+///
+///     dispatcher.add_fn(Event::EventType, Box::new(|_: &Event| Some(Request::MarkHandled)));
+///     dispatcher.dispatch_event(&Event::EventType);
+/// }
+/// ```
+///
+/// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
+/// [`ListenerRequest`]: enum.ListenerRequest.html
+/// [`ParallelDispatcherRequest`]: enum.ParallelDispatcherRequest.html
+pub trait RequestHandler<R, O>: Send + Sync {
+    /// Translates `request` into a request of the dispatcher.
+    fn handle(&self, request: R) -> Option<O>;
+}
+
+impl<R, O, F> RequestHandler<R, O> for F
+where
+    F: Fn(R) -> Option<O> + Send + Sync,
+{
+    fn handle(&self, request: R) -> Option<O> {
+        self(request)
+    }
+}
+
+/// Wraps `handler`, converting its requests `O` into [`ListenerRequest`]s.
+///
+/// [`ListenerRequest`]: enum.ListenerRequest.html
+pub(crate) fn translating_handler<T, P, R, O, H>(handler: H) -> Arc<SyncRequestHandler<T, P, R>>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    P: 'static,
+    R: 'static,
+    O: Into<ListenerRequest<T, P>> + 'static,
+    H: RequestHandler<R, O> + 'static,
+{
+    Arc::new(move |request: R| handler.handle(request).map(Into::into))
 }
 
 /// When `execute_sync_dispatcher_requests` returns,
@@ -119,191 +468,2985 @@ pub(crate) enum ExecuteRequestsResult {
     Stopped,
 }
 
-/// Iterates over the passed `vec` and applies `function` to each element.
-/// `function`'s returned [`SyncDispatcherRequest`] will instruct
+/// A [`ListenerRequest`] that cannot be executed while iterating,
+/// passed on by `execute_sync_dispatcher_requests` alongside
+/// a clone of the element it has been issued by.
+///
+/// [`ListenerRequest`]: enum.ListenerRequest.html
+pub(crate) enum DeferredRequest<E, T, P>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    StopListeningToAll(E),
+    ChangePriority(E, P),
+    Replace(E, BoxedListener<T, P>),
+}
+
+/// Iterates over the passed `slice` and applies `function` to each element.
+/// `function`'s returned [`ListenerRequest`] will instruct
 /// a procedure depending on its variant:
 ///
-/// `StopListening`: Removes item from `vec`.
+/// `StopListening`: Passes the item to `unsubscribe`.
 /// `StopPropagation`: Stops further dispatching to other elements
-/// in `vec`.
+/// in `slice`.
 /// `StopListeningAndPropagation`: Execute `StopListening`,
 /// then execute `StopPropagation`.
+/// `Emit`: Retains item, the event has to be queued by `function`.
+/// `StopListeningToAll`, `ChangePriority` and `Replace`: Passes
+/// a clone of the item on to `defer`.
 ///
-/// **Note**: When `StopListening` is being executed,
-/// removal of items from `vec` will result use a swap of elements,
-/// resulting in an alteration of the order items were originally
-/// inserted into `vec`.
+/// Items stop listening via `StopListening`, `StopListeningAndPropagation`,
+/// `StopListeningToAll` and `Replace`, they are passed to `unsubscribe`
+/// alongside the reason.
 ///
-/// **Note**: Unlike [`retain`], `execute_sync_dispatcher_requests`
-/// can break the current iteration and is able to match [`SyncDispatcherRequest`]
-/// and perform actions based on variants.
+/// **Note**: Items are not removed from `slice`, hence it can be shared
+/// with nested dispatches. Callers remove items passed to `unsubscribe`
+/// or `defer` from their listener-collection once done iterating.
 ///
-/// [`retain`]: https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.retain
-/// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
-pub(crate) fn execute_sync_dispatcher_requests<T, F>(
-    vec: &mut Vec<T>,
+/// [`ListenerRequest`]: enum.ListenerRequest.html
+pub(crate) fn execute_sync_dispatcher_requests<E, T, P, F, D, U>(
+    slice: &[E],
     mut function: F,
+    mut defer: D,
+    mut unsubscribe: U,
 ) -> ExecuteRequestsResult
 where
-    F: FnMut(&T) -> Option<SyncDispatcherRequest>,
+    E: Clone,
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    F: FnMut(&E) -> Option<ListenerRequest<T, P>>,
+    D: FnMut(DeferredRequest<E, T, P>),
+    U: FnMut(&E, UnsubscribeReason),
 {
-    let mut index = 0;
-
-    loop {
-        if index < vec.len() {
-            match function(&vec[index]) {
-                None => index += 1,
-                Some(SyncDispatcherRequest::StopListening) => {
-                    vec.swap_remove(index);
-                }
-                Some(SyncDispatcherRequest::StopPropagation) => {
-                    return ExecuteRequestsResult::Stopped
-                }
-                Some(SyncDispatcherRequest::StopListeningAndPropagation) => {
-                    vec.swap_remove(index);
-                    return ExecuteRequestsResult::Stopped;
-                }
+    for element in slice {
+        match function(element) {
+            None | Some(ListenerRequest::Emit(_)) => (),
+            Some(ListenerRequest::StopListening) => {
+                unsubscribe(element, UnsubscribeReason::StopListening)
             }
-        } else {
-            return ExecuteRequestsResult::Finished;
+            Some(ListenerRequest::StopListeningToAll) => {
+                unsubscribe(element, UnsubscribeReason::StopListeningToAll);
+                defer(DeferredRequest::StopListeningToAll(element.clone()));
+            }
+            Some(ListenerRequest::StopPropagation) => return ExecuteRequestsResult::Stopped,
+            Some(ListenerRequest::StopListeningAndPropagation) => {
+                unsubscribe(element, UnsubscribeReason::StopListening);
+                return ExecuteRequestsResult::Stopped;
+            }
+            Some(ListenerRequest::ChangePriority(priority)) => {
+                defer(DeferredRequest::ChangePriority(element.clone(), priority))
+            }
+            Some(ListenerRequest::Replace(listener)) => {
+                unsubscribe(element, UnsubscribeReason::Replaced);
+                defer(DeferredRequest::Replace(element.clone(), listener));
+            }
+        }
+    }
+
+    ExecuteRequestsResult::Finished
+}
+
+/// An owned listener, translating its requests when being added.
+type OwnedLockedListener<T, P> = LockedListener<T, ListenerRequest<T, P>>;
+
+/// A replaced listener alongside the owned listener replacing it.
+type Replacement<T, P, R> = (FnsAndTraits<T, P, R>, OwnedListener<OwnedLockedListener<T, P>>);
+
+/// A listener owned by its dispatcher, identified by a [`ListenerId`].
+/// It is reference-counted, allowing its listener-collection to be cloned.
+///
+/// [`ListenerId`]: struct.ListenerId.html
+struct OwnedListener<L: ?Sized> {
+    id: ListenerId,
+    listener: Arc<L>,
+}
+
+impl<L: ?Sized> Clone for OwnedListener<L> {
+    fn clone(&self) -> Self {
+        OwnedListener {
+            id: self.id,
+            listener: Arc::clone(&self.listener),
         }
     }
 }
 
 /// Yields closures and trait-objects.
-struct FnsAndTraits<T>
+///
+/// Owned listeners already translate their requests of type `R`
+/// when being added, as they may be replaced by listeners returning
+/// [`ListenerRequest`]s.
+///
+/// [`ListenerRequest`]: enum.ListenerRequest.html
+struct FnsAndTraits<T, P, R>
 where
     T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
 {
-    traits: Vec<Weak<Mutex<Listener<T> + Send + Sync + 'static>>>,
-    fns: EventFunction<T>,
+    traits: Vec<Weak<LockedListener<T, R>>>,
+    owned: Vec<OwnedListener<OwnedLockedListener<T, P>>>,
+    shared: Vec<Weak<SharedListener<T, R> + Send + Sync + 'static>>,
+    fns: EventFunction<T, R>,
 }
 
-impl<T> FnsAndTraits<T>
+impl<T, P, R> Clone for FnsAndTraits<T, P, R>
 where
     T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
 {
-    fn new_with_traits(
-        trait_objects: Vec<Weak<Mutex<Listener<T> + Send + Sync + 'static>>>,
-    ) -> Self {
-        FnsAndTraits {
-            traits: trait_objects,
-            fns: vec![],
-        }
-    }
-
-    fn new_with_fns(fns: EventFunction<T>) -> Self {
+    fn clone(&self) -> Self {
         FnsAndTraits {
-            traits: vec![],
-            fns,
+            traits: self.traits.clone(),
+            owned: self.owned.clone(),
+            shared: self.shared.clone(),
+            fns: self.fns.clone(),
         }
     }
 }
 
-/// Yields `Send` and `Sync` closures and trait-objects.
-struct ParallelFnsAndTraits<T>
+impl<T, P, R> Default for FnsAndTraits<T, P, R>
 where
     T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
 {
-    traits: Vec<Weak<Mutex<ParallelListener<T> + Send + Sync + 'static>>>,
-    fns: ParallelEventFunction<T>,
+    fn default() -> Self {
+        FnsAndTraits {
+            traits: vec![],
+            owned: vec![],
+            shared: vec![],
+            fns: vec![],
+        }
+    }
 }
 
-impl<T> ParallelFnsAndTraits<T>
+impl<T, P, R> FnsAndTraits<T, P, R>
 where
     T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
 {
     fn new_with_traits(
-        trait_objects: Vec<Weak<Mutex<ParallelListener<T> + Send + Sync + 'static>>>,
+        trait_objects: Vec<Weak<LockedListener<T, R>>>,
     ) -> Self {
-        ParallelFnsAndTraits {
+        FnsAndTraits {
             traits: trait_objects,
-            fns: vec![],
+            ..FnsAndTraits::default()
         }
     }
 
-    fn new_with_fns(fns: ParallelEventFunction<T>) -> Self {
-        ParallelFnsAndTraits {
-            traits: vec![],
+    fn new_with_owned(owned: Vec<OwnedListener<OwnedLockedListener<T, P>>>) -> Self {
+        FnsAndTraits {
+            owned,
+            ..FnsAndTraits::default()
+        }
+    }
+
+    fn new_with_shared(shared: Vec<Weak<SharedListener<T, R> + Send + Sync + 'static>>) -> Self {
+        FnsAndTraits {
+            shared,
+            ..FnsAndTraits::default()
+        }
+    }
+
+    fn new_with_fns(fns: EventFunction<T, R>) -> Self {
+        FnsAndTraits {
             fns,
+            ..FnsAndTraits::default()
         }
     }
-}
 
-/// Every event-receiver needs to implement this trait
-/// in order to receive dispatched events.
-/// `T` being the type you use for events, e.g. an `Enum`.
-pub trait Listener<T>
-where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
-{
-    /// This function will be called once a listened
-    /// event-type `T` has been dispatched.
-    fn on_event(&mut self, event: &T) -> Option<SyncDispatcherRequest>;
-}
+    /// Moves all listeners of `other` into `self`.
+    fn append(&mut self, mut other: FnsAndTraits<T, P, R>) {
+        self.traits.append(&mut other.traits);
+        self.owned.append(&mut other.owned);
+        self.shared.append(&mut other.shared);
+        self.fns.append(&mut other.fns);
+    }
 
-/// Every event-receiver needs to implement this trait
-/// in order to receive dispatched events.
-/// `T` being the type you use for events, e.g. an `Enum`.
-pub trait ParallelListener<T>
-where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
-{
-    /// This function will be called once a listened
-    /// event-type `T` has been dispatched.
-    fn on_event(&mut self, event: &T) -> Option<ParallelDispatcherRequest>;
-}
+    /// Removes the owned listener identified by `id`, listening
+    /// for `event_identifier`, returns whether it has been found.
+    fn remove_owned(&mut self, event_identifier: &T, id: ListenerId) -> bool {
+        match self.take_owned(id) {
+            Some(owned) => {
+                owned
+                    .listener
+                    .on_unsubscribe(event_identifier, UnsubscribeReason::Removed);
 
-/// Owns a map of all listened event-variants,
-/// [`Weak`]-references to their listeners and [`Fn`]s.
-///
-/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
-/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
-pub struct EventDispatcher<T>
-where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
-{
-    events: ListenerMap<T>,
-}
+                true
+            }
+            None => false,
+        }
+    }
 
-impl<T> Default for EventDispatcher<T>
-where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
-{
-    fn default() -> EventDispatcher<T> {
-        EventDispatcher {
-            events: ListenerMap::new(),
+    /// Removes the owned listener `id` without notifying it.
+    fn take_owned(&mut self, id: ListenerId) -> Option<OwnedListener<OwnedLockedListener<T, P>>> {
+        let index = self.owned.iter().position(|owned| owned.id == id)?;
+
+        Some(self.owned.remove(index))
+    }
+
+    /// Removes all listeners of `detached` from `listener_collection`,
+    /// listening for `event_identifier`, copying it only if affected
+    /// while a dispatch in progress shares it.
+    fn remove_detached(
+        listener_collection: &mut Arc<Self>,
+        event_identifier: &T,
+        detached: &FnsAndTraits<T, P, R>,
+    ) {
+        if !listener_collection.contains_any(detached) {
+            return;
+        }
+
+        let listener_collection = Arc::make_mut(listener_collection);
+
+        for weak_listener in &listener_collection.traits {
+            if detached.traits.iter().any(|removed| removed.ptr_eq(weak_listener)) {
+                if let Some(listener) = weak_listener.upgrade() {
+                    listener.on_unsubscribe(
+                        event_identifier,
+                        UnsubscribeReason::StopListeningToAll,
+                    );
+                }
+            }
+        }
+
+        for weak_listener in &listener_collection.shared {
+            if detached.shared.iter().any(|removed| removed.ptr_eq(weak_listener)) {
+                if let Some(listener) = weak_listener.upgrade() {
+                    listener.on_unsubscribe(
+                        event_identifier,
+                        UnsubscribeReason::StopListeningToAll,
+                    );
+                }
+            }
         }
+
+        listener_collection.remove_all(detached);
     }
-}
 
-impl<T> EventDispatcher<T>
-where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
-{
-    /// Adds a [`Listener`] to listen for an `event_identifier`.
-    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
-    ///
-    /// **Note**: If your `Enum` owns fields you need to consider implementing
-    /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields,
-    /// see second example for an implementation-suggestion.
-    ///
-    /// # Examples
-    ///
-    /// Adding a [`Listener`] to the dispatcher:
-    ///
-    /// ```rust
-    /// extern crate hey_listen;
-    /// use std::sync::{Arc, Mutex};
-    ///
-    /// use hey_listen::{Listener, EventDispatcher, SyncDispatcherRequest};
-    ///
-    /// #[derive(Clone, Eq, Hash, PartialEq)]
-    /// enum Event {
-    ///     EventType,
-    /// }
+    /// Removes the listeners of `detached` from all priority-levels of
+    /// `event_identifier` like [`remove_detached`], copying
+    /// `prioritised_listener_collection` only if it is affected.
     ///
-    /// struct ListenerStruct {}
+    /// [`remove_detached`]: #method.remove_detached
+    fn remove_prioritised_detached(
+        prioritised_listener_collection: &mut Arc<PriorityLevels<T, P, R>>,
+        event_identifier: &T,
+        detached: &FnsAndTraits<T, P, R>,
+    ) where
+        P: Ord + Clone,
+    {
+        if !prioritised_listener_collection
+            .values()
+            .any(|listener_collection| listener_collection.contains_any(detached))
+        {
+            return;
+        }
+
+        for listener_collection in Arc::make_mut(prioritised_listener_collection).values_mut() {
+            FnsAndTraits::remove_detached(listener_collection, event_identifier, detached);
+        }
+    }
+
+    /// Returns a copy of `self` without the listeners of `removed`.
+    fn without(&self, removed: &FnsAndTraits<T, P, R>) -> Self {
+        let mut remaining = self.clone();
+        remaining.remove_all(removed);
+
+        remaining
+    }
+
+    /// Removes all listeners of `removed` from `self` without notifying them.
+    fn remove_all(&mut self, removed: &FnsAndTraits<T, P, R>) {
+        self.traits
+            .retain(|listener| !removed.traits.iter().any(|other| other.ptr_eq(listener)));
+        self.shared
+            .retain(|listener| !removed.shared.iter().any(|other| other.ptr_eq(listener)));
+        self.owned
+            .retain(|owned| !removed.owned.iter().any(|other| other.id == owned.id));
+        self.fns
+            .retain(|callback| !removed.fns.iter().any(|other| is_same_fn(other, callback)));
+    }
+
+    /// Returns whether `self` contains any listener of `listeners`.
+    fn contains_any(&self, listeners: &FnsAndTraits<T, P, R>) -> bool {
+        self.traits
+            .iter()
+            .any(|listener| listeners.traits.iter().any(|other| other.ptr_eq(listener)))
+            || self.owned
+                .iter()
+                .any(|owned| listeners.owned.iter().any(|other| other.id == owned.id))
+            || self.shared
+                .iter()
+                .any(|listener| listeners.shared.iter().any(|other| other.ptr_eq(listener)))
+            || self.fns
+                .iter()
+                .any(|callback| listeners.fns.iter().any(|other| is_same_fn(other, callback)))
+    }
+
+    /// Calls `on_dispatcher_dropped` on all listeners,
+    /// skipping listeners contained by `notified`.
+    ///
+    /// Returns the amount of listeners dropped without unsubscribing.
+    fn notify_dispatcher_dropped(&self, notified: &mut FnsAndTraits<T, P, R>) -> usize {
+        let mut dead = 0;
+
+        for weak_listener in &self.traits {
+            match weak_listener.upgrade() {
+                Some(listener) => {
+                    if !notified.traits.iter().any(|listener| listener.ptr_eq(weak_listener)) {
+                        listener.on_dispatcher_dropped();
+                        notified.traits.push(Weak::clone(weak_listener));
+                    }
+                }
+                None => dead += 1,
+            }
+        }
+
+        for weak_listener in &self.shared {
+            match weak_listener.upgrade() {
+                Some(listener) => {
+                    if !notified.shared.iter().any(|listener| listener.ptr_eq(weak_listener)) {
+                        listener.on_dispatcher_dropped();
+                        notified.shared.push(Weak::clone(weak_listener));
+                    }
+                }
+                None => dead += 1,
+            }
+        }
+
+        for owned in &self.owned {
+            if owned.listener.is_dead() {
+                dead += 1;
+            } else {
+                owned.listener.on_dispatcher_dropped();
+            }
+        }
+
+        dead
+    }
+
+    /// Returns whether `self` contains no listeners.
+    fn is_empty(&self) -> bool {
+        self.traits.is_empty() && self.owned.is_empty() && self.shared.is_empty()
+            && self.fns.is_empty()
+    }
+
+    /// Returns whether any listener is registered, ignoring
+    /// listeners dropped without unsubscribing.
+    fn has_listeners(&self) -> bool {
+        !self.fns.is_empty()
+            || self.owned.iter().any(|owned| !owned.listener.is_dead())
+            || self.traits.iter().any(|listener| listener.upgrade().is_some())
+            || self.shared.iter().any(|listener| listener.upgrade().is_some())
+    }
+
+    /// Dispatches `event` to all listeners, listening for `event_identifier`,
+    /// follow-up events are dispatched via `dispatcher`
+    /// and requests are translated via `handler`.
+    ///
+    /// `self` may be shared with nested dispatches, hence it is left
+    /// untouched and listeners leaving it are returned via
+    /// [`Relocations`] to be applied on the listener-collection.
+    ///
+    /// [`Relocations`]: struct.Relocations.html
+    fn dispatch<D: NestedDispatch<T>>(
+        &self,
+        dispatcher: &mut D,
+        handler: &SyncRequestHandler<T, P, R>,
+        event_identifier: &T,
+        event: &T,
+        chain: &mut DispatchChain<T>,
+    ) -> (ExecuteRequestsResult, Relocations<T, P, R>)
+    where
+        P: 'static,
+    {
+        let mut relocations = Relocations::new();
+        let mut removed = FnsAndTraits::default();
+        let mut dead = 0;
+
+        let mut result = execute_sync_dispatcher_requests(
+            &self.traits,
+            |weak_listener| {
+                let listener = match weak_listener.upgrade() {
+                    Some(listener) => listener,
+                    None => return remove_dead(&mut dead),
+                };
+                let causer = Causer::referenced(weak_listener);
+
+                call_sync_listener(dispatcher, chain, causer, |context| {
+                    listener
+                        .on_event(event, context)
+                        .and_then(|request| handler.handle(request))
+                })
+            },
+            |request| {
+                relocations.defer(
+                    request,
+                    |weak_listener| FnsAndTraits::new_with_traits(vec![weak_listener]),
+                    |_| ListenerId::new(),
+                )
+            },
+            |weak_listener, reason| {
+                if let Some(listener) = weak_listener.upgrade() {
+                    listener.on_unsubscribe(event_identifier, reason);
+                }
+
+                removed.traits.push(Weak::clone(weak_listener));
+            },
+        );
+
+        if let ExecuteRequestsResult::Finished = result {
+            result = execute_sync_dispatcher_requests(
+                &self.owned,
+                |owned| {
+                    if owned.listener.is_dead() {
+                        return remove_dead(&mut dead);
+                    }
+
+                    call_sync_listener(dispatcher, chain, Causer::Owned(owned.id), |context| {
+                        owned.listener.on_event(event, context)
+                    })
+                },
+                |request| {
+                    relocations.defer(
+                        request,
+                        |owned| FnsAndTraits::new_with_owned(vec![owned]),
+                        |owned| owned.id,
+                    )
+                },
+                |owned, reason| {
+                    owned.listener.on_unsubscribe(event_identifier, reason);
+                    removed.owned.push(owned.clone());
+                },
+            );
+        }
+
+        if let ExecuteRequestsResult::Finished = result {
+            result = execute_sync_dispatcher_requests(
+                &self.shared,
+                |weak_listener| {
+                    let listener = match weak_listener.upgrade() {
+                        Some(listener) => listener,
+                        None => return remove_dead(&mut dead),
+                    };
+                    let causer = Causer::referenced(weak_listener);
+
+                    call_sync_listener(dispatcher, chain, causer, |context| {
+                        listener
+                            .on_event_with_context(event, context)
+                            .and_then(|request| handler.handle(request))
+                    })
+                },
+                |request| {
+                    relocations.defer(
+                        request,
+                        |weak_listener| FnsAndTraits::new_with_shared(vec![weak_listener]),
+                        |_| ListenerId::new(),
+                    )
+                },
+                |weak_listener, reason| {
+                    if let Some(listener) = weak_listener.upgrade() {
+                        listener.on_unsubscribe(event_identifier, reason);
+                    }
+
+                    removed.shared.push(Weak::clone(weak_listener));
+                },
+            );
+        }
+
+        if let ExecuteRequestsResult::Finished = result {
+            result = execute_sync_dispatcher_requests(
+                &self.fns,
+                |callback| {
+                    call_sync_listener(dispatcher, chain, Causer::function(callback), |context| {
+                        callback(event, context)
+                            .and_then(|request| handler.handle(request))
+                    })
+                },
+                |request| {
+                    relocations.defer(
+                        request,
+                        |callback| FnsAndTraits::new_with_fns(vec![callback]),
+                        |_| ListenerId::new(),
+                    )
+                },
+                |callback, _| removed.fns.push(Arc::clone(callback)),
+            );
+        }
+
+        relocations.removed = removed;
+        relocations.dead = dead;
+
+        (result, relocations)
+    }
+}
+
+/// Listeners leaving their listener-collection during a dispatch.
+struct Relocations<T, P, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Listeners to remove from the dispatched collection.
+    removed: FnsAndTraits<T, P, R>,
+    /// Listeners requesting another priority-level.
+    moved: Vec<(P, FnsAndTraits<T, P, R>)>,
+    /// Listeners replacing a listener of the dispatched collection.
+    replacements: Vec<Replacement<T, P, R>>,
+    /// Listeners to remove from all listener-collections.
+    detached: FnsAndTraits<T, P, R>,
+    /// Amount of listeners dropped without unsubscribing.
+    dead: usize,
+}
+
+impl<T, P, R> Relocations<T, P, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn new() -> Self {
+        Relocations {
+            removed: FnsAndTraits::default(),
+            moved: vec![],
+            replacements: vec![],
+            detached: FnsAndTraits::default(),
+            dead: 0,
+        }
+    }
+
+    /// Records `request`, `collection` wraps a relocated listener
+    /// and `id` identifies a replaced listener.
+    fn defer<E, C, I>(&mut self, request: DeferredRequest<E, T, P>, collection: C, id: I)
+    where
+        P: 'static,
+        C: FnOnce(E) -> FnsAndTraits<T, P, R>,
+        I: FnOnce(&E) -> ListenerId,
+    {
+        match request {
+            DeferredRequest::StopListeningToAll(element) => {
+                self.detached.append(collection(element))
+            }
+            DeferredRequest::ChangePriority(element, priority) => {
+                self.moved.push((priority, collection(element)))
+            }
+            DeferredRequest::Replace(element, listener) => {
+                let replacement = OwnedListener {
+                    id: id(&element),
+                    listener: Arc::new(Mutex::new(listener)) as Arc<OwnedLockedListener<T, P>>,
+                };
+
+                self.replacements.push((collection(element), replacement))
+            }
+        }
+    }
+
+    /// Removes the listeners leaving `listener_collection` of `event_identifier`
+    /// and adds their replacements.
+    ///
+    /// Nested dispatches may have relocated the same listeners already,
+    /// hence listeners are only replaced if they are still contained.
+    /// `listener_collection` is only copied if a dispatch in progress
+    /// still shares it.
+    fn apply(
+        &mut self,
+        event_identifier: &T,
+        listener_collection: &mut Arc<FnsAndTraits<T, P, R>>,
+    ) {
+        if self.removed.is_empty() && self.replacements.is_empty() {
+            return;
+        }
+
+        let listener_collection = Arc::make_mut(listener_collection);
+        let replacements: Vec<_> = self.replacements
+            .drain(..)
+            .filter(|(replaced, _)| listener_collection.contains_any(replaced))
+            .map(|(_, replacement)| replacement)
+            .collect();
+
+        listener_collection.remove_all(&self.removed);
+
+        for replacement in replacements {
+            replacement.listener.on_subscribe(event_identifier);
+            listener_collection.owned.push(replacement);
+        }
+    }
+
+    /// Applies `self` on the priority-levels of `event_identifier`,
+    /// moving listeners of the dispatched `priority` still contained
+    /// to their requested priority-level.
+    fn apply_prioritised(
+        mut self,
+        event_identifier: &T,
+        prioritised_listener_collection: &mut Arc<PriorityLevels<T, P, R>>,
+        priority: &P,
+    ) where
+        P: Ord + Clone,
+    {
+        if self.removed.is_empty() && self.replacements.is_empty() && self.moved.is_empty() {
+            return;
+        }
+
+        let prioritised_listener_collection = Arc::make_mut(prioritised_listener_collection);
+        let mut moved = vec![];
+
+        match prioritised_listener_collection.get_mut(priority) {
+            Some(listener_collection) => {
+                self.apply(event_identifier, listener_collection);
+
+                for (priority, relocated) in self.moved.drain(..) {
+                    if listener_collection.contains_any(&relocated) {
+                        Arc::make_mut(listener_collection).remove_all(&relocated);
+                        moved.push((priority, relocated));
+                    }
+                }
+            }
+            None => return,
+        }
+
+        for (priority, listener_collection) in moved {
+            Arc::make_mut(prioritised_listener_collection.entry(priority).or_default())
+                .append(listener_collection);
+        }
+    }
+}
+
+/// Calls `listener` via `function` like `call_listener`,
+/// additionally queuing the event of an `Emit`-request
+/// or reporting it to the dispatcher if it is rejected.
+pub(crate) fn call_sync_listener<T, P, D, F>(
+    dispatcher: &mut D,
+    chain: &mut DispatchChain<T>,
+    listener: Causer,
+    function: F,
+) -> Option<ListenerRequest<T, P>>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    D: NestedDispatch<T>,
+    F: FnOnce(&mut DispatchContext<T>) -> Option<ListenerRequest<T, P>>,
+{
+    match call_listener(dispatcher, chain, listener, function) {
+        Some(ListenerRequest::Emit(event)) => {
+            match chain.check(&event, listener) {
+                Ok(()) => chain.enqueue(event, listener),
+                Err(error) => dispatcher.report_emit_error(&event, &error),
+            }
+
+            None
+        }
+        request => request,
+    }
+}
+
+/// Yields `Send` and `Sync` closures and trait-objects.
+struct ParallelFnsAndTraits<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    traits: Vec<Weak<ParallelLockedListener<T, R>>>,
+    owned: Vec<OwnedListener<ParallelLockedListener<T, R>>>,
+    shared: Vec<Weak<ParallelSharedListener<T, R> + Send + Sync + 'static>>,
+    fns: ParallelEventFunction<T, R>,
+}
+
+impl<T, R> Default for ParallelFnsAndTraits<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        ParallelFnsAndTraits {
+            traits: vec![],
+            owned: vec![],
+            shared: vec![],
+            fns: vec![],
+        }
+    }
+}
+
+impl<T, R> ParallelFnsAndTraits<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn new_with_traits(
+        trait_objects: Vec<Weak<ParallelLockedListener<T, R>>>,
+    ) -> Self {
+        ParallelFnsAndTraits {
+            traits: trait_objects,
+            ..ParallelFnsAndTraits::default()
+        }
+    }
+
+    fn new_with_owned(
+        owned: Vec<OwnedListener<ParallelLockedListener<T, R>>>,
+    ) -> Self {
+        ParallelFnsAndTraits {
+            owned,
+            ..ParallelFnsAndTraits::default()
+        }
+    }
+
+    fn new_with_shared(
+        shared: Vec<Weak<ParallelSharedListener<T, R> + Send + Sync + 'static>>,
+    ) -> Self {
+        ParallelFnsAndTraits {
+            shared,
+            ..ParallelFnsAndTraits::default()
+        }
+    }
+
+    fn new_with_fns(fns: ParallelEventFunction<T, R>) -> Self {
+        ParallelFnsAndTraits {
+            fns,
+            ..ParallelFnsAndTraits::default()
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.traits.is_empty() && self.owned.is_empty() && self.shared.is_empty()
+            && self.fns.is_empty()
+    }
+
+    /// Returns whether any listener is registered, ignoring
+    /// listeners dropped without unsubscribing.
+    fn has_listeners(&self) -> bool {
+        !self.fns.is_empty()
+            || self.owned.iter().any(|owned| !owned.listener.is_dead())
+            || self.traits.iter().any(|listener| listener.upgrade().is_some())
+            || self.shared.iter().any(|listener| listener.upgrade().is_some())
+    }
+
+    /// Records all listeners `event` is about to be dispatched to
+    /// via `tracer`, if tracing is enabled.
+    fn trace(&self, tracer: &mut Option<Tracer<T>>, event: &T) {
+        let tracer = match *tracer {
+            Some(ref mut tracer) => tracer,
+            None => return,
+        };
+
+        for weak_listener in &self.traits {
+            if let Some(listener) = weak_listener.upgrade() {
+                if listener.accepts(event) {
+                    tracer.record(TracedListener::referenced(weak_listener));
+                }
+            }
+        }
+
+        for owned in &self.owned {
+            if owned.listener.accepts(event) {
+                tracer.record(TracedListener::Owned(owned.id));
+            }
+        }
+
+        for weak_listener in &self.shared {
+            if weak_listener.upgrade().is_some() {
+                tracer.record(TracedListener::referenced(weak_listener));
+            }
+        }
+
+        for _ in &self.fns {
+            tracer.record(TracedListener::Fn);
+        }
+    }
+
+    /// Removes the owned listener identified by `id`, listening
+    /// for `event_identifier` and leaving due to `reason`,
+    /// returns whether it has been found.
+    fn remove_owned(
+        &mut self,
+        event_identifier: &T,
+        id: ListenerId,
+        reason: UnsubscribeReason,
+    ) -> bool {
+        match self.owned.iter().position(|owned| owned.id == id) {
+            Some(index) => {
+                self.owned
+                    .remove(index)
+                    .listener
+                    .on_unsubscribe(event_identifier, reason);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes all listeners of `detached` from `self`,
+    /// listening for `event_identifier`.
+    fn remove_detached(&mut self, event_identifier: &T, detached: &ParallelFnsAndTraits<T, R>) {
+        self.traits.retain(|listener| {
+            if detached.traits.iter().any(|removed| removed.ptr_eq(listener)) {
+                if let Some(listener) = listener.upgrade() {
+                    listener.on_unsubscribe(
+                        event_identifier,
+                        UnsubscribeReason::StopListeningToAll,
+                    );
+                }
+
+                false
+            } else {
+                true
+            }
+        });
+        self.shared.retain(|listener| {
+            if detached.shared.iter().any(|removed| removed.ptr_eq(listener)) {
+                if let Some(listener) = listener.upgrade() {
+                    listener.on_unsubscribe(
+                        event_identifier,
+                        UnsubscribeReason::StopListeningToAll,
+                    );
+                }
+
+                false
+            } else {
+                true
+            }
+        });
+        self.fns
+            .retain(|callback| !detached.fns.iter().any(|removed| is_same_fn(removed, callback)));
+    }
+
+    /// Calls `on_dispatcher_dropped` on all listeners,
+    /// skipping listeners contained by `notified`.
+    ///
+    /// Returns the amount of listeners dropped without unsubscribing.
+    fn notify_dispatcher_dropped(&self, notified: &mut ParallelFnsAndTraits<T, R>) -> usize {
+        let mut dead = 0;
+
+        for weak_listener in &self.traits {
+            match weak_listener.upgrade() {
+                Some(listener) => {
+                    if !notified.traits.iter().any(|listener| listener.ptr_eq(weak_listener)) {
+                        listener.on_dispatcher_dropped();
+                        notified.traits.push(Weak::clone(weak_listener));
+                    }
+                }
+                None => dead += 1,
+            }
+        }
+
+        for weak_listener in &self.shared {
+            match weak_listener.upgrade() {
+                Some(listener) => {
+                    if !notified.shared.iter().any(|listener| listener.ptr_eq(weak_listener)) {
+                        listener.on_dispatcher_dropped();
+                        notified.shared.push(Weak::clone(weak_listener));
+                    }
+                }
+                None => dead += 1,
+            }
+        }
+
+        for owned in &self.owned {
+            if owned.listener.is_dead() {
+                dead += 1;
+            } else {
+                owned.listener.on_dispatcher_dropped();
+            }
+        }
+
+        dead
+    }
+}
+
+/// Removes all elements at `indices` from `vec`.
+///
+/// **Note**: Like `execute_sync_dispatcher_requests`, this swaps
+/// elements, hence alters the order items were inserted into `vec`.
+fn swap_remove_indices<T>(vec: &mut Vec<T>, indices: &mut [usize]) {
+    indices.sort_unstable_by(|a, b| b.cmp(a));
+
+    for index in indices.iter() {
+        vec.swap_remove(*index);
+    }
+}
+
+/// Every event-receiver needs to implement this trait
+/// in order to receive dispatched events.
+/// `T` being the type you use for events, e.g. an `Enum`.
+/// `R` being the request type, translated by the dispatcher's [`RequestHandler`],
+/// e.g. [`ListenerRequest`] to emit events or change priority-levels.
+///
+/// [`RequestHandler`]: trait.RequestHandler.html
+/// [`ListenerRequest`]: enum.ListenerRequest.html
+pub trait Listener<T, R = SyncDispatcherRequest>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// This function will be called once a listened
+    /// event-type `T` has been dispatched.
+    fn on_event(&mut self, event: &T) -> Option<R>;
+
+    /// This function will be called instead of [`on_event`] by
+    /// dispatchers supporting follow-up events, passing a [`DispatchContext`]
+    /// to dispatch further events with.
+    /// By default, it calls [`on_event`].
+    ///
+    /// [`on_event`]: trait.Listener.html#tymethod.on_event
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    fn on_event_with_context(&mut self, event: &T, _context: &mut DispatchContext<T>) -> Option<R> {
+        self.on_event(event)
+    }
+
+    /// Returns whether the listener wants to receive `event`,
+    /// dispatchers skip calling [`on_event`] for rejected events.
+    /// By default, it accepts all events.
+    ///
+    /// If the listener's [`ListenerLock`] grants shared access, e.g. a
+    /// `RwLock`, it is evaluated via a read-lock, hence rejected events do
+    /// not take the write-lock.
+    ///
+    /// [`on_event`]: trait.Listener.html#tymethod.on_event
+    /// [`ListenerLock`]: trait.ListenerLock.html
+    fn accepts(&self, _event: &T) -> bool {
+        true
+    }
+
+    /// This function will be called once the listener has been
+    /// added to a dispatcher, listening for `event`.
+    /// By default, it does nothing.
+    ///
+    /// **Note**: Dispatchers lock the listener's lock to call it,
+    /// hence do not hold the lock while adding the listener.
+    fn on_subscribe(&mut self, _event: &T) {}
+
+    /// This function will be called once the listener stopped
+    /// listening for `event` due to `reason`.
+    /// By default, it does nothing.
+    fn on_unsubscribe(&mut self, _event: &T, _reason: UnsubscribeReason) {}
+
+    /// This function will be called once the dispatcher the listener
+    /// is registered at has been dropped, once per dispatcher.
+    /// By default, it does nothing.
+    fn on_dispatcher_dropped(&mut self) {}
+
+    /// Returns all events the listener will listen for once added
+    /// via a dispatcher's `register`-method.
+    /// By default, it returns none.
+    fn subscriptions(&self) -> Vec<T> {
+        Vec::new()
+    }
+}
+
+/// Declares the priority-levels `P` a [`Listener`] listens for its
+/// [`subscriptions`] with, once added via
+/// [`PriorityEventDispatcher::register_prioritised`].
+///
+/// [`Listener`]: trait.Listener.html
+/// [`subscriptions`]: trait.Listener.html#method.subscriptions
+/// [`PriorityEventDispatcher::register_prioritised`]: struct.PriorityEventDispatcher.html#method.register_prioritised
+pub trait SubscriptionPriority<T, P> {
+    /// Returns the priority-level the listener will listen for `event` with,
+    /// `None` uses the priority-level passed to `register_prioritised`.
+    fn subscription_priority(&self, event: &T) -> Option<P>;
+}
+
+impl<T, R, L> Listener<T, R> for Box<L>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    L: Listener<T, R> + ?Sized,
+{
+    fn on_event(&mut self, event: &T) -> Option<R> {
+        (**self).on_event(event)
+    }
+
+    fn on_event_with_context(&mut self, event: &T, context: &mut DispatchContext<T>) -> Option<R> {
+        (**self).on_event_with_context(event, context)
+    }
+
+    fn accepts(&self, event: &T) -> bool {
+        (**self).accepts(event)
+    }
+
+    fn on_subscribe(&mut self, event: &T) {
+        (**self).on_subscribe(event)
+    }
+
+    fn on_unsubscribe(&mut self, event: &T, reason: UnsubscribeReason) {
+        (**self).on_unsubscribe(event, reason)
+    }
+
+    fn on_dispatcher_dropped(&mut self) {
+        (**self).on_dispatcher_dropped()
+    }
+
+    fn subscriptions(&self) -> Vec<T> {
+        (**self).subscriptions()
+    }
+}
+
+/// Wraps an owned [`Listener`] returning requests of type `R`,
+/// translating them via its dispatcher's [`RequestHandler`].
+///
+/// [`Listener`]: trait.Listener.html
+/// [`RequestHandler`]: trait.RequestHandler.html
+struct TranslatingListener<L, T, P, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    listener: L,
+    handler: Arc<SyncRequestHandler<T, P, R>>,
+}
+
+impl<T, P, R, L> Listener<T, ListenerRequest<T, P>> for TranslatingListener<L, T, P, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    L: Listener<T, R>,
+{
+    fn on_event(&mut self, event: &T) -> Option<ListenerRequest<T, P>> {
+        self.listener
+            .on_event(event)
+            .and_then(|request| self.handler.handle(request))
+    }
+
+    fn on_event_with_context(
+        &mut self,
+        event: &T,
+        context: &mut DispatchContext<T>,
+    ) -> Option<ListenerRequest<T, P>> {
+        self.listener
+            .on_event_with_context(event, context)
+            .and_then(|request| self.handler.handle(request))
+    }
+
+    fn accepts(&self, event: &T) -> bool {
+        self.listener.accepts(event)
+    }
+
+    fn on_subscribe(&mut self, event: &T) {
+        self.listener.on_subscribe(event)
+    }
+
+    fn on_unsubscribe(&mut self, event: &T, reason: UnsubscribeReason) {
+        self.listener.on_unsubscribe(event, reason)
+    }
+
+    fn on_dispatcher_dropped(&mut self) {
+        self.listener.on_dispatcher_dropped()
+    }
+
+    fn subscriptions(&self) -> Vec<T> {
+        self.listener.subscriptions()
+    }
+}
+
+impl<T, P, R, L> LockedListener<T, ListenerRequest<T, P>> for TranslatingListener<L, T, P, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    L: LockedListener<T, R>,
+{
+    fn on_event(
+        &self,
+        event: &T,
+        context: &mut DispatchContext<T>,
+    ) -> Option<ListenerRequest<T, P>> {
+        self.listener
+            .on_event(event, context)
+            .and_then(|request| self.handler.handle(request))
+    }
+
+    fn on_subscribe(&self, event: &T) {
+        self.listener.on_subscribe(event)
+    }
+
+    fn on_unsubscribe(&self, event: &T, reason: UnsubscribeReason) {
+        self.listener.on_unsubscribe(event, reason)
+    }
+
+    fn on_dispatcher_dropped(&self) {
+        self.listener.on_dispatcher_dropped()
+    }
+
+    fn is_dead(&self) -> bool {
+        self.listener.is_dead()
+    }
+}
+
+/// Every event-receiver needs to implement this trait
+/// in order to receive dispatched events.
+/// `T` being the type you use for events, e.g. an `Enum`.
+/// `R` being the request type, translated by the dispatcher's [`RequestHandler`].
+///
+/// [`RequestHandler`]: trait.RequestHandler.html
+pub trait ParallelListener<T, R = ParallelDispatcherRequest>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// This function will be called once a listened
+    /// event-type `T` has been dispatched.
+    fn on_event(&mut self, event: &T) -> Option<R>;
+
+    /// Returns whether the listener wants to receive `event`,
+    /// dispatchers skip calling [`on_event`] for rejected events.
+    /// By default, it accepts all events.
+    ///
+    /// It is evaluated before the dispatch is split among threads, hence
+    /// rejecting listeners cause no work to be spawned. If the listener's
+    /// [`ListenerLock`] grants shared access, e.g. a `RwLock`, a read-lock
+    /// is taken, otherwise the listener is locked for the evaluation.
+    ///
+    /// [`on_event`]: trait.ParallelListener.html#tymethod.on_event
+    /// [`ListenerLock`]: trait.ListenerLock.html
+    fn accepts(&self, _event: &T) -> bool {
+        true
+    }
+
+    /// This function will be called once the listener has been
+    /// added to a dispatcher, listening for `event`.
+    /// By default, it does nothing.
+    ///
+    /// **Note**: Dispatchers lock the listener's lock to call it,
+    /// hence do not hold the lock while adding the listener.
+    fn on_subscribe(&mut self, _event: &T) {}
+
+    /// This function will be called once the listener stopped
+    /// listening for `event` due to `reason`.
+    /// By default, it does nothing.
+    fn on_unsubscribe(&mut self, _event: &T, _reason: UnsubscribeReason) {}
+
+    /// This function will be called once the dispatcher the listener
+    /// is registered at has been dropped, once per dispatcher.
+    /// By default, it does nothing.
+    fn on_dispatcher_dropped(&mut self) {}
+
+    /// Returns all events the listener will listen for once added
+    /// via [`ParallelEventDispatcher::register`].
+    /// By default, it returns none.
+    ///
+    /// [`ParallelEventDispatcher::register`]: struct.ParallelEventDispatcher.html#method.register
+    fn subscriptions(&self) -> Vec<T> {
+        Vec::new()
+    }
+}
+
+
+/// Opposed to [`Listener`], this trait receives events via `&self`,
+/// hence the dispatcher does not need to lock a [`Mutex`] before
+/// dispatching.
+/// Any state to mutate has to be synchronised by the implementor,
+/// e.g. by using atomics or a `RwLock`.
+///
+/// [`Listener`]: trait.Listener.html
+/// [`Mutex`]: https://doc.rust-lang.org/std/sync/struct.Mutex.html
+pub trait SharedListener<T, R = SyncDispatcherRequest>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// This function will be called once a listened
+    /// event-type `T` has been dispatched.
+    fn on_event(&self, event: &T) -> Option<R>;
+
+    /// This function will be called instead of [`on_event`] by
+    /// dispatchers supporting follow-up events, passing a [`DispatchContext`]
+    /// to dispatch further events with.
+    /// By default, it calls [`on_event`].
+    ///
+    /// [`on_event`]: trait.SharedListener.html#tymethod.on_event
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    fn on_event_with_context(&self, event: &T, _context: &mut DispatchContext<T>) -> Option<R> {
+        self.on_event(event)
+    }
+
+    /// This function will be called once the listener has been
+    /// added to a dispatcher, listening for `event`.
+    /// By default, it does nothing.
+    fn on_subscribe(&self, _event: &T) {}
+
+    /// This function will be called once the listener stopped
+    /// listening for `event` due to `reason`.
+    /// By default, it does nothing.
+    fn on_unsubscribe(&self, _event: &T, _reason: UnsubscribeReason) {}
+
+    /// This function will be called once the dispatcher the listener
+    /// is registered at has been dropped, once per dispatcher.
+    /// By default, it does nothing.
+    fn on_dispatcher_dropped(&self) {}
+}
+
+/// Opposed to [`ParallelListener`], this trait receives events via `&self`,
+/// hence the dispatcher does not need to lock a [`Mutex`] before
+/// dispatching.
+/// This allows one listener, registered for multiple event-variants,
+/// to process multiple events at the same time.
+/// Any state to mutate has to be synchronised by the implementor,
+/// e.g. by using atomics or a `RwLock`.
+///
+/// [`ParallelListener`]: trait.ParallelListener.html
+/// [`Mutex`]: https://doc.rust-lang.org/std/sync/struct.Mutex.html
+pub trait ParallelSharedListener<T, R = ParallelDispatcherRequest>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// This function will be called once a listened
+    /// event-type `T` has been dispatched.
+    fn on_event(&self, event: &T) -> Option<R>;
+
+    /// This function will be called once the listener has been
+    /// added to a dispatcher, listening for `event`.
+    /// By default, it does nothing.
+    fn on_subscribe(&self, _event: &T) {}
+
+    /// This function will be called once the listener stopped
+    /// listening for `event` due to `reason`.
+    /// By default, it does nothing.
+    fn on_unsubscribe(&self, _event: &T, _reason: UnsubscribeReason) {}
+
+    /// This function will be called once the dispatcher the listener
+    /// is registered at has been dropped, once per dispatcher.
+    /// By default, it does nothing.
+    fn on_dispatcher_dropped(&self) {}
+}
+
+/// Type-erases a [`ListenerLock`] guarding a [`Listener`].
+///
+/// [`ListenerLock`]: trait.ListenerLock.html
+/// [`Listener`]: trait.Listener.html
+trait LockedListener<T, R = SyncDispatcherRequest>: Send + Sync
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn on_event(&self, event: &T, context: &mut DispatchContext<T>) -> Option<R>;
+    fn on_subscribe(&self, event: &T);
+    fn on_unsubscribe(&self, event: &T, reason: UnsubscribeReason);
+    fn on_dispatcher_dropped(&self);
+
+    /// Returns whether the listener has been dropped without unsubscribing,
+    /// for wrappers holding a `Weak`-reference to it.
+    fn is_dead(&self) -> bool {
+        false
+    }
+}
+
+impl<T, R, L> LockedListener<T, R> for L
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    L: ListenerLock,
+    L::Listener: Listener<T, R>,
+{
+    fn on_event(&self, event: &T, context: &mut DispatchContext<T>) -> Option<R> {
+        call_accepting(
+            self,
+            |listener| listener.accepts(event),
+            |listener| listener.on_event_with_context(event, context),
+        )
+    }
+
+    fn on_subscribe(&self, event: &T) {
+        self.with_listener(|listener| listener.on_subscribe(event))
+    }
+
+    fn on_unsubscribe(&self, event: &T, reason: UnsubscribeReason) {
+        self.with_listener(|listener| listener.on_unsubscribe(event, reason))
+    }
+
+    fn on_dispatcher_dropped(&self) {
+        self.with_listener(|listener| listener.on_dispatcher_dropped())
+    }
+}
+
+/// Type-erases a [`ListenerLock`] guarding a [`ParallelListener`].
+///
+/// [`ListenerLock`]: trait.ListenerLock.html
+/// [`ParallelListener`]: trait.ParallelListener.html
+trait ParallelLockedListener<T, R = ParallelDispatcherRequest>: Send + Sync {
+    fn on_event(&self, event: &T) -> Option<R>;
+    /// Returns whether the listener accepts `event`, locking the
+    /// listener only if its lock grants no shared access.
+    fn accepts(&self, event: &T) -> bool;
+    fn on_subscribe(&self, event: &T);
+    fn on_unsubscribe(&self, event: &T, reason: UnsubscribeReason);
+    fn on_dispatcher_dropped(&self);
+
+    /// Returns whether the listener has been dropped without unsubscribing,
+    /// for wrappers holding a `Weak`-reference to it.
+    fn is_dead(&self) -> bool {
+        false
+    }
+}
+
+impl<T, R, L> ParallelLockedListener<T, R> for L
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    L: ListenerLock,
+    L::Listener: ParallelListener<T, R>,
+{
+    fn on_event(&self, event: &T) -> Option<R> {
+        call_accepting(
+            self,
+            |listener| listener.accepts(event),
+            |listener| listener.on_event(event),
+        )
+    }
+
+    fn accepts(&self, event: &T) -> bool {
+        check_accepts(self, |listener| listener.accepts(event))
+    }
+
+    fn on_subscribe(&self, event: &T) {
+        self.with_listener(|listener| listener.on_subscribe(event))
+    }
+
+    fn on_unsubscribe(&self, event: &T, reason: UnsubscribeReason) {
+        self.with_listener(|listener| listener.on_unsubscribe(event, reason))
+    }
+
+    fn on_dispatcher_dropped(&self) {
+        self.with_listener(|listener| listener.on_dispatcher_dropped())
+    }
+}
+
+/// Owns a map of all listened event-variants,
+/// [`Weak`]-references to their listeners and [`Fn`]s.
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+pub struct EventDispatcher<T, R = SyncDispatcherRequest>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    events: ListenerMap<T, R>,
+    request_handler: Arc<SyncRequestHandler<T, (), R>>,
+    max_dispatch_depth: usize,
+    queue: VecDeque<QueuedEvent<T>>,
+    max_flush_events: usize,
+    /// Listeners stopping to listen to all events during the current dispatch.
+    detached: FnsAndTraits<T, (), R>,
+    dead_listener_handler: Option<DeadListenerHandler<T>>,
+    /// Listeners receiving all events.
+    global: Arc<FnsAndTraits<T, (), R>>,
+    unhandled_handler: Option<UnhandledHandler<T>>,
+    emit_error_handler: Option<EmitErrorHandler<T>>,
+    dead_letters: DeadLetters<T>,
+    /// Returns an event's parent, if the event-hierarchy is used.
+    parent: Option<ParentFn<T>>,
+    /// Listeners subscribed to ranges of events, once any has been added.
+    ranges: Option<Box<RangeListeners<T, R>>>,
+    stamper: Stamper,
+    /// Records dispatched events, once tracing has been enabled.
+    tracer: Option<Tracer<T>>,
+    /// Listeners able to cancel events dispatched via `dispatch_cancellable`.
+    vetoes: HashMap<T, VetoListeners<T>>,
+}
+
+impl<T, R> Default for EventDispatcher<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: Into<ListenerRequest<T>> + 'static,
+{
+    fn default() -> EventDispatcher<T, R> {
+        EventDispatcher::new_with_request_handler(|request: R| Some(request))
+    }
+}
+
+impl<T, R> EventDispatcher<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: 'static,
+{
+    /// Creates a dispatcher whose listeners return requests of type `R`,
+    /// translated by `handler` into requests `O`, e.g.
+    /// [`SyncDispatcherRequest`] or [`ListenerRequest`].
+    ///
+    /// See [`RequestHandler`] for an example.
+    ///
+    /// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
+    /// [`ListenerRequest`]: enum.ListenerRequest.html
+    /// [`RequestHandler`]: trait.RequestHandler.html
+    pub fn new_with_request_handler<H, O>(handler: H) -> Self
+    where
+        H: RequestHandler<R, O> + 'static,
+        O: Into<ListenerRequest<T>> + 'static,
+    {
+        EventDispatcher {
+            events: ListenerMap::new(),
+            request_handler: translating_handler(handler),
+            max_dispatch_depth: DEFAULT_MAX_DISPATCH_DEPTH,
+            queue: VecDeque::new(),
+            max_flush_events: usize::MAX,
+            detached: FnsAndTraits::default(),
+            dead_listener_handler: None,
+            global: Arc::default(),
+            unhandled_handler: None,
+            emit_error_handler: None,
+            dead_letters: DeadLetters::new(),
+            parent: None,
+            ranges: None,
+            stamper: Stamper::new(),
+            tracer: None,
+            vetoes: HashMap::new(),
+        }
+    }
+
+    /// Adds a [`Listener`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// **Note**: If your `Enum` owns fields you need to consider implementing
+    /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields,
+    /// see second example for an implementation-suggestion.
+    ///
+    /// # Examples
+    ///
+    /// Adding a [`Listener`] to the dispatcher:
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use hey_listen::{Listener, EventDispatcher, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct {}
+    ///
+    /// impl Listener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> { None }
+    /// }
+    ///
+    /// fn main() {
+    ///     let listener = Arc::new(Mutex::new(ListenerStruct {}));
+    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///
+    ///     dispatcher.add_listener(Event::EventType, &listener);
+    /// }
+    /// ```
+    ///
+    /// Declaring your own [`Hash`]- and [`PartialEq`]-trait to bypass
+    /// hashing on fields:
+    ///
+    /// ```rust
+    /// use std::hash::{Hash, Hasher};
+    /// use std::mem::discriminant;
+    ///
+    /// #[derive(Clone)]
+    /// enum Event {
+    ///     TestVariant(i32),
+    /// }
+    ///
+    /// impl Hash for Event {
+    ///     fn hash<H: Hasher>(&self, _state: &mut H) {}
+    /// }
+    ///
+    /// impl PartialEq for Event {
+    ///     fn eq(&self, other: &Event) -> bool {
+    ///         discriminant(self) == discriminant(other)
+    ///     }
+    /// }
+    ///
+    /// impl Eq for Event {}
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
+    /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_listener<L>(&mut self, event_identifier: T, listener: &Arc<L>)
+    where
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+    {
+        let listener = Arc::clone(listener) as Arc<LockedListener<T, R>>;
+        listener.on_subscribe(&event_identifier);
+        let weak_listener = Arc::downgrade(&listener);
+
+        if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
+            Arc::make_mut(listener_collection).traits.push(weak_listener);
+
+            return;
+        }
+
+        self.events.insert(
+            event_identifier,
+            Arc::new(FnsAndTraits::new_with_traits(vec![weak_listener])),
+        );
+    }
+
+    /// Adds a [`Listener`] to listen for all events returned by
+    /// its [`subscriptions`]-method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use hey_listen::{EventDispatcher, Listener, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Opened,
+    ///     Closed,
+    /// }
+    ///
+    /// struct ListenerStruct {
+    ///     received_events: usize,
+    /// }
+    ///
+    /// impl Listener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, _: &Event) -> Option<SyncDispatcherRequest> {
+    ///         self.received_events += 1;
+    ///
+    ///         None
+    ///     }
+    ///
+    ///     fn subscriptions(&self) -> Vec<Event> {
+    ///         vec![Event::Opened, Event::Closed]
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let listener = Arc::new(Mutex::new(ListenerStruct { received_events: 0 }));
+    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///
+    ///     dispatcher.register(&listener);
+    ///     dispatcher.dispatch_event(&Event::Opened);
+    ///     dispatcher.dispatch_event(&Event::Closed);
+    ///
+    ///     assert_eq!(listener.lock().unwrap().received_events, 2);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`subscriptions`]: trait.Listener.html#method.subscriptions
+    pub fn register<L>(&mut self, listener: &Arc<L>)
+    where
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+    {
+        let subscriptions = listener.with_listener(|listener| listener.subscriptions());
+        self.add_listener_for(subscriptions, listener);
+    }
+
+    /// Adds a [`Listener`] to listen for all `event_identifiers`.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    pub fn add_listener_for<I, L>(&mut self, event_identifiers: I, listener: &Arc<L>)
+    where
+        I: IntoIterator<Item = T>,
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+    {
+        for event_identifier in event_identifiers {
+            self.add_listener(event_identifier, listener);
+        }
+    }
+
+    /// Adds a [`Listener`] to listen for an `event_identifier`, only
+    /// receiving events `predicate` returns `true` for.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// `predicate` is evaluated before the listener's lock is taken,
+    /// hence rejected events do not lock the listener at all.
+    /// The dispatcher holds a [`Weak`]-reference to the listener and
+    /// owns the filter, which can be removed via [`remove_listener`]
+    /// using the returned [`ListenerId`].
+    ///
+    /// # Examples
+    ///
+    /// Listening to a single sensor:
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use std::hash::{Hash, Hasher};
+    /// use std::mem::discriminant;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use hey_listen::{EventDispatcher, Listener, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone)]
+    /// enum Event {
+    ///     Temperature(u32),
+    /// }
+    ///
+    /// impl Hash for Event {
+    ///     fn hash<H: Hasher>(&self, _state: &mut H) {}
+    /// }
+    ///
+    /// impl PartialEq for Event {
+    ///     fn eq(&self, other: &Event) -> bool {
+    ///         discriminant(self) == discriminant(other)
+    ///     }
+    /// }
+    ///
+    /// impl Eq for Event {}
+    ///
+    /// struct Thermometer {
+    ///     readings: usize,
+    /// }
+    ///
+    /// impl Listener<Event> for Thermometer {
+    ///     fn on_event(&mut self, _: &Event) -> Option<SyncDispatcherRequest> {
+    ///         self.readings += 1;
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let thermometer = Arc::new(Mutex::new(Thermometer { readings: 0 }));
+    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///
+    ///     dispatcher.add_listener_filtered(Event::Temperature(0), &thermometer, |event| {
+    ///         match *event {
+    ///             Event::Temperature(sensor) => sensor == 42,
+    ///         }
+    ///     });
+    ///
+    ///     dispatcher.dispatch_event(&Event::Temperature(7));
+    ///     dispatcher.dispatch_event(&Event::Temperature(42));
+    ///
+    ///     assert_eq!(thermometer.lock().unwrap().readings, 1);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`ListenerId`]: struct.ListenerId.html
+    /// [`remove_listener`]: #method.remove_listener
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_listener_filtered<L, F>(
+        &mut self,
+        event_identifier: T,
+        listener: &Arc<L>,
+        predicate: F,
+    ) -> ListenerId
+    where
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        let listener = self.translating(FilteredListener::new(listener, predicate));
+
+        self.insert_owned(event_identifier, listener)
+    }
+
+    /// Hands a [`Listener`] over to the dispatcher, listening for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// Opposed to [`add_listener`], the dispatcher owns the [`Listener`],
+    /// hence it lives until it requests to stop listening or is removed
+    /// via [`remove_listener`] using the returned [`ListenerId`].
+    ///
+    /// # Examples
+    ///
+    /// Adding an owned [`Listener`] to the dispatcher:
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use hey_listen::{Listener, EventDispatcher, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct {}
+    ///
+    /// impl Listener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> { None }
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///
+    ///     let id = dispatcher.add_owned_listener(Event::EventType, ListenerStruct {});
+    ///     dispatcher.dispatch_event(&Event::EventType);
+    ///
+    ///     assert!(dispatcher.remove_listener(id));
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`ListenerId`]: struct.ListenerId.html
+    /// [`add_listener`]: struct.EventDispatcher.html#method.add_listener
+    /// [`remove_listener`]: struct.EventDispatcher.html#method.remove_listener
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_owned_listener<D: Listener<T, R> + Send + Sync + 'static>(
+        &mut self,
+        event_identifier: T,
+        listener: D,
+    ) -> ListenerId {
+        let listener = self.translating(Mutex::new(listener));
+
+        self.insert_owned(event_identifier, listener)
+    }
+
+    /// Wraps `listener` to translate its requests via the request handler.
+    fn translating<L>(&self, listener: L) -> Arc<OwnedLockedListener<T, ()>>
+    where
+        L: LockedListener<T, R> + 'static,
+    {
+        Arc::new(TranslatingListener {
+            listener,
+            handler: Arc::clone(&self.request_handler),
+        })
+    }
+
+    /// Adds the owned `listener` to the listener-collection of `event_identifier`.
+    fn insert_owned(
+        &mut self,
+        event_identifier: T,
+        listener: Arc<OwnedLockedListener<T, ()>>,
+    ) -> ListenerId {
+        let id = ListenerId::new();
+        let owned = OwnedListener { id, listener };
+        owned.listener.on_subscribe(&event_identifier);
+
+        if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
+            Arc::make_mut(listener_collection).owned.push(owned);
+
+            return id;
+        }
+
+        self.events.insert(
+            event_identifier,
+            Arc::new(FnsAndTraits::new_with_owned(vec![owned])),
+        );
+
+        id
+    }
+
+    /// Adds a [`SharedListener`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// Opposed to [`add_listener`], the dispatcher stores a [`Weak`]-reference
+    /// to the [`SharedListener`] itself, hence no [`Mutex`] is required.
+    ///
+    /// # Examples
+    ///
+    /// Adding a [`SharedListener`] to the dispatcher:
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// use hey_listen::{SharedListener, EventDispatcher, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct {
+    ///     received_events: AtomicUsize,
+    /// }
+    ///
+    /// impl SharedListener<Event> for ListenerStruct {
+    ///     fn on_event(&self, event: &Event) -> Option<SyncDispatcherRequest> {
+    ///         self.received_events.fetch_add(1, Ordering::SeqCst);
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let listener = Arc::new(ListenerStruct { received_events: AtomicUsize::new(0) });
+    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///
+    ///     dispatcher.add_shared_listener(Event::EventType, &listener);
+    ///     dispatcher.dispatch_event(&Event::EventType);
+    ///
+    ///     assert_eq!(listener.received_events.load(Ordering::SeqCst), 1);
+    /// }
+    /// ```
+    ///
+    /// [`SharedListener`]: trait.SharedListener.html
+    /// [`add_listener`]: struct.EventDispatcher.html#method.add_listener
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    /// [`Mutex`]: https://doc.rust-lang.org/std/sync/struct.Mutex.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_shared_listener<D: SharedListener<T, R> + Send + Sync + 'static>(
+        &mut self,
+        event_identifier: T,
+        listener: &Arc<D>,
+    ) {
+        listener.on_subscribe(&event_identifier);
+        let weak_listener = Arc::downgrade(
+            &(Arc::clone(listener) as Arc<SharedListener<T, R> + Send + Sync + 'static>),
+        );
+
+        if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
+            Arc::make_mut(listener_collection).shared.push(weak_listener);
+
+            return;
+        }
+
+        self.events.insert(
+            event_identifier,
+            Arc::new(FnsAndTraits::new_with_shared(vec![weak_listener])),
+        );
+    }
+
+    /// Removes the [`Listener`] identified by `id` from the dispatcher,
+    /// dropping it if owned.
+    /// Returns `false` if no such [`Listener`] has been found.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    pub fn remove_listener(&mut self, id: ListenerId) -> bool {
+        let removed = self
+            .events
+            .iter_mut()
+            .any(|(event_identifier, listener_collection)| {
+                Arc::make_mut(listener_collection).remove_owned(event_identifier, id)
+            });
+
+        match self.ranges {
+            Some(ref mut ranges) if !removed => ranges.remove_owned(id),
+            _ => removed,
+        }
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// **Note**: If your `Enum` owns fields you need to consider implementing
+    /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields.
+    ///
+    /// # Examples
+    ///
+    /// Adding a [`Fn`] to the dispatcher:
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use hey_listen::EventDispatcher;
+    /// use hey_listen::SyncDispatcherRequest;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct EventListener {
+    ///     used_method: bool,
+    /// }
+    ///
+    /// impl EventListener {
+    ///     fn test_method(&mut self, _event: &Event) {
+    ///         self.used_method = true;
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let listener = Arc::new(Mutex::new(EventListener { used_method: false }));
+    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///     let weak_listener_ref = Arc::downgrade(&Arc::clone(&listener));
+    ///
+    ///     let closure = Box::new(move |event: &Event| -> Option<SyncDispatcherRequest> {
+    ///         if let Some(listener) = weak_listener_ref.upgrade() {
+    ///             listener.lock().expect("TODO:").test_method(&event);
+    ///
+    ///             None
+    ///         } else {
+    ///             Some(SyncDispatcherRequest::StopListening)
+    ///         }
+    ///     });
+    ///
+    ///     dispatcher.add_fn(Event::EventType, closure);
+    /// }
+    /// ```
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
+    /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_fn(&mut self, event_identifier: T, function: BoxedFn<T, R>) {
+        self.add_context_fn(
+            event_identifier,
+            Box::new(move |event: &T, _: &mut DispatchContext<T>| function(event)),
+        );
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier`, receiving
+    /// a [`DispatchContext`] to dispatch follow-up events with.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// # Examples
+    ///
+    /// Dispatching a follow-up event:
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use hey_listen::{DispatchContext, EventDispatcher};
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Pressed,
+    ///     Clicked,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///
+    ///     dispatcher.add_context_fn(Event::Pressed, Box::new(|_: &Event, context: &mut DispatchContext<Event>| {
+    ///         context.dispatch(Event::Clicked).expect("Clicked causes no cycle.");
+    ///
+    ///         None
+    ///     }));
+    ///
+    ///     dispatcher.dispatch_event(&Event::Pressed);
+    /// }
+    /// ```
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_context_fn(&mut self, event_identifier: T, function: BoxedContextFn<T, R>) {
+        self.insert_fn(event_identifier, Arc::from(function));
+    }
+
+    /// Adds an [`EnvelopeListener`] to listen for an `event_identifier`,
+    /// receiving each dispatched event wrapped in its [`Envelope`].
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// Like [`add_listener`], the dispatcher holds a [`Weak`]-reference
+    /// only, the listener can be removed via [`remove_listener`]
+    /// using the returned [`ListenerId`].
+    ///
+    /// [`EnvelopeListener`]: trait.EnvelopeListener.html
+    /// [`Envelope`]: struct.Envelope.html
+    /// [`add_listener`]: #method.add_listener
+    /// [`remove_listener`]: #method.remove_listener
+    /// [`ListenerId`]: struct.ListenerId.html
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_envelope_listener<L>(&mut self, event_identifier: T, listener: &Arc<L>) -> ListenerId
+    where
+        L: ListenerLock,
+        L::Listener: EnvelopeListener<T, R>,
+    {
+        let listener = self.translating(EnvelopedListener::new(listener));
+
+        self.insert_owned(event_identifier, listener)
+    }
+
+    /// Adds an [`Fn`] to listen for an `event_identifier`, receiving
+    /// each dispatched event wrapped in its [`Envelope`].
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// See [`Envelope`] for an example.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Envelope`]: struct.Envelope.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_envelope_fn(&mut self, event_identifier: T, function: BoxedEnvelopeFn<T, R>) {
+        self.add_context_fn(
+            event_identifier,
+            Box::new(move |_: &T, context: &mut DispatchContext<T>| function(context.envelope())),
+        );
+    }
+
+    /// Adds a [`VetoListener`] asked before `event_identifier` is
+    /// dispatched via [`dispatch_cancellable`], able to cancel it.
+    /// The dispatcher holds a [`Weak`]-reference only.
+    ///
+    /// See [`VetoListener`] for an example.
+    ///
+    /// [`VetoListener`]: trait.VetoListener.html
+    /// [`dispatch_cancellable`]: #method.dispatch_cancellable
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    pub fn add_veto_listener<L>(&mut self, event_identifier: T, listener: &Arc<L>)
+    where
+        L: ListenerLock,
+        L::Listener: VetoListener<T>,
+    {
+        self.vetoes
+            .entry(event_identifier)
+            .or_default()
+            .push_listener(listener);
+    }
+
+    /// Adds an [`Fn`] called before `event_identifier` is dispatched via
+    /// [`dispatch_cancellable`], returning a [`Veto`] cancels the dispatch.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`dispatch_cancellable`]: #method.dispatch_cancellable
+    /// [`Veto`]: struct.Veto.html
+    pub fn add_veto_fn(&mut self, event_identifier: T, function: BoxedVetoFn<T>) {
+        self.vetoes
+            .entry(event_identifier)
+            .or_default()
+            .push_fn(function);
+    }
+
+    /// Adds one [`Fn`] to listen for all `event_identifiers`.
+    /// The [`Fn`] is stored once and shared among all events,
+    /// hence its captured state is not cloned.
+    ///
+    /// Returning `SyncDispatcherRequest::StopListening` removes it from
+    /// the dispatched event only, `StopListeningToAll` from all events.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// use hey_listen::EventDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Opened,
+    ///     Closed,
+    /// }
+    ///
+    /// fn main() {
+    ///     let counter = Arc::new(AtomicUsize::new(0));
+    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///
+    ///     let fn_counter = Arc::clone(&counter);
+    ///     dispatcher.add_fn_for(vec![Event::Opened, Event::Closed], Box::new(move |_: &Event| {
+    ///         fn_counter.fetch_add(1, Ordering::SeqCst);
+    ///
+    ///         None
+    ///     }));
+    ///
+    ///     dispatcher.dispatch_event(&Event::Opened);
+    ///     dispatcher.dispatch_event(&Event::Closed);
+    ///
+    ///     assert_eq!(counter.load(Ordering::SeqCst), 2);
+    /// }
+    /// ```
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    pub fn add_fn_for<I>(
+        &mut self,
+        event_identifiers: I,
+        function: BoxedFn<T, R>,
+    ) where
+        I: IntoIterator<Item = T>,
+    {
+        let function: Arc<ContextFn<T, R>> =
+            Arc::new(move |event: &T, _: &mut DispatchContext<T>| function(event));
+
+        for event_identifier in event_identifiers {
+            self.insert_fn(event_identifier, Arc::clone(&function));
+        }
+    }
+
+    /// Adds a [`Listener`] to listen for all events inside `range`.
+    ///
+    /// Ranges are indexed by their start, a dispatched event is received
+    /// by the listeners of its own event first, followed by the listeners
+    /// of all ranges containing it, ordered by the ranges' start.
+    ///
+    /// Returns a [`ListenerId`] to remove the listener via
+    /// [`remove_listener`]. Only a [`Weak`]-reference to the listener is
+    /// held, once dropped, the dispatcher removes it.
+    ///
+    /// Only the `EventDispatcher` supports ranges.
+    ///
+    /// **Note**: `on_subscribe` and, once removed via [`remove_listener`],
+    /// `on_unsubscribe` receive the start of `range`, neither is called for
+    /// ranges unbounded at their start. Once the listener stops listening,
+    /// `on_unsubscribe` receives the dispatched event.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use hey_listen::{EventDispatcher, Listener, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    /// struct Code(u16);
+    ///
+    /// struct ClientErrorHandler {
+    ///     handled: Vec<Code>,
+    /// }
+    ///
+    /// impl Listener<Code> for ClientErrorHandler {
+    ///     fn on_event(&mut self, code: &Code) -> Option<SyncDispatcherRequest> {
+    ///         self.handled.push(code.clone());
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let listener = Arc::new(Mutex::new(ClientErrorHandler { handled: vec![] }));
+    ///     let mut dispatcher: EventDispatcher<Code> = EventDispatcher::default();
+    ///
+    ///     dispatcher.add_listener_range(Code(400)..Code(500), &listener);
+    ///
+    ///     dispatcher.dispatch_event(&Code(200));
+    ///     dispatcher.dispatch_event(&Code(404));
+    ///
+    ///     assert_eq!(listener.lock().unwrap().handled, [Code(404)]);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`ListenerId`]: struct.ListenerId.html
+    /// [`remove_listener`]: #method.remove_listener
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    pub fn add_listener_range<B, L>(&mut self, range: B, listener: &Arc<L>) -> ListenerId
+    where
+        T: Ord,
+        B: RangeBounds<T>,
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+    {
+        let range = EventRange::new(&range);
+        // Filtered listeners hold a `Weak`-reference, accepting all events.
+        let listener = self.translating(FilteredListener::new(listener, |_: &T| true));
+        let id = ListenerId::new();
+
+        if let Some(start) = range.start() {
+            listener.on_subscribe(start);
+        }
+
+        self.range_listeners(range)
+            .owned
+            .push(OwnedListener { id, listener });
+
+        id
+    }
+
+    /// Adds a [`Fn`] to listen for all events inside `range`,
+    /// as described at [`add_listener_range`].
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`add_listener_range`]: #method.add_listener_range
+    pub fn add_fn_range<B>(
+        &mut self,
+        range: B,
+        function: BoxedFn<T, R>,
+    ) where
+        T: Ord,
+        B: RangeBounds<T>,
+    {
+        self.range_listeners(EventRange::new(&range)).fns.push(Arc::new(
+            move |event: &T, _: &mut DispatchContext<T>| function(event),
+        ));
+    }
+
+    /// Returns the listener-collection of `range`, creating
+    /// the index of ranges and the collection if missing.
+    fn range_listeners(&mut self, range: EventRange<T>) -> &mut FnsAndTraits<T, (), R>
+    where
+        T: Ord,
+    {
+        self.ranges
+            .get_or_insert_with(|| Box::new(RangeIndex::new()))
+            .entry(range)
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier`, only
+    /// being called with events `predicate` returns `true` for.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_fn_filtered<F>(
+        &mut self,
+        event_identifier: T,
+        function: BoxedFn<T, R>,
+        predicate: F,
+    ) where
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        self.add_fn(
+            event_identifier,
+            Box::new(move |event: &T| {
+                if predicate(event) {
+                    function(event)
+                } else {
+                    None
+                }
+            }),
+        );
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier`, calling it with
+    /// the value guarded by `target` alongside the event.
+    /// Only a [`Weak`]-reference to `target` is held, once `target` has been
+    /// dropped, the [`Fn`] is removed.
+    ///
+    /// # Examples
+    ///
+    /// Calling a method on every event:
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use hey_listen::EventDispatcher;
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct Window {
+    ///     redraws: usize,
+    /// }
+    ///
+    /// impl Window {
+    ///     fn redraw(&mut self) {
+    ///         self.redraws += 1;
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let window = Arc::new(Mutex::new(Window { redraws: 0 }));
+    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///
+    ///     dispatcher.add_weak_fn(Event::EventType, &window, |window: &mut Window, _: &Event| {
+    ///         window.redraw();
+    ///
+    ///         None
+    ///     });
+    ///
+    ///     dispatcher.dispatch_event(&Event::EventType);
+    ///     assert_eq!(window.lock().unwrap().redraws, 1);
+    /// }
+    /// ```
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    pub fn add_weak_fn<L, F>(&mut self, event_identifier: T, target: &Arc<L>, function: F)
+    where
+        L: ListenerLock,
+        F: Fn(&mut L::Listener, &T) -> Option<R> + Send + Sync + 'static,
+    {
+        let listener = self.translating(WeakFn::new(target, function));
+        self.insert_owned(event_identifier, listener);
+    }
+
+    /// Adds a [`Listener`] to listen for all events.
+    ///
+    /// Global listeners receive an event after all listeners registered
+    /// for the event, unless propagation has been stopped.
+    /// As they do not subscribe to a specific event, [`on_subscribe`]
+    /// is not called.
+    ///
+    /// **Note**: Global listeners do not receive follow-up events
+    /// dispatched immediately by global listeners, follow-up events
+    /// queued via [`DispatchContext::enqueue`] are received.
+    ///
+    /// # Examples
+    ///
+    /// Logging all events:
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use hey_listen::{EventDispatcher, Listener, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Opened,
+    ///     Closed,
+    /// }
+    ///
+    /// struct Logger {
+    ///     log: Vec<Event>,
+    /// }
+    ///
+    /// impl Listener<Event> for Logger {
+    ///     fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> {
+    ///         self.log.push(event.clone());
+    ///
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let logger = Arc::new(Mutex::new(Logger { log: vec![] }));
+    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///
+    ///     dispatcher.add_global_listener(&logger);
+    ///     dispatcher.dispatch_event(&Event::Opened);
+    ///     dispatcher.dispatch_event(&Event::Closed);
+    ///
+    ///     assert_eq!(logger.lock().unwrap().log, [Event::Opened, Event::Closed]);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`on_subscribe`]: trait.Listener.html#method.on_subscribe
+    /// [`DispatchContext::enqueue`]: struct.DispatchContext.html#method.enqueue
+    pub fn add_global_listener<L>(&mut self, listener: &Arc<L>)
+    where
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+    {
+        let listener = Arc::clone(listener) as Arc<LockedListener<T, R>>;
+        Arc::make_mut(&mut self.global)
+            .traits
+            .push(Arc::downgrade(&listener));
+    }
+
+    /// Adds a [`Fn`] to listen for all events.
+    ///
+    /// See [`add_global_listener`] for when global listeners are called.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`add_global_listener`]: #method.add_global_listener
+    pub fn add_global_fn(&mut self, function: BoxedFn<T, R>) {
+        Arc::make_mut(&mut self.global).fns.push(Arc::new(
+            move |event: &T, _: &mut DispatchContext<T>| function(event),
+        ));
+    }
+
+    /// Adds `function` to the listener-collection of `event_identifier`.
+    fn insert_fn(&mut self, event_identifier: T, function: Arc<ContextFn<T, R>>) {
+        if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
+            Arc::make_mut(listener_collection).fns.push(function);
+
+            return;
+        }
+
+        self.events.insert(
+            event_identifier,
+            Arc::new(FnsAndTraits::new_with_fns(vec![function])),
+        );
+    }
+
+    /// All [`Listener`]s listening to a passed `event_identifier`
+    /// will be called via their implemented [`on_event`]-method.
+    /// [`Fn`]s returning [`Result`] with `Ok(())` will be retained
+    /// and `Err(SyncDispatcherRequest::StopListening)` will cause them to
+    /// be removed from the event-dispatcher.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`on_event`]: trait.Listener.html#tymethod.on_event
+    /// [`Error`]: enum.Error.html
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Box`]: https://doc.rust-lang.org/std/boxed/struct.Box.html
+    /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
+    pub fn dispatch_event(&mut self, event_identifier: &T) {
+        self.dispatch_correlated(event_identifier, CorrelationId::new());
+    }
+
+    /// Dispatches `event_identifier` like [`dispatch_event`], continuing
+    /// the correlation `correlation_id` instead of starting a new one,
+    /// e.g. for an event caused by an event received from another service.
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    pub fn dispatch_correlated(&mut self, event_identifier: &T, correlation_id: CorrelationId) {
+        self.dispatch_propagating(event_identifier, correlation_id);
+    }
+
+    /// Dispatches `event_identifier` like [`dispatch_correlated`],
+    /// returning whether a listener stopped its propagation.
+    ///
+    /// [`dispatch_correlated`]: #method.dispatch_correlated
+    pub(crate) fn dispatch_propagating(
+        &mut self,
+        event_identifier: &T,
+        correlation_id: CorrelationId,
+    ) -> ExecuteRequestsResult {
+        let (max_depth, tracer) = (self.max_dispatch_depth, self.tracer.take());
+        let (result, tracer) =
+            dispatch_chain(self, event_identifier, max_depth, correlation_id, tracer);
+
+        self.tracer = tracer;
+        self.detached = FnsAndTraits::default();
+
+        result
+    }
+
+    /// Dispatches `event_identifier` in two phases: First, the veto-listeners
+    /// of the event and its ancestors are asked whether it may be dispatched,
+    /// in the order they have been added. Unless any of them returns a
+    /// [`Veto`], the event is dispatched to all listeners like via
+    /// [`dispatch_event`].
+    ///
+    /// Returns the first [`Veto`], which stops asking further veto-listeners.
+    /// Events dispatched via [`dispatch_event`] skip veto-listeners.
+    ///
+    /// See [`VetoListener`] for an example.
+    ///
+    /// [`Veto`]: struct.Veto.html
+    /// [`VetoListener`]: trait.VetoListener.html
+    /// [`dispatch_event`]: #method.dispatch_event
+    pub fn dispatch_cancellable(&mut self, event_identifier: &T) -> Result<(), Veto> {
+        // Cyclic hierarchies are reported once the event is dispatched.
+        for key in Lineage::new(event_identifier, self.parent, self.max_dispatch_depth) {
+            if let Some(vetoes) = self.vetoes.get_mut(&*key) {
+                let (result, dead) = vetoes.check(event_identifier);
+                report_dead_listeners(&self.dead_listener_handler, &key, dead, true);
+                result?;
+            }
+        }
+
+        self.dispatch_event(event_identifier);
+
+        Ok(())
+    }
+
+    /// Sets the [`Clock`] providing the timestamps of [`Envelope`]s,
+    /// defaults to [`SystemClock`].
+    ///
+    /// [`Clock`]: trait.Clock.html
+    /// [`Envelope`]: struct.Envelope.html
+    /// [`SystemClock`]: struct.SystemClock.html
+    pub fn clock<C: Clock + 'static>(&mut self, clock: C) {
+        self.stamper.set_clock(Box::new(clock));
+    }
+
+    /// Sets the source ID carried by [`Envelope`]s, unset by default.
+    ///
+    /// [`Envelope`]: struct.Envelope.html
+    pub fn source_id<S: Into<String>>(&mut self, source_id: S) {
+        self.stamper.set_source_id(source_id.into());
+    }
+
+    /// Starts recording which listeners receive dispatched events and
+    /// which follow-up events they dispatch, taken via [`take_trace`].
+    ///
+    /// See [`Trace`] for an example.
+    ///
+    /// [`take_trace`]: #method.take_trace
+    /// [`Trace`]: struct.Trace.html
+    pub fn enable_tracing(&mut self) {
+        if self.tracer.is_none() {
+            self.tracer = Some(Tracer::new());
+        }
+    }
+
+    /// Takes all events recorded since tracing has been enabled or
+    /// the trace has last been taken, empty if tracing is disabled.
+    pub fn take_trace(&mut self) -> Trace<T> {
+        self.tracer
+            .as_mut()
+            .map_or_else(Trace::default, Tracer::take)
+    }
+
+    /// Sets the maximum depth of follow-up events dispatched via
+    /// [`DispatchContext`], defaults to `32`.
+    ///
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    pub fn max_dispatch_depth(&mut self, depth: usize) {
+        self.max_dispatch_depth = depth;
+    }
+
+    /// Dispatches events to the listeners of all their ancestors
+    /// as well, as reported by [`EventHierarchy`], the listeners of
+    /// the most specific key first.
+    ///
+    /// Propagation stopped by a listener also stops propagation to
+    /// the listeners of ancestors.
+    ///
+    /// **Note**: Listeners of an ancestor do not receive follow-up events
+    /// dispatched immediately by listeners of the same ancestor,
+    /// follow-up events queued via [`DispatchContext::enqueue`] are received.
+    ///
+    /// See [`EventHierarchy`] for an example.
+    ///
+    /// [`EventHierarchy`]: trait.EventHierarchy.html
+    /// [`DispatchContext::enqueue`]: struct.DispatchContext.html#method.enqueue
+    pub fn use_event_hierarchy(&mut self)
+    where
+        T: EventHierarchy,
+    {
+        self.parent = Some(T::parent);
+    }
+
+    /// Queues `event_identifier` to be dispatched on the next [`flush`].
+    ///
+    /// [`flush`]: #method.flush
+    pub fn queue_event(&mut self, event_identifier: T) {
+        self.queue.push_back(QueuedEvent::new(event_identifier));
+    }
+
+    /// Dispatches all queued events in the order they have been queued.
+    /// Events queued by listeners via [`DispatchContext`] during the flush
+    /// are dispatched by the same flush.
+    ///
+    /// At most as many queued events as set via [`max_flush_events`]
+    /// are dispatched, the remaining events stay queued.
+    ///
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    /// [`max_flush_events`]: #method.max_flush_events
+    pub fn flush(&mut self) {
+        let queue = ::std::mem::take(&mut self.queue);
+        let (max_depth, limit) = (self.max_dispatch_depth, self.max_flush_events);
+        let tracer = self.tracer.take();
+
+        let (queue, tracer) = flush_queue(self, queue, max_depth, limit, tracer);
+        self.queue = queue;
+        self.tracer = tracer;
+        self.detached = FnsAndTraits::default();
+    }
+
+    /// Sets the maximum amount of queued events dispatched per [`flush`],
+    /// unlimited by default.
+    ///
+    /// [`flush`]: #method.flush
+    pub fn max_flush_events(&mut self, limit: usize) {
+        self.max_flush_events = limit;
+    }
+
+    /// Sets `handler` to be called with a [`DeadListener`] for each
+    /// listener found dropped by its owner without unsubscribing,
+    /// either while dispatching or when the dispatcher drops.
+    ///
+    /// Global and range listeners are only reported while dispatching,
+    /// as there is no event to report them with once the dispatcher drops.
+    /// The same holds for all other dispatchers.
+    /// Veto-listeners are reported when asked via `dispatch_cancellable`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use hey_listen::{EventDispatcher, Listener, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct;
+    ///
+    /// impl Listener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, _: &Event) -> Option<SyncDispatcherRequest> {
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///     let listener = Arc::new(Mutex::new(ListenerStruct));
+    ///
+    ///     dispatcher.on_dead_listener(|dead| {
+    ///         assert!(dead.missed_dispatch());
+    ///         println!("A listener of {:?} has been dropped.", dead.event());
+    ///     });
+    ///     dispatcher.add_listener(Event::EventType, &listener);
+    ///
+    ///     drop(listener);
+    ///     dispatcher.dispatch_event(&Event::EventType);
+    /// }
+    /// ```
+    ///
+    /// [`DeadListener`]: struct.DeadListener.html
+    pub fn on_dead_listener<F>(&mut self, handler: F)
+    where
+        F: Fn(&DeadListener<T>) + Send + Sync + 'static,
+    {
+        self.dead_listener_handler = Some(Box::new(handler));
+    }
+
+    /// Sets `handler` to be called with each dispatched event no listener
+    /// has been registered for, either as none has been added for the event
+    /// or all of them have been dropped without unsubscribing.
+    /// Global listeners are not considered.
+    ///
+    /// # Examples
+    ///
+    /// Keeping unhandled events to dispatch them again later:
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use hey_listen::EventDispatcher;
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Opened,
+    ///     Closed,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///
+    ///     dispatcher.on_unhandled(|event| println!("No listener for {:?}.", event));
+    ///     dispatcher.dead_letter_capacity(16);
+    ///
+    ///     dispatcher.add_fn(Event::Opened, Box::new(|_: &Event| None));
+    ///     dispatcher.dispatch_event(&Event::Opened);
+    ///     dispatcher.dispatch_event(&Event::Closed);
+    ///
+    ///     assert_eq!(dispatcher.take_dead_letters(), [Event::Closed]);
+    /// }
+    /// ```
+    pub fn on_unhandled<F>(&mut self, handler: F)
+    where
+        F: Fn(&T) + Send + Sync + 'static,
+    {
+        self.unhandled_handler = Some(Box::new(handler));
+    }
+
+    /// Sets `handler` to be called with each event emitted via
+    /// [`ListenerRequest::Emit`] that is rejected with a [`DispatchError`],
+    /// as it would exceed the maximum depth or cause a cycle.
+    /// Events whose [`EventHierarchy`] repeats a key or exceeds the maximum
+    /// depth of ancestors are reported as well, once their ancestors are cut off.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use hey_listen::{DispatchError, EventDispatcher, ListenerRequest};
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Ping,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: EventDispatcher<Event, ListenerRequest<Event>> =
+    ///         EventDispatcher::default();
+    ///
+    ///     dispatcher.on_emit_error(|event, error| {
+    ///         assert_eq!(*error, DispatchError::Cycle);
+    ///         println!("{:?} has been rejected: {}", event, error);
+    ///     });
+    ///     dispatcher.add_fn(
+    ///         Event::Ping,
+    ///         Box::new(|_: &Event| Some(ListenerRequest::Emit(Event::Ping))),
+    ///     );
+    ///
+    ///     dispatcher.dispatch_event(&Event::Ping);
+    /// }
+    /// ```
+    ///
+    /// [`ListenerRequest::Emit`]: enum.ListenerRequest.html#variant.Emit
+    /// [`DispatchError`]: enum.DispatchError.html
+    /// [`EventHierarchy`]: trait.EventHierarchy.html
+    pub fn on_emit_error<F>(&mut self, handler: F)
+    where
+        F: Fn(&T, &DispatchError) + Send + Sync + 'static,
+    {
+        self.emit_error_handler = Some(Box::new(handler));
+    }
+
+    /// Keeps up to `capacity` unhandled events, as described at
+    /// [`on_unhandled`], until taken via [`take_dead_letters`].
+    /// Once full, the oldest event is dropped.
+    /// Defaults to `0`, keeping no events.
+    ///
+    /// [`on_unhandled`]: #method.on_unhandled
+    /// [`take_dead_letters`]: #method.take_dead_letters
+    pub fn dead_letter_capacity(&mut self, capacity: usize) {
+        self.dead_letters.set_capacity(capacity);
+    }
+
+    /// Removes all kept unhandled events and returns them,
+    /// the oldest event being first.
+    pub fn take_dead_letters(&mut self) -> Vec<T> {
+        self.dead_letters.take()
+    }
+}
+
+impl<T, R> NestedDispatch<T> for EventDispatcher<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: 'static,
+{
+    fn dispatch_nested(&mut self, event_identifier: &T, chain: &mut DispatchChain<T>) {
+        let handler = Arc::clone(&self.request_handler);
+        let mut result = ExecuteRequestsResult::Finished;
+        let mut handled = false;
+        chain.enter(self.stamper.stamp(event_identifier, chain.correlation_id));
+
+        // Listeners are called via collections shared with nested dispatches,
+        // changed copy-on-write, hence follow-up events reach them as well.
+        let mut lineage = Lineage::new(event_identifier, self.parent, chain.max_depth);
+
+        for key in lineage.by_ref() {
+            let listener_collection = match self.events.get(&*key) {
+                Some(listener_collection) => Arc::clone(listener_collection),
+                None => continue,
+            };
+
+            handled |= listener_collection.has_listeners();
+            let (collection_result, mut relocations) = self.dispatch_collection(
+                &listener_collection,
+                &*handler,
+                &key,
+                event_identifier,
+                chain,
+            );
+            drop(listener_collection);
+            result = collection_result;
+
+            if let Some(listener_collection) = self.events.get_mut(&*key) {
+                relocations.apply(&key, listener_collection);
+            }
+
+            if let ExecuteRequestsResult::Stopped = result {
+                break;
+            }
+        }
+
+        if let Some(error) = lineage.take_error() {
+            self.report_emit_error(event_identifier, &error);
+        }
+
+        if let ExecuteRequestsResult::Finished = result {
+            let ranges = match self.ranges {
+                Some(ref ranges) => ranges.matching(event_identifier),
+                None => vec![],
+            };
+
+            for (range, listener_collection) in ranges {
+                handled |= listener_collection.has_listeners();
+                let (collection_result, mut relocations) = self.dispatch_collection(
+                    &listener_collection,
+                    &*handler,
+                    event_identifier,
+                    event_identifier,
+                    chain,
+                );
+                drop(listener_collection);
+                result = collection_result;
+
+                if let Some(ref mut ranges) = self.ranges {
+                    ranges.relocate(&range, &mut |listener_collection| {
+                        relocations.apply(event_identifier, listener_collection)
+                    });
+                }
+
+                if let ExecuteRequestsResult::Stopped = result {
+                    break;
+                }
+            }
+        }
+
+        if !handled {
+            report_unhandled(&self.unhandled_handler, &mut self.dead_letters, event_identifier);
+        }
+
+        if let ExecuteRequestsResult::Finished = result {
+            if !self.global.is_empty() {
+                let global = Arc::clone(&self.global);
+                let (_, mut relocations) = self.dispatch_collection(
+                    &global,
+                    &*handler,
+                    event_identifier,
+                    event_identifier,
+                    chain,
+                );
+                drop(global);
+                relocations.apply(event_identifier, &mut self.global);
+            }
+        }
+
+        if let ExecuteRequestsResult::Stopped = result {
+            chain.stop_propagation();
+        }
+
+        chain.leave();
+
+        // Listeners detached during the dispatch leave all other collections.
+        if !self.detached.is_empty() {
+            for (listened_event, listener_collection) in self.events.iter_mut() {
+                FnsAndTraits::remove_detached(listener_collection, listened_event, &self.detached);
+            }
+
+            if let Some(ref mut ranges) = self.ranges {
+                let detached = &self.detached;
+
+                ranges.for_each_mut(&mut |listener_collection| {
+                    FnsAndTraits::remove_detached(listener_collection, event_identifier, detached)
+                });
+            }
+
+            FnsAndTraits::remove_detached(&mut self.global, event_identifier, &self.detached);
+        }
+    }
+
+    fn report_emit_error(&self, event: &T, error: &DispatchError) {
+        if let Some(ref handler) = self.emit_error_handler {
+            handler(event, error);
+        }
+    }
+}
+
+impl<T, R> EventDispatcher<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: 'static,
+{
+    /// Dispatches `event` to `listener_collection` of `event_identifier`,
+    /// one of the dispatcher's collections, skipping listeners detached
+    /// by previously dispatched collections.
+    ///
+    /// Returns the relocations to apply on the dispatcher's collection,
+    /// moved listeners stay in it for lack of priority-levels.
+    fn dispatch_collection(
+        &mut self,
+        listener_collection: &FnsAndTraits<T, (), R>,
+        handler: &SyncRequestHandler<T, (), R>,
+        event_identifier: &T,
+        event: &T,
+        chain: &mut DispatchChain<T>,
+    ) -> (ExecuteRequestsResult, Relocations<T, (), R>) {
+        let remaining;
+        let listener_collection = if self.detached.is_empty() {
+            listener_collection
+        } else {
+            remaining = listener_collection.without(&self.detached);
+            &remaining
+        };
+
+        let (result, mut relocations) =
+            listener_collection.dispatch(self, handler, event_identifier, event, chain);
+        report_dead_listeners(
+            &self.dead_listener_handler,
+            event_identifier,
+            relocations.dead,
+            true,
+        );
+        let detached = ::std::mem::take(&mut relocations.detached);
+        self.detached.append(detached);
+
+        (result, relocations)
+    }
+}
+
+impl<T, R> Drop for EventDispatcher<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        let mut notified = FnsAndTraits::default();
+
+        for (event_identifier, listener_collection) in &self.events {
+            let dead = listener_collection.notify_dispatcher_dropped(&mut notified);
+            report_dead_listeners(&self.dead_listener_handler, event_identifier, dead, false);
+        }
+
+        // Dead listeners are not reported, see `EventDispatcher::on_dead_listener`.
+        if let Some(ref mut ranges) = self.ranges {
+            ranges.for_each_mut(&mut |listener_collection| {
+                listener_collection.notify_dispatcher_dropped(&mut notified);
+            });
+        }
+
+        self.global.notify_dispatcher_dropped(&mut notified);
+    }
+}
+
+/// Owns a map of all listened event-variants,
+/// [`Weak`]-references to their listeners and [`Fn`]s.
+/// Opposed to [`EventListener`], this structure utilises one [`BTreeMap`] per
+/// event-type to order listeners by a given priority-level.
+///
+/// **Note**: Consider implementing your own [`Ord`]-trait, if you
+/// want a different kind of order.
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+/// [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
+/// [`EventListener`]: struct.EventDispatcher.html
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+pub struct PriorityEventDispatcher<P, T, R = SyncDispatcherRequest>
+where
+    P: Ord,
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    events: PriorityListenerMap<P, T, R>,
+    request_handler: Arc<SyncRequestHandler<T, P, R>>,
+    max_dispatch_depth: usize,
+    queue: VecDeque<QueuedEvent<T>>,
+    max_flush_events: usize,
+    /// Listeners stopping to listen to all events during the current dispatch.
+    detached: FnsAndTraits<T, P, R>,
+    dead_listener_handler: Option<DeadListenerHandler<T>>,
+    /// Listeners receiving all events, ordered by their priority-level.
+    global: Arc<PriorityLevels<T, P, R>>,
+    unhandled_handler: Option<UnhandledHandler<T>>,
+    emit_error_handler: Option<EmitErrorHandler<T>>,
+    dead_letters: DeadLetters<T>,
+    /// Returns an event's parent, if the event-hierarchy is used.
+    parent: Option<ParentFn<T>>,
+    stamper: Stamper,
+    /// Records dispatched events, once tracing has been enabled.
+    tracer: Option<Tracer<T>>,
+    /// Listeners able to cancel events dispatched via `dispatch_cancellable`,
+    /// ordered by their priority-level.
+    vetoes: HashMap<T, BTreeMap<P, VetoListeners<T>>>,
+}
+
+impl<P, T, R> Default for PriorityEventDispatcher<P, T, R>
+where
+    P: Ord + Clone + 'static,
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: Into<ListenerRequest<T, P>> + 'static,
+{
+    fn default() -> PriorityEventDispatcher<P, T, R> {
+        PriorityEventDispatcher::new_with_request_handler(|request: R| Some(request))
+    }
+}
+
+impl<P, T, R> PriorityEventDispatcher<P, T, R>
+where
+    P: Ord + Clone + 'static,
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: 'static,
+{
+    /// Creates a dispatcher whose listeners return requests of type `R`,
+    /// translated by `handler` into requests `O`, e.g.
+    /// [`SyncDispatcherRequest`] or [`ListenerRequest`].
+    ///
+    /// See [`RequestHandler`] for an example.
+    ///
+    /// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
+    /// [`ListenerRequest`]: enum.ListenerRequest.html
+    /// [`RequestHandler`]: trait.RequestHandler.html
+    pub fn new_with_request_handler<H, O>(handler: H) -> Self
+    where
+        H: RequestHandler<R, O> + 'static,
+        O: Into<ListenerRequest<T, P>> + 'static,
+    {
+        PriorityEventDispatcher {
+            events: PriorityListenerMap::new(),
+            request_handler: translating_handler(handler),
+            max_dispatch_depth: DEFAULT_MAX_DISPATCH_DEPTH,
+            queue: VecDeque::new(),
+            max_flush_events: usize::MAX,
+            detached: FnsAndTraits::default(),
+            dead_listener_handler: None,
+            global: Arc::default(),
+            unhandled_handler: None,
+            emit_error_handler: None,
+            dead_letters: DeadLetters::new(),
+            parent: None,
+            stamper: Stamper::new(),
+            tracer: None,
+            vetoes: HashMap::new(),
+        }
+    }
+
+    /// Adds a [`Listener`] to listen for an `event_identifier`, considering
+    /// a given `priority` implementing the [`Ord`]-trait, to sort dispatch-order.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// **Note**: If your `Enum` owns fields you need to consider implementing
+    /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields,
+    /// see second example for an implementation-suggestion.
+    ///
+    /// # Examples
+    ///
+    /// Adding a [`Listener`] to the dispatcher:
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use hey_listen::{Listener, PriorityEventDispatcher, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct {}
     ///
     /// impl Listener<Event> for ListenerStruct {
     ///     fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> { None }
@@ -311,9 +3454,9 @@ where
     ///
     /// fn main() {
     ///     let listener = Arc::new(Mutex::new(ListenerStruct {}));
-    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///     let mut dispatcher: PriorityEventDispatcher<u32, Event> = PriorityEventDispatcher::default();
     ///
-    ///     dispatcher.add_listener(Event::EventType, &listener);
+    ///     dispatcher.add_listener(Event::EventType, &listener, 1);
     /// }
     /// ```
     ///
@@ -342,48 +3485,368 @@ where
     /// impl Eq for Event {}
     /// ```
     ///
+    /// [`EventDispatcher`]: struct.EventDispatcher.html
     /// [`Listener`]: trait.Listener.html
     /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
-    pub fn add_listener<D: Listener<T> + Send + Sync + 'static>(
+    /// [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
+    pub fn add_listener<L>(&mut self, event_identifier: T, listener: &Arc<L>, priority: P)
+    where
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+    {
+        let listener = Arc::clone(listener) as Arc<LockedListener<T, R>>;
+        listener.on_subscribe(&event_identifier);
+        let weak_listener = Arc::downgrade(&listener);
+
+        if let Some(prioritised_listener_collection) = self.events.get_mut(&event_identifier) {
+            let prioritised_listener_collection = Arc::make_mut(prioritised_listener_collection);
+
+            if let Some(priority_level_collection) =
+                prioritised_listener_collection.get_mut(&priority)
+            {
+                Arc::make_mut(priority_level_collection).traits.push(weak_listener);
+
+                return;
+            }
+            prioritised_listener_collection.insert(
+                priority.clone(),
+                Arc::new(FnsAndTraits::new_with_traits(vec![weak_listener])),
+            );
+            return;
+        }
+
+        let mut b_tree_map = BTreeMap::new();
+        b_tree_map.insert(
+            priority,
+            Arc::new(FnsAndTraits::new_with_traits(vec![weak_listener])),
+        );
+        self.events.insert(event_identifier, Arc::new(b_tree_map));
+    }
+
+    /// Adds a [`Listener`] to listen for all events returned by
+    /// its [`subscriptions`]-method on a given `priority`-level.
+    ///
+    /// See [`register_prioritised`] to listen on different priority-levels.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`subscriptions`]: trait.Listener.html#method.subscriptions
+    /// [`register_prioritised`]: #method.register_prioritised
+    pub fn register<L>(&mut self, listener: &Arc<L>, priority: P)
+    where
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+    {
+        let subscriptions = listener.with_listener(|listener| listener.subscriptions());
+        self.add_listener_for(subscriptions, listener, priority);
+    }
+
+    /// Adds a [`Listener`] to listen for all events returned by
+    /// its [`subscriptions`]-method.
+    /// Each event is listened for on the priority-level returned by
+    /// [`subscription_priority`], falling back to `priority`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use hey_listen::{Listener, PriorityEventDispatcher, SubscriptionPriority,
+    ///                  SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     Opened,
+    ///     Closed,
+    /// }
+    ///
+    /// struct ListenerStruct {}
+    ///
+    /// impl Listener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, _: &Event) -> Option<SyncDispatcherRequest> { None }
+    ///
+    ///     fn subscriptions(&self) -> Vec<Event> {
+    ///         vec![Event::Opened, Event::Closed]
+    ///     }
+    /// }
+    ///
+    /// impl SubscriptionPriority<Event, u32> for ListenerStruct {
+    ///     fn subscription_priority(&self, event: &Event) -> Option<u32> {
+    ///         match *event {
+    ///             Event::Closed => Some(0),
+    ///             Event::Opened => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let listener = Arc::new(Mutex::new(ListenerStruct {}));
+    ///     let mut dispatcher: PriorityEventDispatcher<u32, Event> = PriorityEventDispatcher::default();
+    ///
+    ///     // Listens for `Opened` on priority-level 5 and for `Closed` on 0.
+    ///     dispatcher.register_prioritised(&listener, 5);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`subscriptions`]: trait.Listener.html#method.subscriptions
+    /// [`subscription_priority`]: trait.SubscriptionPriority.html#tymethod.subscription_priority
+    pub fn register_prioritised<L>(&mut self, listener: &Arc<L>, priority: P)
+    where
+        L: ListenerLock,
+        L::Listener: Listener<T, R> + SubscriptionPriority<T, P>,
+    {
+        let subscriptions: Vec<(T, Option<P>)> = listener.with_listener(|listener| {
+            listener
+                .subscriptions()
+                .into_iter()
+                .map(|event_identifier| {
+                    let priority = listener.subscription_priority(&event_identifier);
+
+                    (event_identifier, priority)
+                })
+                .collect()
+        });
+
+        for (event_identifier, event_priority) in subscriptions {
+            let event_priority = event_priority.unwrap_or_else(|| priority.clone());
+            self.add_listener(event_identifier, listener, event_priority);
+        }
+    }
+
+    /// Adds a [`Listener`] to listen for all `event_identifiers`
+    /// on a given `priority`-level.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    pub fn add_listener_for<I, L>(&mut self, event_identifiers: I, listener: &Arc<L>, priority: P)
+    where
+        I: IntoIterator<Item = T>,
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+    {
+        for event_identifier in event_identifiers {
+            self.add_listener(event_identifier, listener, priority.clone());
+        }
+    }
+
+    /// Adds a [`Listener`] to listen for an `event_identifier` on a given
+    /// `priority`-level, only receiving events `predicate` returns `true` for.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// `predicate` is evaluated before the listener's lock is taken,
+    /// hence rejected events do not lock the listener at all.
+    /// The filter can be removed via [`remove_listener`] using the
+    /// returned [`ListenerId`].
+    ///
+    /// See [`EventDispatcher::add_listener_filtered`] for an example.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`ListenerId`]: struct.ListenerId.html
+    /// [`remove_listener`]: #method.remove_listener
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`EventDispatcher::add_listener_filtered`]: struct.EventDispatcher.html#method.add_listener_filtered
+    pub fn add_listener_filtered<L, F>(
+        &mut self,
+        event_identifier: T,
+        listener: &Arc<L>,
+        predicate: F,
+        priority: P,
+    ) -> ListenerId
+    where
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        let listener = self.translating(FilteredListener::new(listener, predicate));
+
+        self.insert_owned(event_identifier, listener, priority)
+    }
+
+    /// Hands a [`Listener`] over to the dispatcher, listening for an `event_identifier`
+    /// on a given `priority`-level.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// Opposed to [`add_listener`], the dispatcher owns the [`Listener`],
+    /// hence it lives until it requests to stop listening or is removed
+    /// via [`remove_listener`] using the returned [`ListenerId`].
+    ///
+    /// # Examples
+    ///
+    /// Adding an owned [`Listener`] to the dispatcher:
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use hey_listen::{Listener, PriorityEventDispatcher, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct {}
+    ///
+    /// impl Listener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> { None }
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: PriorityEventDispatcher<u32, Event> = PriorityEventDispatcher::default();
+    ///
+    ///     let id = dispatcher.add_owned_listener(Event::EventType, ListenerStruct {}, 1);
+    ///     dispatcher.dispatch_event(&Event::EventType);
+    ///
+    ///     assert!(dispatcher.remove_listener(id));
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`ListenerId`]: struct.ListenerId.html
+    /// [`add_listener`]: struct.PriorityEventDispatcher.html#method.add_listener
+    /// [`remove_listener`]: struct.PriorityEventDispatcher.html#method.remove_listener
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_owned_listener<D: Listener<T, R> + Send + Sync + 'static>(
+        &mut self,
+        event_identifier: T,
+        listener: D,
+        priority: P,
+    ) -> ListenerId {
+        let listener = self.translating(Mutex::new(listener));
+
+        self.insert_owned(event_identifier, listener, priority)
+    }
+
+    /// Wraps `listener` to translate its requests via the request handler.
+    fn translating<L>(&self, listener: L) -> Arc<OwnedLockedListener<T, P>>
+    where
+        L: LockedListener<T, R> + 'static,
+    {
+        Arc::new(TranslatingListener {
+            listener,
+            handler: Arc::clone(&self.request_handler),
+        })
+    }
+
+    /// Adds the owned `listener` to the listener-collection
+    /// of `event_identifier` on `priority`.
+    fn insert_owned(
+        &mut self,
+        event_identifier: T,
+        listener: Arc<OwnedLockedListener<T, P>>,
+        priority: P,
+    ) -> ListenerId {
+        let id = ListenerId::new();
+        let owned = OwnedListener { id, listener };
+        owned.listener.on_subscribe(&event_identifier);
+
+        if let Some(prioritised_listener_collection) = self.events.get_mut(&event_identifier) {
+            let prioritised_listener_collection = Arc::make_mut(prioritised_listener_collection);
+
+            if let Some(priority_level_collection) =
+                prioritised_listener_collection.get_mut(&priority)
+            {
+                Arc::make_mut(priority_level_collection).owned.push(owned);
+
+                return id;
+            }
+            prioritised_listener_collection.insert(
+                priority.clone(),
+                Arc::new(FnsAndTraits::new_with_owned(vec![owned])),
+            );
+            return id;
+        }
+
+        let mut b_tree_map = BTreeMap::new();
+        b_tree_map.insert(
+            priority,
+            Arc::new(FnsAndTraits::new_with_owned(vec![owned])),
+        );
+        self.events.insert(event_identifier, Arc::new(b_tree_map));
+
+        id
+    }
+
+    /// Adds a [`SharedListener`] to listen for an `event_identifier`, considering
+    /// a given `priority` implementing the [`Ord`]-trait, to sort dispatch-order.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// Opposed to [`add_listener`], the dispatcher stores a [`Weak`]-reference
+    /// to the [`SharedListener`] itself, hence no [`Mutex`] is required.
+    ///
+    /// [`SharedListener`]: trait.SharedListener.html
+    /// [`add_listener`]: struct.PriorityEventDispatcher.html#method.add_listener
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    /// [`Mutex`]: https://doc.rust-lang.org/std/sync/struct.Mutex.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
+    pub fn add_shared_listener<D: SharedListener<T, R> + Send + Sync + 'static>(
         &mut self,
         event_identifier: T,
-        listener: &Arc<Mutex<D>>,
+        listener: &Arc<D>,
+        priority: P,
     ) {
-        if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
-            listener_collection.traits.push(Arc::downgrade(
-                &(Arc::clone(listener) as Arc<Mutex<Listener<T> + Send + Sync + 'static>>),
-            ));
+        listener.on_subscribe(&event_identifier);
+        let weak_listener = Arc::downgrade(
+            &(Arc::clone(listener) as Arc<SharedListener<T, R> + Send + Sync + 'static>),
+        );
+
+        if let Some(prioritised_listener_collection) = self.events.get_mut(&event_identifier) {
+            let prioritised_listener_collection = Arc::make_mut(prioritised_listener_collection);
 
+            if let Some(priority_level_collection) =
+                prioritised_listener_collection.get_mut(&priority)
+            {
+                Arc::make_mut(priority_level_collection).shared.push(weak_listener);
+
+                return;
+            }
+            prioritised_listener_collection.insert(
+                priority.clone(),
+                Arc::new(FnsAndTraits::new_with_shared(vec![weak_listener])),
+            );
             return;
         }
 
-        self.events.insert(
-            event_identifier,
-            FnsAndTraits::new_with_traits(vec![
-                Arc::downgrade(
-                    &(Arc::clone(listener) as Arc<Mutex<Listener<T> + Send + Sync + 'static>>),
-                ),
-            ]),
+        let mut b_tree_map = BTreeMap::new();
+        b_tree_map.insert(
+            priority,
+            Arc::new(FnsAndTraits::new_with_shared(vec![weak_listener])),
         );
+        self.events.insert(event_identifier, Arc::new(b_tree_map));
     }
 
-    /// Adds a [`Fn`] to listen for an `event_identifier`.
-    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    /// Removes the owned [`Listener`] identified by `id` from
+    /// the dispatcher, dropping it.
+    /// Returns `false` if no such [`Listener`] has been found.
     ///
-    /// **Note**: If your `Enum` owns fields you need to consider implementing
-    /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields.
+    /// [`Listener`]: trait.Listener.html
+    pub fn remove_listener(&mut self, id: ListenerId) -> bool {
+        self.events
+            .iter_mut()
+            .any(|(event_identifier, prioritised_listener_collection)| {
+                Arc::make_mut(prioritised_listener_collection)
+                    .values_mut()
+                    .any(|listener_collection| {
+                        Arc::make_mut(listener_collection).remove_owned(event_identifier, id)
+                    })
+            })
+    }
+
+    /// Adds an [`Fn`] to listen for an `event_identifier`, considering
+    /// a given `priority` implementing the [`Ord`]-trait in order to sort dispatch-order.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
     ///
     /// # Examples
     ///
-    /// Adding a [`Fn`] to the dispatcher:
+    /// Adding an [`Fn`] to the dispatcher:
     ///
     /// ```rust
     /// extern crate hey_listen;
     ///
-    /// use hey_listen::EventDispatcher;
-    /// use hey_listen::SyncDispatcherRequest;
+    /// use hey_listen::{PriorityEventDispatcher, SyncDispatcherRequest};
     /// use std::sync::{Arc, Mutex};
     ///
     /// #[derive(Clone, Eq, Hash, PartialEq)]
@@ -403,7 +3866,7 @@ where
     ///
     /// fn main() {
     ///     let listener = Arc::new(Mutex::new(EventListener { used_method: false }));
-    ///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+    ///     let mut dispatcher: PriorityEventDispatcher<u32, Event> = PriorityEventDispatcher::default();
     ///     let weak_listener_ref = Arc::downgrade(&Arc::clone(&listener));
     ///
     ///     let closure = Box::new(move |event: &Event| -> Option<SyncDispatcherRequest> {
@@ -416,7 +3879,7 @@ where
     ///         }
     ///     });
     ///
-    ///     dispatcher.add_fn(Event::EventType, closure);
+    ///     dispatcher.add_fn(Event::EventType, closure, 1);
     /// }
     /// ```
     ///
@@ -424,100 +3887,762 @@ where
     /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
-    pub fn add_fn(
+    pub fn add_fn(&mut self, event_identifier: T, function: BoxedFn<T, R>, priority: P) {
+        self.add_context_fn(
+            event_identifier,
+            Box::new(move |event: &T, _: &mut DispatchContext<T>| function(event)),
+            priority,
+        );
+    }
+
+    /// Adds an [`Fn`] to listen for an `event_identifier`, receiving
+    /// a [`DispatchContext`] to dispatch follow-up events with.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_context_fn(
         &mut self,
         event_identifier: T,
-        function: Box<Fn(&T) -> Option<SyncDispatcherRequest> + Send + Sync + 'static>,
+        function: BoxedContextFn<T, R>,
+        priority: P,
     ) {
-        if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
-            listener_collection.fns.push(function);
+        self.insert_fn(event_identifier, Arc::from(function), priority);
+    }
+
+    /// Adds an [`EnvelopeListener`] to listen for an `event_identifier`
+    /// on a given `priority`-level, receiving each dispatched event wrapped
+    /// in its [`Envelope`].
+    ///
+    /// See [`EventDispatcher::add_envelope_listener`] for details.
+    ///
+    /// [`EnvelopeListener`]: trait.EnvelopeListener.html
+    /// [`Envelope`]: struct.Envelope.html
+    /// [`EventDispatcher::add_envelope_listener`]: struct.EventDispatcher.html#method.add_envelope_listener
+    pub fn add_envelope_listener<L>(
+        &mut self,
+        event_identifier: T,
+        listener: &Arc<L>,
+        priority: P,
+    ) -> ListenerId
+    where
+        L: ListenerLock,
+        L::Listener: EnvelopeListener<T, R>,
+    {
+        let listener = self.translating(EnvelopedListener::new(listener));
+
+        self.insert_owned(event_identifier, listener, priority)
+    }
+
+    /// Adds an [`Fn`] to listen for an `event_identifier` on a given
+    /// `priority`-level, receiving each dispatched event wrapped in its [`Envelope`].
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Envelope`]: struct.Envelope.html
+    pub fn add_envelope_fn(
+        &mut self,
+        event_identifier: T,
+        function: BoxedEnvelopeFn<T, R>,
+        priority: P,
+    ) {
+        self.add_context_fn(
+            event_identifier,
+            Box::new(move |_: &T, context: &mut DispatchContext<T>| function(context.envelope())),
+            priority,
+        );
+    }
+
+    /// Adds a [`VetoListener`] on a given `priority`-level, asked before
+    /// `event_identifier` is dispatched via [`dispatch_cancellable`],
+    /// able to cancel it. The dispatcher holds a [`Weak`]-reference only.
+    ///
+    /// [`VetoListener`]: trait.VetoListener.html
+    /// [`dispatch_cancellable`]: #method.dispatch_cancellable
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    pub fn add_veto_listener<L>(&mut self, event_identifier: T, listener: &Arc<L>, priority: P)
+    where
+        L: ListenerLock,
+        L::Listener: VetoListener<T>,
+    {
+        self.vetoes
+            .entry(event_identifier)
+            .or_default()
+            .entry(priority)
+            .or_default()
+            .push_listener(listener);
+    }
+
+    /// Adds an [`Fn`] on a given `priority`-level, called before
+    /// `event_identifier` is dispatched via [`dispatch_cancellable`],
+    /// returning a [`Veto`] cancels the dispatch.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`dispatch_cancellable`]: #method.dispatch_cancellable
+    /// [`Veto`]: struct.Veto.html
+    pub fn add_veto_fn(&mut self, event_identifier: T, function: BoxedVetoFn<T>, priority: P) {
+        self.vetoes
+            .entry(event_identifier)
+            .or_default()
+            .entry(priority)
+            .or_default()
+            .push_fn(function);
+    }
+
+    /// Adds one [`Fn`] to listen for all `event_identifiers` on
+    /// a given `priority`-level.
+    /// The [`Fn`] is stored once and shared among all events,
+    /// hence its captured state is not cloned.
+    ///
+    /// See [`EventDispatcher::add_fn_for`] for an example.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`EventDispatcher::add_fn_for`]: struct.EventDispatcher.html#method.add_fn_for
+    pub fn add_fn_for<I>(
+        &mut self,
+        event_identifiers: I,
+        function: BoxedFn<T, R>,
+        priority: P,
+    ) where
+        I: IntoIterator<Item = T>,
+    {
+        let function: Arc<ContextFn<T, R>> =
+            Arc::new(move |event: &T, _: &mut DispatchContext<T>| function(event));
+
+        for event_identifier in event_identifiers {
+            self.insert_fn(event_identifier, Arc::clone(&function), priority.clone());
+        }
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier` on a given
+    /// `priority`-level, only being called with events `predicate`
+    /// returns `true` for.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_fn_filtered<F>(
+        &mut self,
+        event_identifier: T,
+        function: BoxedFn<T, R>,
+        predicate: F,
+        priority: P,
+    ) where
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        self.add_fn(
+            event_identifier,
+            Box::new(move |event: &T| {
+                if predicate(event) {
+                    function(event)
+                } else {
+                    None
+                }
+            }),
+            priority,
+        );
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier` on a given
+    /// `priority`-level, calling it with the value guarded by `target`
+    /// alongside the event.
+    /// Only a [`Weak`]-reference to `target` is held, once `target` has been
+    /// dropped, the [`Fn`] is removed.
+    ///
+    /// See [`EventDispatcher::add_weak_fn`] for an example.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    /// [`EventDispatcher::add_weak_fn`]: struct.EventDispatcher.html#method.add_weak_fn
+    pub fn add_weak_fn<L, F>(
+        &mut self,
+        event_identifier: T,
+        target: &Arc<L>,
+        function: F,
+        priority: P,
+    ) where
+        L: ListenerLock,
+        F: Fn(&mut L::Listener, &T) -> Option<R> + Send + Sync + 'static,
+    {
+        let listener = self.translating(WeakFn::new(target, function));
+        self.insert_owned(event_identifier, listener, priority);
+    }
+
+    /// Adds a [`Listener`] to listen for all events on a given `priority`-level.
+    ///
+    /// Global listeners are ordered alongside the listeners registered for
+    /// the dispatched event by their priority-level. On equal priority-levels,
+    /// global listeners receive the event last.
+    /// As they do not subscribe to a specific event, [`on_subscribe`]
+    /// is not called.
+    ///
+    /// **Note**: Global listeners do not receive follow-up events
+    /// dispatched immediately by global listeners, follow-up events
+    /// queued via [`DispatchContext::enqueue`] are received.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`on_subscribe`]: trait.Listener.html#method.on_subscribe
+    /// [`DispatchContext::enqueue`]: struct.DispatchContext.html#method.enqueue
+    pub fn add_global_listener<L>(&mut self, listener: &Arc<L>, priority: P)
+    where
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+    {
+        let listener = Arc::clone(listener) as Arc<LockedListener<T, R>>;
+        Arc::make_mut(Arc::make_mut(&mut self.global).entry(priority).or_default())
+            .traits
+            .push(Arc::downgrade(&listener));
+    }
+
+    /// Adds a [`Fn`] to listen for all events on a given `priority`-level.
+    ///
+    /// See [`add_global_listener`] for when global listeners are called.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`add_global_listener`]: #method.add_global_listener
+    pub fn add_global_fn(&mut self, function: BoxedFn<T, R>, priority: P) {
+        Arc::make_mut(Arc::make_mut(&mut self.global).entry(priority).or_default())
+            .fns
+            .push(Arc::new(
+                move |event: &T, _: &mut DispatchContext<T>| function(event),
+            ));
+    }
+
+    /// Adds `function` to the listener-collection of `event_identifier`
+    /// on a given `priority`-level.
+    fn insert_fn(&mut self, event_identifier: T, function: Arc<ContextFn<T, R>>, priority: P) {
+        if let Some(prioritised_listener_collection) = self.events.get_mut(&event_identifier) {
+            let prioritised_listener_collection = Arc::make_mut(prioritised_listener_collection);
+
+            if let Some(priority_level_collection) =
+                prioritised_listener_collection.get_mut(&priority)
+            {
+                Arc::make_mut(priority_level_collection).fns.push(function);
+
+                return;
+            }
+            prioritised_listener_collection.insert(
+                priority.clone(),
+                Arc::new(FnsAndTraits::new_with_fns(vec![function])),
+            );
+            return;
+        }
+
+        let mut b_tree_map = BTreeMap::new();
+        b_tree_map.insert(
+            priority,
+            Arc::new(FnsAndTraits::new_with_fns(vec![function])),
+        );
+        self.events.insert(event_identifier, Arc::new(b_tree_map));
+    }
+
+    /// All [`Listener`]s listening to a passed `event_identifier`
+    /// will be called via their implemented [`on_event`]-method.
+    /// [`Fn`]s returning [`Result`] with `Ok(())` will be retained
+    /// and `Err(SyncDispatcherRequest::StopListening)` will cause them to
+    /// be removed from the event-dispatcher.
+    ///
+    /// **Notice**: [`Listener`]s will called ordered by their priority-level.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`on_event`]: trait.Listener.html#tymethod.on_event
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
+    pub fn dispatch_event(&mut self, event_identifier: &T) {
+        self.dispatch_correlated(event_identifier, CorrelationId::new());
+    }
+
+    /// Dispatches `event_identifier` like [`dispatch_event`], continuing
+    /// the correlation `correlation_id` instead of starting a new one,
+    /// e.g. for an event caused by an event received from another service.
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    pub fn dispatch_correlated(&mut self, event_identifier: &T, correlation_id: CorrelationId) {
+        let (max_depth, tracer) = (self.max_dispatch_depth, self.tracer.take());
+
+        let (_, tracer) = dispatch_chain(self, event_identifier, max_depth, correlation_id, tracer);
+
+        self.tracer = tracer;
+        self.detached = FnsAndTraits::default();
+    }
+
+    /// Dispatches `event_identifier` in two phases: First, the veto-listeners
+    /// of the event and its ancestors are asked whether it may be dispatched,
+    /// ordered by their priority-level. Unless any of them returns a [`Veto`],
+    /// the event is dispatched to all listeners like via [`dispatch_event`].
+    ///
+    /// Returns the first [`Veto`], which stops asking further veto-listeners.
+    /// Events dispatched via [`dispatch_event`] skip veto-listeners.
+    ///
+    /// [`Veto`]: struct.Veto.html
+    /// [`dispatch_event`]: #method.dispatch_event
+    pub fn dispatch_cancellable(&mut self, event_identifier: &T) -> Result<(), Veto> {
+        // Cyclic hierarchies are reported once the event is dispatched.
+        for key in Lineage::new(event_identifier, self.parent, self.max_dispatch_depth) {
+            if let Some(prioritised_vetoes) = self.vetoes.get_mut(&*key) {
+                for vetoes in prioritised_vetoes.values_mut() {
+                    let (result, dead) = vetoes.check(event_identifier);
+                    report_dead_listeners(&self.dead_listener_handler, &key, dead, true);
+                    result?;
+                }
+            }
+        }
+
+        self.dispatch_event(event_identifier);
+
+        Ok(())
+    }
+
+    /// Sets the [`Clock`] providing the timestamps of [`Envelope`]s,
+    /// defaults to [`SystemClock`].
+    ///
+    /// [`Clock`]: trait.Clock.html
+    /// [`Envelope`]: struct.Envelope.html
+    /// [`SystemClock`]: struct.SystemClock.html
+    pub fn clock<C: Clock + 'static>(&mut self, clock: C) {
+        self.stamper.set_clock(Box::new(clock));
+    }
+
+    /// Sets the source ID carried by [`Envelope`]s, unset by default.
+    ///
+    /// [`Envelope`]: struct.Envelope.html
+    pub fn source_id<S: Into<String>>(&mut self, source_id: S) {
+        self.stamper.set_source_id(source_id.into());
+    }
+
+    /// Starts recording which listeners receive dispatched events and
+    /// which follow-up events they dispatch, taken via [`take_trace`].
+    ///
+    /// See [`Trace`] for an example.
+    ///
+    /// [`take_trace`]: #method.take_trace
+    /// [`Trace`]: struct.Trace.html
+    pub fn enable_tracing(&mut self) {
+        if self.tracer.is_none() {
+            self.tracer = Some(Tracer::new());
+        }
+    }
+
+    /// Takes all events recorded since tracing has been enabled or
+    /// the trace has last been taken, empty if tracing is disabled.
+    pub fn take_trace(&mut self) -> Trace<T> {
+        self.tracer
+            .as_mut()
+            .map_or_else(Trace::default, Tracer::take)
+    }
+
+    /// Sets the maximum depth of follow-up events dispatched via
+    /// [`DispatchContext`], defaults to `32`.
+    ///
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    pub fn max_dispatch_depth(&mut self, depth: usize) {
+        self.max_dispatch_depth = depth;
+    }
+
+    /// Dispatches events to the listeners of all their ancestors
+    /// as well, as reported by [`EventHierarchy`], the listeners of
+    /// the most specific key first.
+    ///
+    /// Propagation stopped by a listener also stops propagation to
+    /// the listeners of ancestors.
+    ///
+    /// **Note**: Listeners of an ancestor do not receive follow-up events
+    /// dispatched immediately by listeners of the same ancestor,
+    /// follow-up events queued via [`DispatchContext::enqueue`] are received.
+    ///
+    /// See [`EventHierarchy`] for an example.
+    ///
+    /// [`EventHierarchy`]: trait.EventHierarchy.html
+    /// [`DispatchContext::enqueue`]: struct.DispatchContext.html#method.enqueue
+    pub fn use_event_hierarchy(&mut self)
+    where
+        T: EventHierarchy,
+    {
+        self.parent = Some(T::parent);
+    }
+
+    /// Queues `event_identifier` to be dispatched on the next [`flush`].
+    ///
+    /// [`flush`]: #method.flush
+    pub fn queue_event(&mut self, event_identifier: T) {
+        self.queue.push_back(QueuedEvent::new(event_identifier));
+    }
+
+    /// Dispatches all queued events in the order they have been queued.
+    /// Events queued by listeners via [`DispatchContext`] during the flush
+    /// are dispatched by the same flush.
+    ///
+    /// At most as many queued events as set via [`max_flush_events`]
+    /// are dispatched, the remaining events stay queued.
+    ///
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    /// [`max_flush_events`]: #method.max_flush_events
+    pub fn flush(&mut self) {
+        let queue = ::std::mem::take(&mut self.queue);
+        let (max_depth, limit) = (self.max_dispatch_depth, self.max_flush_events);
+        let tracer = self.tracer.take();
+
+        let (queue, tracer) = flush_queue(self, queue, max_depth, limit, tracer);
+        self.queue = queue;
+        self.tracer = tracer;
+        self.detached = FnsAndTraits::default();
+    }
+
+    /// Sets the maximum amount of queued events dispatched per [`flush`],
+    /// unlimited by default.
+    ///
+    /// [`flush`]: #method.flush
+    pub fn max_flush_events(&mut self, limit: usize) {
+        self.max_flush_events = limit;
+    }
+
+    /// Sets `handler` to be called with a [`DeadListener`] for each
+    /// listener found dropped by its owner without unsubscribing,
+    /// either while dispatching or when the dispatcher drops.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use hey_listen::{PriorityEventDispatcher, Listener, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct;
+    ///
+    /// impl Listener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, _: &Event) -> Option<SyncDispatcherRequest> {
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: PriorityEventDispatcher<u32, Event> = PriorityEventDispatcher::default();
+    ///     let listener = Arc::new(Mutex::new(ListenerStruct));
+    ///
+    ///     dispatcher.on_dead_listener(|dead| {
+    ///         assert!(dead.missed_dispatch());
+    ///         println!("A listener of {:?} has been dropped.", dead.event());
+    ///     });
+    ///     dispatcher.add_listener(Event::EventType, &listener, 0);
+    ///
+    ///     drop(listener);
+    ///     dispatcher.dispatch_event(&Event::EventType);
+    /// }
+    /// ```
+    ///
+    /// [`DeadListener`]: struct.DeadListener.html
+    pub fn on_dead_listener<F>(&mut self, handler: F)
+    where
+        F: Fn(&DeadListener<T>) + Send + Sync + 'static,
+    {
+        self.dead_listener_handler = Some(Box::new(handler));
+    }
+
+    /// Sets `handler` to be called with each unhandled event,
+    /// see [`EventDispatcher::on_unhandled`] for details and an example.
+    ///
+    /// [`EventDispatcher::on_unhandled`]: struct.EventDispatcher.html#method.on_unhandled
+    pub fn on_unhandled<F>(&mut self, handler: F)
+    where
+        F: Fn(&T) + Send + Sync + 'static,
+    {
+        self.unhandled_handler = Some(Box::new(handler));
+    }
+
+    /// Sets `handler` to be called with each event emitted via
+    /// [`ListenerRequest::Emit`] that is rejected with a [`DispatchError`].
+    ///
+    /// See [`EventDispatcher::on_emit_error`] for an example.
+    ///
+    /// [`ListenerRequest::Emit`]: enum.ListenerRequest.html#variant.Emit
+    /// [`DispatchError`]: enum.DispatchError.html
+    /// [`EventDispatcher::on_emit_error`]: struct.EventDispatcher.html#method.on_emit_error
+    pub fn on_emit_error<F>(&mut self, handler: F)
+    where
+        F: Fn(&T, &DispatchError) + Send + Sync + 'static,
+    {
+        self.emit_error_handler = Some(Box::new(handler));
+    }
+
+    /// Keeps up to `capacity` unhandled events, as described at
+    /// [`on_unhandled`], until taken via [`take_dead_letters`].
+    /// Once full, the oldest event is dropped.
+    /// Defaults to `0`, keeping no events.
+    ///
+    /// [`on_unhandled`]: #method.on_unhandled
+    /// [`take_dead_letters`]: #method.take_dead_letters
+    pub fn dead_letter_capacity(&mut self, capacity: usize) {
+        self.dead_letters.set_capacity(capacity);
+    }
+
+    /// Removes all kept unhandled events and returns them,
+    /// the oldest event being first.
+    pub fn take_dead_letters(&mut self) -> Vec<T> {
+        self.dead_letters.take()
+    }
+}
+
+impl<P, T, R> NestedDispatch<T> for PriorityEventDispatcher<P, T, R>
+where
+    P: Ord + Clone + 'static,
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: 'static,
+{
+    fn dispatch_nested(&mut self, event_identifier: &T, chain: &mut DispatchChain<T>) {
+        let handler = Arc::clone(&self.request_handler);
+        chain.enter(self.stamper.stamp(event_identifier, chain.correlation_id));
+
+        // Listeners are called via their priority-levels shared before
+        // dispatching and changed copy-on-write, hence follow-up events
+        // reach them as well and moved listeners are not called twice.
+        // Each entry holds the listeners of the event or one of its ancestors.
+        let mut ancestors = Lineage::new(event_identifier, self.parent, chain.max_depth);
+        let lineage: Vec<_> = ancestors
+            .by_ref()
+            .filter_map(|key| {
+                let prioritised_listener_collection = Arc::clone(self.events.get(&*key)?);
+
+                Some((key, prioritised_listener_collection))
+            })
+            .collect();
+
+        if let Some(error) = ancestors.take_error() {
+            self.report_emit_error(event_identifier, &error);
+        }
+
+        if !lineage.iter().any(|(_, prioritised_listener_collection)| {
+            prioritised_listener_collection
+                .values()
+                .any(|listener_collection| listener_collection.has_listeners())
+        }) {
+            report_unhandled(&self.unhandled_handler, &mut self.dead_letters, event_identifier);
+        }
+
+        let global = Arc::clone(&self.global);
+        let mut priorities: Vec<P> = lineage
+            .iter()
+            .flat_map(|(_, prioritised_listener_collection)| {
+                prioritised_listener_collection.keys()
+            })
+            .chain(global.keys())
+            .cloned()
+            .collect();
+        priorities.sort();
+        priorities.dedup();
+
+        // Per priority-level, the most specific key is dispatched to first.
+        'priorities: for priority in priorities {
+            for (key, prioritised_listener_collection) in &lineage {
+                if let Some(listener_collection) = prioritised_listener_collection.get(&priority) {
+                    let (result, relocations) = self.dispatch_collection(
+                        listener_collection,
+                        &*handler,
+                        key,
+                        event_identifier,
+                        chain,
+                    );
+
+                    if let Some(prioritised_listener_collection) = self.events.get_mut(&**key) {
+                        relocations.apply_prioritised(
+                            key,
+                            prioritised_listener_collection,
+                            &priority,
+                        );
+                    }
+
+                    if let ExecuteRequestsResult::Stopped = result {
+                        chain.stop_propagation();
+                        break 'priorities;
+                    }
+                }
+            }
+
+            if let Some(listener_collection) = global.get(&priority) {
+                let (result, relocations) = self.dispatch_collection(
+                    listener_collection,
+                    &*handler,
+                    event_identifier,
+                    event_identifier,
+                    chain,
+                );
+                relocations.apply_prioritised(event_identifier, &mut self.global, &priority);
 
-            return;
+                if let ExecuteRequestsResult::Stopped = result {
+                    chain.stop_propagation();
+                    break;
+                }
+            }
         }
 
-        self.events
-            .insert(event_identifier, FnsAndTraits::new_with_fns(vec![function]));
+        chain.leave();
+
+        // Listeners detached during the dispatch leave all other collections.
+        if !self.detached.is_empty() {
+            for (listened_event, prioritised_listener_collection) in self.events.iter_mut() {
+                FnsAndTraits::remove_prioritised_detached(
+                    prioritised_listener_collection,
+                    listened_event,
+                    &self.detached,
+                );
+            }
+
+            FnsAndTraits::remove_prioritised_detached(
+                &mut self.global,
+                event_identifier,
+                &self.detached,
+            );
+        }
     }
 
-    /// All [`Listener`]s listening to a passed `event_identifier`
-    /// will be called via their implemented [`on_event`]-method.
-    /// [`Fn`]s returning [`Result`] with `Ok(())` will be retained
-    /// and `Err(SyncDispatcherRequest::StopListening)` will cause them to
-    /// be removed from the event-dispatcher.
+    fn report_emit_error(&self, event: &T, error: &DispatchError) {
+        if let Some(ref handler) = self.emit_error_handler {
+            handler(event, error);
+        }
+    }
+}
+
+impl<P, T, R> PriorityEventDispatcher<P, T, R>
+where
+    P: Ord + Clone + 'static,
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: 'static,
+{
+    /// Dispatches `event` to `listener_collection` of `event_identifier`
+    /// on one priority-level, one of the dispatcher's collections,
+    /// skipping listeners detached by higher priority-levels.
     ///
-    /// [`Listener`]: trait.Listener.html
-    /// [`on_event`]: trait.Listener.html#tymethod.on_event
-    /// [`Error`]: enum.Error.html
-    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
-    /// [`Box`]: https://doc.rust-lang.org/std/boxed/struct.Box.html
-    /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
-    pub fn dispatch_event(&mut self, event_identifier: &T) {
-        if let Some(listener_collection) = self.events.get_mut(event_identifier) {
-            let mut found_invalid_weak_ref = false;
+    /// Returns the relocations to apply on the dispatcher's collection.
+    fn dispatch_collection(
+        &mut self,
+        listener_collection: &FnsAndTraits<T, P, R>,
+        handler: &SyncRequestHandler<T, P, R>,
+        event_identifier: &T,
+        event: &T,
+        chain: &mut DispatchChain<T>,
+    ) -> (ExecuteRequestsResult, Relocations<T, P, R>) {
+        let remaining;
+        let listener_collection = if self.detached.is_empty() {
+            listener_collection
+        } else {
+            remaining = listener_collection.without(&self.detached);
+            &remaining
+        };
 
-            execute_sync_dispatcher_requests(&mut listener_collection.traits, |weak_listener| {
-                if let Some(listener_arc) = weak_listener.upgrade() {
-                    let mut listener = listener_arc.lock().expect("TODO:");
-                    listener.on_event(event_identifier)
-                } else {
-                    found_invalid_weak_ref = true;
-                    None
-                }
-            });
+        let (result, mut relocations) =
+            listener_collection.dispatch(self, handler, event_identifier, event, chain);
+        let detached = ::std::mem::take(&mut relocations.detached);
+        self.detached.append(detached);
+        report_dead_listeners(
+            &self.dead_listener_handler,
+            event_identifier,
+            relocations.dead,
+            true,
+        );
 
-            execute_sync_dispatcher_requests(&mut listener_collection.fns, |callback| {
-                callback(event_identifier)
-            });
+        (result, relocations)
+    }
+}
+
+impl<P, T, R> Drop for PriorityEventDispatcher<P, T, R>
+where
+    P: Ord,
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        let mut notified = FnsAndTraits::default();
 
-            if found_invalid_weak_ref {
-                listener_collection
-                    .traits
-                    .retain(|listener| Weak::clone(listener).upgrade().is_some());
+        for (event_identifier, prioritised_listener_collection) in &self.events {
+            for listener_collection in prioritised_listener_collection.values() {
+                let dead = listener_collection.notify_dispatcher_dropped(&mut notified);
+                report_dead_listeners(&self.dead_listener_handler, event_identifier, dead, false);
             }
         }
+
+        // Dead listeners are not reported, see `EventDispatcher::on_dead_listener`.
+        for listener_collection in self.global.values() {
+            listener_collection.notify_dispatcher_dropped(&mut notified);
+        }
     }
 }
 
+/// Errors for ThreadPool-building related failures.
+#[derive(Fail, Debug)]
+pub enum BuildError {
+    #[fail(display = "Internal error on trying to build thread-pool: {:?}", _0)]
+    NumThreads(String),
+}
+
 /// Owns a map of all listened event-variants,
 /// [`Weak`]-references to their listeners and [`Fn`]s.
-/// Opposed to [`EventListener`], this structure utilises one [`BTreeMap`] per
-/// event-type to order listeners by a given priority-level.
-///
-/// **Note**: Consider implementing your own [`Ord`]-trait, if you
-/// want a different kind of order.
 ///
 /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
-/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
-/// [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
-/// [`EventListener`]: struct.EventDispatcher.html
 /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
-pub struct PriorityEventDispatcher<P, T>
+pub struct ParallelEventDispatcher<T, R = ParallelDispatcherRequest>
 where
-    P: Ord,
     T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
 {
-    events: PriorityListenerMap<P, T>,
+    events: ParallelListenerMap<T, R>,
+    request_handler: Box<ParallelRequestHandler<R>>,
+    thread_pool: Option<ThreadPool>,
+    dead_listener_handler: Option<DeadListenerHandler<T>>,
+    /// Listeners receiving all events.
+    global: ParallelFnsAndTraits<T, R>,
+    unhandled_handler: Option<UnhandledHandler<T>>,
+    dead_letters: DeadLetters<T>,
+    /// Returns an event's parent, if the event-hierarchy is used.
+    parent: Option<ParentFn<T>>,
+    /// Records dispatched events, once tracing has been enabled.
+    tracer: Option<Tracer<T>>,
 }
 
-impl<P, T> Default for PriorityEventDispatcher<P, T>
+impl<T> Default for ParallelEventDispatcher<T>
 where
-    P: Ord + Clone,
     T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
 {
-    fn default() -> PriorityEventDispatcher<P, T> {
-        PriorityEventDispatcher {
-            events: PriorityListenerMap::new(),
-        }
+    fn default() -> ParallelEventDispatcher<T> {
+        ParallelEventDispatcher::new_with_request_handler(
+            |request: ParallelDispatcherRequest| Some(request),
+        )
     }
 }
 
-impl<P, T> PriorityEventDispatcher<P, T>
+impl<T, R> ParallelEventDispatcher<T, R>
 where
-    P: Ord + Clone,
     T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: 'static,
 {
-    /// Adds a [`Listener`] to listen for an `event_identifier`, considering
-    /// a given `priority` implementing the [`Ord`]-trait, to sort dispatch-order.
+    /// Creates a dispatcher whose listeners return requests of type `R`,
+    /// translated into [`ParallelDispatcherRequest`]s by `handler`.
+    ///
+    /// **Note**: `handler` is called concurrently by all threads
+    /// dispatching to listeners.
+    ///
+    /// [`ParallelDispatcherRequest`]: enum.ParallelDispatcherRequest.html
+    pub fn new_with_request_handler<H>(handler: H) -> Self
+    where
+        H: RequestHandler<R, ParallelDispatcherRequest> + 'static,
+    {
+        ParallelEventDispatcher {
+            events: ParallelListenerMap::new(),
+            request_handler: Box::new(handler),
+            thread_pool: None,
+            dead_listener_handler: None,
+            global: ParallelFnsAndTraits::default(),
+            unhandled_handler: None,
+            dead_letters: DeadLetters::new(),
+            parent: None,
+            tracer: None,
+        }
+    }
+
+    /// Adds a [`ParallelListener`] to listen for an `event_identifier`.
     /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
     ///
     /// **Note**: If your `Enum` owns fields you need to consider implementing
@@ -526,13 +4651,15 @@ where
     ///
     /// # Examples
     ///
-    /// Adding a [`Listener`] to the dispatcher:
+    /// Adding a [`ParallelListener`] to the dispatcher:
     ///
     /// ```rust
     /// extern crate hey_listen;
     /// use std::sync::{Arc, Mutex};
     ///
-    /// use hey_listen::{Listener, PriorityEventDispatcher, SyncDispatcherRequest};
+    /// use hey_listen::ParallelListener;
+    /// use hey_listen::ParallelEventDispatcher;
+    /// use hey_listen::ParallelDispatcherRequest;
     ///
     /// #[derive(Clone, Eq, Hash, PartialEq)]
     /// enum Event {
@@ -541,15 +4668,15 @@ where
     ///
     /// struct ListenerStruct {}
     ///
-    /// impl Listener<Event> for ListenerStruct {
-    ///     fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> { None }
+    /// impl ParallelListener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, event: &Event) -> Option<ParallelDispatcherRequest> { None }
     /// }
     ///
     /// fn main() {
     ///     let listener = Arc::new(Mutex::new(ListenerStruct {}));
-    ///     let mut dispatcher: PriorityEventDispatcher<u32, Event> = PriorityEventDispatcher::default();
+    ///     let mut dispatcher: ParallelEventDispatcher<Event> = ParallelEventDispatcher::default();
     ///
-    ///     dispatcher.add_listener(Event::EventType, &listener, 1);
+    ///     dispatcher.add_listener(Event::EventType, &listener);
     /// }
     /// ```
     ///
@@ -578,230 +4705,77 @@ where
     /// impl Eq for Event {}
     /// ```
     ///
-    /// [`EventDispatcher`]: struct.EventDispatcher.html
-    /// [`Listener`]: trait.Listener.html
+    /// [`ParallelListener`]: trait.ParallelListener.html
     /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
-    /// [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
-    pub fn add_listener<D: Listener<T> + Send + Sync + 'static>(
-        &mut self,
-        event_identifier: T,
-        listener: &Arc<Mutex<D>>,
-        priority: P,
-    ) {
-        if let Some(prioritised_listener_collection) = self.events.get_mut(&event_identifier) {
-            if let Some(priority_level_collection) =
-                prioritised_listener_collection.get_mut(&priority)
-            {
-                priority_level_collection.traits.push(Arc::downgrade(
-                    &(Arc::clone(listener) as Arc<Mutex<Listener<T> + Send + Sync + 'static>>),
-                ));
+    pub fn add_listener<L>(&mut self, event_identifier: T, listener: &Arc<L>)
+    where
+        L: ListenerLock,
+        L::Listener: ParallelListener<T, R>,
+    {
+        let listener = Arc::clone(listener) as Arc<ParallelLockedListener<T, R>>;
+        listener.on_subscribe(&event_identifier);
+        let weak_listener = Arc::downgrade(&listener);
+
+        if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
+            listener_collection.traits.push(weak_listener);
 
-                return;
-            }
-            prioritised_listener_collection.insert(
-                priority.clone(),
-                FnsAndTraits::new_with_traits(vec![
-                    Arc::downgrade(
-                        &(Arc::clone(listener) as Arc<Mutex<Listener<T> + Send + Sync + 'static>>),
-                    ),
-                ]),
-            );
             return;
         }
 
-        let mut b_tree_map = BTreeMap::new();
-        b_tree_map.insert(
-            priority,
-            FnsAndTraits::new_with_traits(vec![
-                Arc::downgrade(
-                    &(Arc::clone(listener) as Arc<Mutex<Listener<T> + Send + Sync + 'static>>),
-                ),
-            ]),
+        self.events.insert(
+            event_identifier,
+            ParallelFnsAndTraits::new_with_traits(vec![weak_listener]),
         );
-        self.events.insert(event_identifier, b_tree_map);
     }
 
-    /// Adds an [`Fn`] to listen for an `event_identifier`, considering
-    /// a given `priority` implementing the [`Ord`]-trait in order to sort dispatch-order.
-    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
-    ///
-    /// # Examples
-    ///
-    /// Adding an [`Fn`] to the dispatcher:
-    ///
-    /// ```rust
-    /// extern crate hey_listen;
-    ///
-    /// use hey_listen::{PriorityEventDispatcher, SyncDispatcherRequest};
-    /// use std::sync::{Arc, Mutex};
-    ///
-    /// #[derive(Clone, Eq, Hash, PartialEq)]
-    /// enum Event {
-    ///     EventType,
-    /// }
-    ///
-    /// struct EventListener {
-    ///     used_method: bool,
-    /// }
-    ///
-    /// impl EventListener {
-    ///     fn test_method(&mut self, _event: &Event) {
-    ///         self.used_method = true;
-    ///     }
-    /// }
-    ///
-    /// fn main() {
-    ///     let listener = Arc::new(Mutex::new(EventListener { used_method: false }));
-    ///     let mut dispatcher: PriorityEventDispatcher<u32, Event> = PriorityEventDispatcher::default();
-    ///     let weak_listener_ref = Arc::downgrade(&Arc::clone(&listener));
-    ///
-    ///     let closure = Box::new(move |event: &Event| -> Option<SyncDispatcherRequest> {
-    ///         if let Some(listener) = weak_listener_ref.upgrade() {
-    ///             listener.lock().expect("TODO:").test_method(&event);
-    ///
-    ///             None
-    ///         } else {
-    ///             Some(SyncDispatcherRequest::StopListening)
-    ///         }
-    ///     });
+    /// Adds a [`ParallelListener`] to listen for all events returned by
+    /// its [`subscriptions`]-method.
     ///
-    ///     dispatcher.add_fn(Event::EventType, closure, 1);
-    /// }
-    /// ```
+    /// See [`EventDispatcher::register`] for an example.
     ///
-    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
-    /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
-    /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
-    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
-    pub fn add_fn(
-        &mut self,
-        event_identifier: T,
-        function: Box<Fn(&T) -> Option<SyncDispatcherRequest> + Send + Sync>,
-        priority: P,
-    ) {
-        if let Some(prioritised_listener_collection) = self.events.get_mut(&event_identifier) {
-            if let Some(priority_level_collection) =
-                prioritised_listener_collection.get_mut(&priority)
-            {
-                priority_level_collection.fns.push(function);
-
-                return;
-            }
-            prioritised_listener_collection
-                .insert(priority.clone(), FnsAndTraits::new_with_fns(vec![function]));
-            return;
-        }
-
-        let mut b_tree_map = BTreeMap::new();
-        b_tree_map.insert(priority, FnsAndTraits::new_with_fns(vec![function]));
-        self.events.insert(event_identifier, b_tree_map);
+    /// [`ParallelListener`]: trait.ParallelListener.html
+    /// [`subscriptions`]: trait.ParallelListener.html#method.subscriptions
+    /// [`EventDispatcher::register`]: struct.EventDispatcher.html#method.register
+    pub fn register<L>(&mut self, listener: &Arc<L>)
+    where
+        L: ListenerLock,
+        L::Listener: ParallelListener<T, R>,
+    {
+        let subscriptions = listener.with_listener(|listener| listener.subscriptions());
+        self.add_listener_for(subscriptions, listener);
     }
 
-    /// All [`Listener`]s listening to a passed `event_identifier`
-    /// will be called via their implemented [`on_event`]-method.
-    /// [`Fn`]s returning [`Result`] with `Ok(())` will be retained
-    /// and `Err(SyncDispatcherRequest::StopListening)` will cause them to
-    /// be removed from the event-dispatcher.
-    ///
-    /// **Notice**: [`Listener`]s will called ordered by their priority-level.
+    /// Adds a [`ParallelListener`] to listen for all `event_identifiers`.
     ///
-    /// [`Listener`]: trait.Listener.html
-    /// [`on_event`]: trait.Listener.html#tymethod.on_event
-    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
-    /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
-    pub fn dispatch_event(&mut self, event_identifier: &T) {
-        if let Some(prioritised_listener_collection) = self.events.get_mut(event_identifier) {
-            for (_, listener_collection) in prioritised_listener_collection.iter_mut() {
-                let mut found_invalid_weak_ref = false;
-
-                if let ExecuteRequestsResult::Stopped = execute_sync_dispatcher_requests(
-                    &mut listener_collection.traits,
-                    |weak_listener| {
-                        if let Some(listener_arc) = weak_listener.upgrade() {
-                            let mut listener = listener_arc.lock().expect("TODO:");
-                            listener.on_event(event_identifier)
-                        } else {
-                            found_invalid_weak_ref = true;
-                            None
-                        }
-                    },
-                ) {
-                    break;
-                }
-
-                if let ExecuteRequestsResult::Stopped = execute_sync_dispatcher_requests(
-                    &mut listener_collection.fns,
-                    |callback| callback(event_identifier),
-                ) {
-                    break;
-                }
-
-                if found_invalid_weak_ref {
-                    listener_collection
-                        .traits
-                        .retain(|listener| Weak::clone(listener).upgrade().is_some());
-                }
-            }
-        }
-    }
-}
-
-/// Errors for ThreadPool-building related failures.
-#[derive(Fail, Debug)]
-pub enum BuildError {
-    #[fail(display = "Internal error on trying to build thread-pool: {:?}", _0)]
-    NumThreads(String),
-}
-
-/// Owns a map of all listened event-variants,
-/// [`Weak`]-references to their listeners and [`Fn`]s.
-///
-/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
-/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
-pub struct ParallelEventDispatcher<T>
-where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
-{
-    events: ParallelListenerMap<T>,
-    thread_pool: Option<ThreadPool>,
-}
-
-impl<T> Default for ParallelEventDispatcher<T>
-where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
-{
-    fn default() -> ParallelEventDispatcher<T> {
-        ParallelEventDispatcher {
-            events: ParallelListenerMap::new(),
-            thread_pool: None,
+    /// [`ParallelListener`]: trait.ParallelListener.html
+    pub fn add_listener_for<I, L>(&mut self, event_identifiers: I, listener: &Arc<L>)
+    where
+        I: IntoIterator<Item = T>,
+        L: ListenerLock,
+        L::Listener: ParallelListener<T, R>,
+    {
+        for event_identifier in event_identifiers {
+            self.add_listener(event_identifier, listener);
         }
     }
-}
 
-impl<T> ParallelEventDispatcher<T>
-where
-    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
-{
-    /// Adds a [`ParallelListener`] to listen for an `event_identifier`.
+    /// Hands a [`ParallelListener`] over to the dispatcher, listening for an `event_identifier`.
     /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
     ///
-    /// **Note**: If your `Enum` owns fields you need to consider implementing
-    /// the [`Hash`]- and [`PartialEq`]-trait if you want to ignore fields,
-    /// see second example for an implementation-suggestion.
+    /// Opposed to [`add_listener`], the dispatcher owns the [`ParallelListener`],
+    /// hence it lives until it requests to stop listening or is removed
+    /// via [`remove_listener`] using the returned [`ListenerId`].
     ///
     /// # Examples
     ///
-    /// Adding a [`ParallelListener`] to the dispatcher:
+    /// Adding an owned [`ParallelListener`] to the dispatcher:
     ///
     /// ```rust
-    /// extern crate hey_listen;
-    /// use std::sync::{Arc, Mutex};
-    ///
-    /// use hey_listen::ParallelListener;
-    /// use hey_listen::ParallelEventDispatcher;
-    /// use hey_listen::ParallelDispatcherRequest;
+    /// extern crate hey_listen;
+    ///
+    /// use hey_listen::{ParallelListener, ParallelEventDispatcher, ParallelDispatcherRequest};
     ///
     /// #[derive(Clone, Eq, Hash, PartialEq)]
     /// enum Event {
@@ -815,66 +4789,137 @@ where
     /// }
     ///
     /// fn main() {
-    ///     let listener = Arc::new(Mutex::new(ListenerStruct {}));
     ///     let mut dispatcher: ParallelEventDispatcher<Event> = ParallelEventDispatcher::default();
     ///
-    ///     dispatcher.add_listener(Event::EventType, &listener);
+    ///     let id = dispatcher.add_owned_listener(Event::EventType, ListenerStruct {});
+    ///     dispatcher.dispatch_event(&Event::EventType);
+    ///
+    ///     assert!(dispatcher.remove_listener(id));
     /// }
     /// ```
     ///
-    /// Declaring your own [`Hash`]- and [`PartialEq`]-trait to bypass
-    /// hashing on fields:
-    ///
-    /// ```rust
-    /// use std::hash::{Hash, Hasher};
-    /// use std::mem::discriminant;
+    /// [`ParallelListener`]: trait.ParallelListener.html
+    /// [`ListenerId`]: struct.ListenerId.html
+    /// [`add_listener`]: struct.ParallelEventDispatcher.html#method.add_listener
+    /// [`remove_listener`]: struct.ParallelEventDispatcher.html#method.remove_listener
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_owned_listener<D: ParallelListener<T, R> + Send + Sync + 'static>(
+        &mut self,
+        event_identifier: T,
+        listener: D,
+    ) -> ListenerId {
+        self.insert_owned(
+            event_identifier,
+            Arc::new(Mutex::new(listener)) as Arc<ParallelLockedListener<T, R>>,
+        )
+    }
+
+    /// Adds a [`ParallelListener`] to listen for an `event_identifier`,
+    /// only receiving events `predicate` returns `true` for.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
     ///
-    /// #[derive(Clone)]
-    /// enum Event {
-    ///     TestVariant(i32),
-    /// }
+    /// `predicate` is evaluated before the listener's lock is taken,
+    /// hence rejected events do not lock the listener at all.
+    /// The filter can be removed via [`remove_listener`] using the
+    /// returned [`ListenerId`].
     ///
-    /// impl Hash for Event {
-    ///     fn hash<H: Hasher>(&self, _state: &mut H) {}
-    /// }
+    /// See [`EventDispatcher::add_listener_filtered`] for an example.
     ///
-    /// impl PartialEq for Event {
-    ///     fn eq(&self, other: &Event) -> bool {
-    ///         discriminant(self) == discriminant(other)
-    ///     }
-    /// }
+    /// [`ParallelListener`]: trait.ParallelListener.html
+    /// [`ListenerId`]: struct.ListenerId.html
+    /// [`remove_listener`]: #method.remove_listener
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`EventDispatcher::add_listener_filtered`]: struct.EventDispatcher.html#method.add_listener_filtered
+    pub fn add_listener_filtered<L, F>(
+        &mut self,
+        event_identifier: T,
+        listener: &Arc<L>,
+        predicate: F,
+    ) -> ListenerId
+    where
+        L: ListenerLock,
+        L::Listener: ParallelListener<T, R>,
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        let listener = FilteredListener::new(listener, predicate);
+
+        self.insert_owned(
+            event_identifier,
+            Arc::new(listener) as Arc<ParallelLockedListener<T, R>>,
+        )
+    }
+
+    /// Adds the owned `listener` to the listener-collection of `event_identifier`.
+    fn insert_owned(
+        &mut self,
+        event_identifier: T,
+        listener: Arc<ParallelLockedListener<T, R>>,
+    ) -> ListenerId {
+        let id = ListenerId::new();
+        listener.on_subscribe(&event_identifier);
+        let owned = OwnedListener { id, listener };
+
+        if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
+            listener_collection.owned.push(owned);
+
+            return id;
+        }
+
+        self.events.insert(
+            event_identifier,
+            ParallelFnsAndTraits::new_with_owned(vec![owned]),
+        );
+
+        id
+    }
+
+    /// Adds a [`ParallelSharedListener`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
     ///
-    /// impl Eq for Event {}
-    /// ```
+    /// Opposed to [`add_listener`], the dispatcher stores a [`Weak`]-reference
+    /// to the [`ParallelSharedListener`] itself, hence no [`Mutex`] is required
+    /// and one listener can process multiple events simultaneously.
     ///
-    /// [`ParallelListener`]: trait.ParallelListener.html
-    /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
-    /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
+    /// [`ParallelSharedListener`]: trait.ParallelSharedListener.html
+    /// [`add_listener`]: struct.ParallelEventDispatcher.html#method.add_listener
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    /// [`Mutex`]: https://doc.rust-lang.org/std/sync/struct.Mutex.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
-    pub fn add_listener<D: ParallelListener<T> + Send + Sync + 'static>(
+    pub fn add_shared_listener<D: ParallelSharedListener<T, R> + Send + Sync + 'static>(
         &mut self,
         event_identifier: T,
-        listener: &Arc<Mutex<D>>,
+        listener: &Arc<D>,
     ) {
+        listener.on_subscribe(&event_identifier);
+        let weak_listener = Arc::downgrade(
+            &(Arc::clone(listener) as Arc<ParallelSharedListener<T, R> + Send + Sync + 'static>),
+        );
+
         if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
-            listener_collection.traits.push(Arc::downgrade(
-                &(Arc::clone(listener) as Arc<Mutex<ParallelListener<T> + Send + Sync + 'static>>),
-            ));
+            listener_collection.shared.push(weak_listener);
 
             return;
         }
 
         self.events.insert(
             event_identifier,
-            ParallelFnsAndTraits::new_with_traits(vec![
-                Arc::downgrade(
-                    &(Arc::clone(listener)
-                        as Arc<Mutex<ParallelListener<T> + Send + Sync + 'static>>),
-                ),
-            ]),
+            ParallelFnsAndTraits::new_with_shared(vec![weak_listener]),
         );
     }
 
+    /// Removes the owned [`ParallelListener`] identified by `id` from
+    /// the dispatcher, dropping it.
+    /// Returns `false` if no such [`ParallelListener`] has been found.
+    ///
+    /// [`ParallelListener`]: trait.ParallelListener.html
+    pub fn remove_listener(&mut self, id: ListenerId) -> bool {
+        self.events
+            .iter_mut()
+            .any(|(event_identifier, listener_collection)| {
+                listener_collection.remove_owned(event_identifier, id, UnsubscribeReason::Removed)
+            })
+    }
+
     /// Adds a [`Fn`] to listen for an `event_identifier`.
     /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
     ///
@@ -932,11 +4977,114 @@ where
     /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
     /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
     /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
-    pub fn add_fn(
+    pub fn add_fn(&mut self, event_identifier: T, function: BoxedFn<T, R>) {
+        self.insert_fn(event_identifier, Arc::from(function));
+    }
+
+    /// Adds one [`Fn`] to listen for all `event_identifiers`.
+    /// The [`Fn`] is stored once and shared among all events,
+    /// hence its captured state is not cloned.
+    ///
+    /// See [`EventDispatcher::add_fn_for`] for an example.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`EventDispatcher::add_fn_for`]: struct.EventDispatcher.html#method.add_fn_for
+    pub fn add_fn_for<I>(
+        &mut self,
+        event_identifiers: I,
+        function: BoxedFn<T, R>,
+    ) where
+        I: IntoIterator<Item = T>,
+    {
+        let function: Arc<ParallelFn<T, R>> = Arc::from(function);
+
+        for event_identifier in event_identifiers {
+            self.insert_fn(event_identifier, Arc::clone(&function));
+        }
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier`, only
+    /// being called with events `predicate` returns `true` for.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_fn_filtered<F>(
         &mut self,
         event_identifier: T,
-        function: Box<Fn(&T) -> Option<ParallelDispatcherRequest> + Send + Sync>,
-    ) {
+        function: BoxedFn<T, R>,
+        predicate: F,
+    ) where
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        self.add_fn(
+            event_identifier,
+            Box::new(move |event: &T| {
+                if predicate(event) {
+                    function(event)
+                } else {
+                    None
+                }
+            }),
+        );
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier`, calling it with
+    /// the value guarded by `target` alongside the event.
+    /// Only a [`Weak`]-reference to `target` is held, once `target` has been
+    /// dropped, the [`Fn`] is removed.
+    ///
+    /// See [`EventDispatcher::add_weak_fn`] for an example.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    /// [`EventDispatcher::add_weak_fn`]: struct.EventDispatcher.html#method.add_weak_fn
+    pub fn add_weak_fn<L, F>(&mut self, event_identifier: T, target: &Arc<L>, function: F)
+    where
+        L: ListenerLock,
+        F: Fn(&mut L::Listener, &T) -> Option<R> + Send + Sync + 'static,
+    {
+        let listener = WeakFn::new(target, function);
+
+        self.insert_owned(
+            event_identifier,
+            Arc::new(listener) as Arc<ParallelLockedListener<T, R>>,
+        );
+    }
+
+    /// Adds a [`ParallelListener`] to listen for all events.
+    ///
+    /// Global listeners receive an event once all listeners registered
+    /// for the event returned.
+    /// As they do not subscribe to a specific event, [`on_subscribe`]
+    /// is not called.
+    ///
+    /// See [`EventDispatcher::add_global_listener`] for an example.
+    ///
+    /// [`ParallelListener`]: trait.ParallelListener.html
+    /// [`on_subscribe`]: trait.ParallelListener.html#method.on_subscribe
+    /// [`EventDispatcher::add_global_listener`]: struct.EventDispatcher.html#method.add_global_listener
+    pub fn add_global_listener<L>(&mut self, listener: &Arc<L>)
+    where
+        L: ListenerLock,
+        L::Listener: ParallelListener<T, R>,
+    {
+        let listener = Arc::clone(listener) as Arc<ParallelLockedListener<T, R>>;
+        self.global.traits.push(Arc::downgrade(&listener));
+    }
+
+    /// Adds a [`Fn`] to listen for all events.
+    ///
+    /// See [`add_global_listener`] for when global listeners are called.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`add_global_listener`]: #method.add_global_listener
+    pub fn add_global_fn(&mut self, function: BoxedFn<T, R>) {
+        self.global.fns.push(Arc::from(function));
+    }
+
+    /// Adds `function` to the listener-collection of `event_identifier`.
+    fn insert_fn(&mut self, event_identifier: T, function: Arc<ParallelFn<T, R>>) {
         if let Some(listener_collection) = self.events.get_mut(&event_identifier) {
             listener_collection.fns.push(function);
 
@@ -949,6 +5097,20 @@ where
         );
     }
 
+    /// Dispatches events to the listeners of all their ancestors
+    /// as well, as reported by [`EventHierarchy`], the listeners of
+    /// the most specific key first.
+    ///
+    /// See [`EventHierarchy`] for an example.
+    ///
+    /// [`EventHierarchy`]: trait.EventHierarchy.html
+    pub fn use_event_hierarchy(&mut self)
+    where
+        T: EventHierarchy,
+    {
+        self.parent = Some(T::parent);
+    }
+
     /// Immediately after calling this method,
     /// the dispatcher will attempt to build a thread-pool with
     /// `num` amount of threads.
@@ -969,6 +5131,107 @@ where
         }
     }
 
+    /// Sets `handler` to be called with a [`DeadListener`] for each
+    /// listener found dropped by its owner without unsubscribing,
+    /// either while dispatching or when the dispatcher drops.
+    ///
+    /// **Note**: `handler` is called after all listeners have been called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    ///
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use hey_listen::{ParallelEventDispatcher, ParallelDispatcherRequest, ParallelListener};
+    ///
+    /// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct;
+    ///
+    /// impl ParallelListener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, _: &Event) -> Option<ParallelDispatcherRequest> {
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut dispatcher: ParallelEventDispatcher<Event> = ParallelEventDispatcher::default();
+    ///     let listener = Arc::new(Mutex::new(ListenerStruct));
+    ///
+    ///     dispatcher.on_dead_listener(|dead| {
+    ///         assert!(dead.missed_dispatch());
+    ///         println!("A listener of {:?} has been dropped.", dead.event());
+    ///     });
+    ///     dispatcher.add_listener(Event::EventType, &listener);
+    ///
+    ///     drop(listener);
+    ///     dispatcher.dispatch_event(&Event::EventType);
+    /// }
+    /// ```
+    ///
+    /// [`DeadListener`]: struct.DeadListener.html
+    pub fn on_dead_listener<F>(&mut self, handler: F)
+    where
+        F: Fn(&DeadListener<T>) + Send + Sync + 'static,
+    {
+        self.dead_listener_handler = Some(Box::new(handler));
+    }
+
+    /// Sets `handler` to be called with each unhandled event,
+    /// see [`EventDispatcher::on_unhandled`] for details and an example.
+    ///
+    /// [`EventDispatcher::on_unhandled`]: struct.EventDispatcher.html#method.on_unhandled
+    pub fn on_unhandled<F>(&mut self, handler: F)
+    where
+        F: Fn(&T) + Send + Sync + 'static,
+    {
+        self.unhandled_handler = Some(Box::new(handler));
+    }
+
+    /// Keeps up to `capacity` unhandled events, as described at
+    /// [`on_unhandled`], until taken via [`take_dead_letters`].
+    /// Once full, the oldest event is dropped.
+    /// Defaults to `0`, keeping no events.
+    ///
+    /// [`on_unhandled`]: #method.on_unhandled
+    /// [`take_dead_letters`]: #method.take_dead_letters
+    pub fn dead_letter_capacity(&mut self, capacity: usize) {
+        self.dead_letters.set_capacity(capacity);
+    }
+
+    /// Removes all kept unhandled events and returns them,
+    /// the oldest event being first.
+    pub fn take_dead_letters(&mut self) -> Vec<T> {
+        self.dead_letters.take()
+    }
+
+    /// Starts recording which listeners receive dispatched events,
+    /// taken via [`take_trace`].
+    ///
+    /// Parallel listeners cannot dispatch follow-up events,
+    /// hence each traced event is a root of the [`Trace`].
+    ///
+    /// [`take_trace`]: #method.take_trace
+    /// [`Trace`]: struct.Trace.html
+    pub fn enable_tracing(&mut self) {
+        if self.tracer.is_none() {
+            self.tracer = Some(Tracer::new());
+        }
+    }
+
+    /// Takes all events recorded since tracing has been enabled or
+    /// the trace has last been taken, empty if tracing is disabled.
+    pub fn take_trace(&mut self) -> Trace<T> {
+        self.tracer
+            .as_mut()
+            .map_or_else(Trace::default, Tracer::take)
+    }
+
     /// All [`ParallelListener`]s listening to a passed `event_identifier`
     /// will be called via their implemented [`on_event`]-method.
     /// [`Fn`]s returning an [`Option`] wrapping [`ParallelDispatcherRequest`]
@@ -981,90 +5244,319 @@ where
     /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
     /// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
     pub fn dispatch_event(&mut self, event_identifier: &T) {
-        if let Some(listener_collection) = self.events.get_mut(event_identifier) {
-            let mut fns_to_remove = Mutex::new(Vec::new());
-            let mut traits_to_remove = Mutex::new(Vec::new());
+        // Lacking an emit-error handler, cyclic hierarchies are cut off silently.
+        let max_ancestors = DEFAULT_MAX_DISPATCH_DEPTH;
+        let handled = Lineage::new(event_identifier, self.parent, max_ancestors).any(|key| {
+            self.events
+                .get(&*key)
+                .is_some_and(ParallelFnsAndTraits::has_listeners)
+        });
 
-            if let Some(ref thread_pool) = self.thread_pool {
-                thread_pool.install(|| {
-                    ParallelEventDispatcher::joined_parallel_dispatch(
-                        listener_collection,
-                        event_identifier,
-                        &fns_to_remove,
-                        &traits_to_remove,
-                    )
-                });
-            } else {
-                ParallelEventDispatcher::joined_parallel_dispatch(
+        if !handled {
+            report_unhandled(&self.unhandled_handler, &mut self.dead_letters, event_identifier);
+        }
+
+        if let Some(ref mut tracer) = self.tracer {
+            tracer.enter(event_identifier, None);
+        }
+
+        for key in Lineage::new(event_identifier, self.parent, max_ancestors) {
+            if let Some(listener_collection) = self.events.get_mut(&*key) {
+                listener_collection.trace(&mut self.tracer, event_identifier);
+                let detached = ParallelEventDispatcher::dispatch_collection(
                     listener_collection,
+                    &*self.request_handler,
+                    &self.thread_pool,
+                    &self.dead_listener_handler,
+                    &key,
                     event_identifier,
-                    &fns_to_remove,
-                    &traits_to_remove,
                 );
+                self.remove_detached(event_identifier, &detached);
             }
+        }
 
-            fns_to_remove.lock().expect("TODO:").iter().for_each(|index| {
-                listener_collection.fns.swap_remove(*index);
-            });
+        // Global listeners receive the event once all listeners
+        // registered for it returned.
+        if !self.global.is_empty() {
+            self.global.trace(&mut self.tracer, event_identifier);
+            let detached = ParallelEventDispatcher::dispatch_collection(
+                &mut self.global,
+                &*self.request_handler,
+                &self.thread_pool,
+                &self.dead_listener_handler,
+                event_identifier,
+                event_identifier,
+            );
+            self.remove_detached(event_identifier, &detached);
+        }
 
-            traits_to_remove.lock().expect("TODO:").iter().for_each(|index| {
-                listener_collection.traits.swap_remove(*index);
-            });
+        if let Some(ref mut tracer) = self.tracer {
+            tracer.leave();
+        }
+    }
+
+    /// Dispatches `event` to all listeners of `listener_collection`,
+    /// listening for `event_identifier`, using `thread_pool` if available,
+    /// and removes all listeners requesting so.
+    ///
+    /// Returns all listeners requesting to stop listening to all events.
+    fn dispatch_collection(
+        listener_collection: &mut ParallelFnsAndTraits<T, R>,
+        handler: &ParallelRequestHandler<R>,
+        thread_pool: &Option<ThreadPool>,
+        dead_listener_handler: &Option<DeadListenerHandler<T>>,
+        event_identifier: &T,
+        event: &T,
+    ) -> ParallelFnsAndTraits<T, R> {
+        let to_remove = if let Some(ref thread_pool) = *thread_pool {
+            thread_pool.install(|| {
+                ParallelEventDispatcher::joined_parallel_dispatch(
+                    listener_collection,
+                    handler,
+                    event,
+                )
+            })
+        } else {
+            ParallelEventDispatcher::joined_parallel_dispatch(listener_collection, handler, event)
+        };
+
+        let (detached, dead) = to_remove.apply(event_identifier, listener_collection);
+        report_dead_listeners(dead_listener_handler, event_identifier, dead, true);
+
+        detached
+    }
+
+    /// Removes all listeners of `detached` from all events
+    /// and from the global listeners.
+    fn remove_detached(&mut self, event_identifier: &T, detached: &ParallelFnsAndTraits<T, R>) {
+        if detached.is_empty() {
+            return;
         }
+
+        for (listened_event, listener_collection) in self.events.iter_mut() {
+            listener_collection.remove_detached(listened_event, detached);
+        }
+
+        self.global.remove_detached(event_identifier, detached);
     }
 
     /// Encapsulates `Rayon`'s joined `par_iter`-function on
-    /// `Fn`s and `ParallelListener`s.
+    /// `Fn`s, `ParallelListener`s, owned `ParallelListener`s,
+    /// and `ParallelSharedListener`s.
+    /// Returns all listeners requesting to be removed,
+    /// their requests being translated via `handler`.
     ///
     /// This enables it to be used captured inside a `ThreadPool`'s
     /// `install`-method but also bare as is - in case no
     /// `ThreadPool` is avail.
+    ///
+    /// Listeners rejecting the event via `accepts` without being locked
+    /// are skipped before the dispatch is split among threads.
     fn joined_parallel_dispatch(
-        listener_collection: &ParallelFnsAndTraits<T>,
+        listener_collection: &ParallelFnsAndTraits<T, R>,
+        handler: &ParallelRequestHandler<R>,
         event_identifier: &T,
-        fns_to_remove: &Mutex<Vec<usize>>,
-        traits_to_remove: &Mutex<Vec<usize>>,
-    ) {
-        join(
+    ) -> ParallelRemovals {
+        let accepting_traits: Vec<_> = listener_collection
+            .traits
+            .iter()
+            .enumerate()
+            .filter_map(|(index, listener)| match listener.upgrade() {
+                Some(ref listener) if !listener.accepts(event_identifier) => None,
+                listener => Some((index, listener)),
+            })
+            .collect();
+        let accepting_owned: Vec<_> = listener_collection
+            .owned
+            .iter()
+            .filter(|owned| {
+                owned.listener.is_dead() || owned.listener.accepts(event_identifier)
+            })
+            .collect();
+
+        let ((traits, owned), (shared, fns)) = join(
             || {
-                listener_collection
-                    .traits
-                    .par_iter()
-                    .enumerate()
-                    .for_each(|(index, listener)| {
-                        if let Some(listener_arc) = listener.upgrade() {
-                            let mut listener = listener_arc.lock().expect("TODO:");
-
-                            if let Some(instruction) = listener.on_event(event_identifier) {
-                                match instruction {
-                                    ParallelDispatcherRequest::StopListening => {
-                                        traits_to_remove.lock().expect("TODO:").push(index)
-                                    }
+                join(
+                    || {
+                        accepting_traits
+                            .par_iter()
+                            .filter_map(|&(index, ref listener)| {
+                                if let Some(ref listener) = *listener {
+                                    listener
+                                        .on_event(event_identifier)
+                                        .and_then(|request| handler.handle(request))
+                                        .map(|request| (index, request))
+                                } else {
+                                    Some((index, ParallelDispatcherRequest::StopListening))
                                 }
-                            }
-                        } else {
-                            traits_to_remove.lock().expect("TODO:").push(index)
-                        }
-                    })
+                            })
+                            .collect()
+                    },
+                    || {
+                        accepting_owned
+                            .par_iter()
+                            .filter_map(|owned| {
+                                if owned.listener.is_dead() {
+                                    let request = ParallelDispatcherRequest::StopListening;
+
+                                    return Some((owned.id, request));
+                                }
+
+                                owned
+                                    .listener
+                                    .on_event(event_identifier)
+                                    .and_then(|request| handler.handle(request))
+                                    .map(|request| (owned.id, request))
+                            })
+                            .collect()
+                    },
+                )
             },
             || {
-                listener_collection
-                    .fns
-                    .par_iter()
-                    .enumerate()
-                    .for_each(|(index, callback)| {
-                        if let Some(instruction) = callback(event_identifier) {
-                            match instruction {
-                                ParallelDispatcherRequest::StopListening => {
-                                    fns_to_remove.lock().expect("TODO:").push(index);
+                join(
+                    || {
+                        listener_collection
+                            .shared
+                            .par_iter()
+                            .enumerate()
+                            .filter_map(|(index, listener)| {
+                                if let Some(listener) = listener.upgrade() {
+                                    listener
+                                        .on_event(event_identifier)
+                                        .and_then(|request| handler.handle(request))
+                                        .map(|request| (index, request))
+                                } else {
+                                    Some((index, ParallelDispatcherRequest::StopListening))
                                 }
-                            }
-                        } else {
-                            ()
-                        }
-                    });
+                            })
+                            .collect()
+                    },
+                    || {
+                        listener_collection
+                            .fns
+                            .par_iter()
+                            .enumerate()
+                            .filter_map(|(index, callback)| {
+                                callback(event_identifier)
+                                    .and_then(|request| handler.handle(request))
+                                    .map(|request| (index, request))
+                            })
+                            .collect()
+                    },
+                )
             },
         );
+
+        ParallelRemovals {
+            traits,
+            owned,
+            shared,
+            fns,
+        }
+    }
+}
+
+impl<T, R> Drop for ParallelEventDispatcher<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        let mut notified = ParallelFnsAndTraits::default();
+
+        for (event_identifier, listener_collection) in &self.events {
+            let dead = listener_collection.notify_dispatcher_dropped(&mut notified);
+            report_dead_listeners(&self.dead_listener_handler, event_identifier, dead, false);
+        }
+
+        // Dead listeners are not reported, see `EventDispatcher::on_dead_listener`.
+        self.global.notify_dispatcher_dropped(&mut notified);
+    }
+}
+
+/// Collects listeners requesting removal during a parallel dispatch,
+/// alongside their request.
+struct ParallelRemovals {
+    traits: Vec<(usize, ParallelDispatcherRequest)>,
+    owned: Vec<(ListenerId, ParallelDispatcherRequest)>,
+    shared: Vec<(usize, ParallelDispatcherRequest)>,
+    fns: Vec<(usize, ParallelDispatcherRequest)>,
+}
+
+impl ParallelRemovals {
+    /// Removes all collected listeners from `listener_collection`,
+    /// listening for `event_identifier`.
+    ///
+    /// Returns all listeners and closures requesting to stop listening to all
+    /// events, owned listeners are omitted as they are registered for one event only.
+    /// Additionally returns the amount of removed listeners that have been
+    /// dropped without unsubscribing.
+    fn apply<T, R>(
+        self,
+        event_identifier: &T,
+        listener_collection: &mut ParallelFnsAndTraits<T, R>,
+    ) -> (ParallelFnsAndTraits<T, R>, usize)
+    where
+        T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    {
+        let mut detached = ParallelFnsAndTraits::default();
+        let mut dead = 0;
+
+        for &(index, ref request) in &self.traits {
+            let weak_listener = &listener_collection.traits[index];
+
+            if let Some(listener) = weak_listener.upgrade() {
+                listener.on_unsubscribe(event_identifier, request.reason());
+            } else {
+                dead += 1;
+            }
+
+            if let ParallelDispatcherRequest::StopListeningToAll = *request {
+                detached.traits.push(Weak::clone(weak_listener));
+            }
+        }
+
+        for &(index, ref request) in &self.shared {
+            let weak_listener = &listener_collection.shared[index];
+
+            if let Some(listener) = weak_listener.upgrade() {
+                listener.on_unsubscribe(event_identifier, request.reason());
+            } else {
+                dead += 1;
+            }
+
+            if let ParallelDispatcherRequest::StopListeningToAll = *request {
+                detached.shared.push(Weak::clone(weak_listener));
+            }
+        }
+
+        for &(index, ref request) in &self.fns {
+            if let ParallelDispatcherRequest::StopListeningToAll = *request {
+                detached
+                    .fns
+                    .push(Arc::clone(&listener_collection.fns[index]));
+            }
+        }
+
+        let mut traits: Vec<usize> = self.traits.iter().map(|&(index, _)| index).collect();
+        let mut shared: Vec<usize> = self.shared.iter().map(|&(index, _)| index).collect();
+        let mut fns: Vec<usize> = self.fns.iter().map(|&(index, _)| index).collect();
+        swap_remove_indices(&mut listener_collection.traits, &mut traits);
+        swap_remove_indices(&mut listener_collection.shared, &mut shared);
+        swap_remove_indices(&mut listener_collection.fns, &mut fns);
+
+        for (id, request) in self.owned {
+            if listener_collection
+                .owned
+                .iter()
+                .any(|owned| owned.id == id && owned.listener.is_dead())
+            {
+                dead += 1;
+            }
+
+            listener_collection.remove_owned(event_identifier, id, request.reason());
+        }
+
+        (detached, dead)
     }
 }
 
@@ -1076,45 +5568,65 @@ mod tests {
     mod execute_sync_dispatcher_requests {
         use super::*;
 
-        fn map_usize_to_request(x: &usize) -> Option<SyncDispatcherRequest> {
+        fn map_usize_to_request(x: &usize) -> Option<ListenerRequest<()>> {
             match *x {
-                0 => Some(SyncDispatcherRequest::StopListening),
-                1 => Some(SyncDispatcherRequest::StopPropagation),
-                2 => Some(SyncDispatcherRequest::StopListeningAndPropagation),
+                0 => Some(ListenerRequest::StopListening),
+                1 => Some(ListenerRequest::StopPropagation),
+                2 => Some(ListenerRequest::StopListeningAndPropagation),
                 _ => None,
             }
         }
 
-        #[test]
-        fn stop_listening() {
-            let mut vec = vec![0, 0, 0, 1, 1, 1, 1];
-            execute_sync_dispatcher_requests(&mut vec, map_usize_to_request);
+        /// Returns all elements of `slice` passed to `unsubscribe`.
+        fn unsubscribed(slice: &[usize]) -> Vec<usize> {
+            let mut unsubscribed = vec![];
+            execute_sync_dispatcher_requests(
+                slice,
+                map_usize_to_request,
+                |_| (),
+                |x, _| unsubscribed.push(*x),
+            );
 
-            assert_eq!(vec, [1, 0, 0, 1, 1, 1]);
+            unsubscribed
         }
 
         #[test]
-        fn empty_vec() {
-            let mut vec = Vec::new();
-            execute_sync_dispatcher_requests(&mut vec, map_usize_to_request);
+        fn stop_listening() {
+            assert_eq!(unsubscribed(&[0, 0, 0, 1, 0, 1, 1]), [0, 0, 0]);
+        }
 
-            assert!(vec.is_empty());
+        #[test]
+        fn empty_slice() {
+            assert!(unsubscribed(&[]).is_empty());
         }
 
         #[test]
         fn removing_all() {
-            let mut vec = vec![0, 0, 0, 0, 0, 0, 0];
-            execute_sync_dispatcher_requests(&mut vec, map_usize_to_request);
-
-            assert!(vec.is_empty());
+            assert_eq!(unsubscribed(&[0, 0, 0, 0, 0, 0, 0]), [0; 7]);
         }
 
         #[test]
         fn remove_one_element_and_stop() {
-            let mut vec = vec![2, 0];
-            execute_sync_dispatcher_requests(&mut vec, map_usize_to_request);
+            assert_eq!(unsubscribed(&[2, 0]), [2]);
+        }
 
-            assert_eq!(vec, [0]);
+        #[test]
+        fn unsubscribe_removed_elements() {
+            let mut unsubscribed = vec![];
+            execute_sync_dispatcher_requests(
+                &[0, 3, 2, 0],
+                map_usize_to_request,
+                |_| (),
+                |x, reason| unsubscribed.push((*x, reason)),
+            );
+
+            assert_eq!(
+                unsubscribed,
+                [
+                    (0, UnsubscribeReason::StopListening),
+                    (2, UnsubscribeReason::StopListening),
+                ]
+            );
         }
     }
 }
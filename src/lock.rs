@@ -0,0 +1,162 @@
+//! Locks guarding listeners.
+//!
+//! Dispatchers do not depend on a specific lock-implementation,
+//! instead they accept every lock implementing [`ListenerLock`].
+//! Implementations for `std`'s `Mutex` and `RwLock` are always available,
+//! `parking_lot`'s and `spin`'s locks are supported via the equally named
+//! features.
+//!
+//! [`ListenerLock`]: trait.ListenerLock.html
+#[cfg(feature = "parking_lot")]
+use parking_lot;
+#[cfg(feature = "spin")]
+use spin;
+
+/// A lock granting exclusive access to a listener.
+///
+/// Implement this trait for your own lock in order to register
+/// listeners guarded by it.
+///
+/// # Examples
+///
+/// Wrapping a listener into a custom lock:
+///
+/// ```rust
+/// extern crate hey_listen;
+///
+/// use hey_listen::ListenerLock;
+/// use std::sync::Mutex;
+///
+/// struct LoggingLock<D>(Mutex<D>);
+///
+/// impl<D: Send + 'static> ListenerLock for LoggingLock<D> {
+///     type Listener = D;
+///
+///     fn with_listener<R, F: FnOnce(&mut D) -> R>(&self, function: F) -> R {
+///         println!("Locking listener.");
+///
+///         function(&mut self.0.lock().expect("Listener's lock is poisoned."))
+///     }
+/// }
+/// ```
+pub trait ListenerLock: Send + Sync + 'static {
+    /// The guarded listener.
+    type Listener: ?Sized;
+
+    /// Locks `self`, passes the guarded listener to `function`,
+    /// and unlocks again after `function` returned.
+    fn with_listener<R, F: FnOnce(&mut Self::Listener) -> R>(&self, function: F) -> R;
+
+    /// Passes a shared reference to the guarded listener to `function`,
+    /// if `self` grants shared access, e.g. via a read-lock.
+    ///
+    /// Dispatchers use it to ask a listener whether it accepts an event
+    /// without taking exclusive access.
+    /// By default, it returns `None`, signalling no shared access is granted.
+    fn with_listener_ref<R, F: FnOnce(&Self::Listener) -> R>(&self, _function: F) -> Option<R> {
+        None
+    }
+}
+
+/// Calls `function` with the listener guarded by `lock`, unless
+/// `accepts` returns `false` for it.
+///
+/// If `lock` grants shared access, `accepts` is evaluated without taking
+/// exclusive access first, hence rejecting listeners are not locked.
+pub(crate) fn call_accepting<L, O, A, F>(lock: &L, accepts: A, function: F) -> Option<O>
+where
+    L: ListenerLock + ?Sized,
+    A: Fn(&L::Listener) -> bool,
+    F: FnOnce(&mut L::Listener) -> Option<O>,
+{
+    if let Some(false) = lock.with_listener_ref(|listener| accepts(listener)) {
+        return None;
+    }
+
+    lock.with_listener(|listener| {
+        if accepts(listener) {
+            function(listener)
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns whether the listener guarded by `lock` passes `accepts`.
+///
+/// If `lock` grants shared access, `accepts` is evaluated without taking
+/// exclusive access, otherwise `lock` is locked for the evaluation only.
+pub(crate) fn check_accepts<L, A>(lock: &L, accepts: A) -> bool
+where
+    L: ListenerLock + ?Sized,
+    A: Fn(&L::Listener) -> bool,
+{
+    match lock.with_listener_ref(|listener| accepts(listener)) {
+        Some(accepted) => accepted,
+        None => lock.with_listener(|listener| accepts(listener)),
+    }
+}
+
+impl<D: ?Sized + Send + 'static> ListenerLock for ::std::sync::Mutex<D> {
+    type Listener = D;
+
+    fn with_listener<R, F: FnOnce(&mut D) -> R>(&self, function: F) -> R {
+        function(&mut self.lock().expect("Listener's lock is poisoned."))
+    }
+}
+
+impl<D: ?Sized + Send + Sync + 'static> ListenerLock for ::std::sync::RwLock<D> {
+    type Listener = D;
+
+    fn with_listener<R, F: FnOnce(&mut D) -> R>(&self, function: F) -> R {
+        function(&mut self.write().expect("Listener's lock is poisoned."))
+    }
+
+    fn with_listener_ref<R, F: FnOnce(&D) -> R>(&self, function: F) -> Option<R> {
+        Some(function(&self.read().expect("Listener's lock is poisoned.")))
+    }
+}
+
+#[cfg(feature = "parking_lot")]
+impl<D: ?Sized + Send + 'static> ListenerLock for parking_lot::Mutex<D> {
+    type Listener = D;
+
+    fn with_listener<R, F: FnOnce(&mut D) -> R>(&self, function: F) -> R {
+        function(&mut self.lock())
+    }
+}
+
+#[cfg(feature = "parking_lot")]
+impl<D: ?Sized + Send + Sync + 'static> ListenerLock for parking_lot::RwLock<D> {
+    type Listener = D;
+
+    fn with_listener<R, F: FnOnce(&mut D) -> R>(&self, function: F) -> R {
+        function(&mut self.write())
+    }
+
+    fn with_listener_ref<R, F: FnOnce(&D) -> R>(&self, function: F) -> Option<R> {
+        Some(function(&self.read()))
+    }
+}
+
+#[cfg(feature = "spin")]
+impl<D: ?Sized + Send + 'static> ListenerLock for spin::Mutex<D> {
+    type Listener = D;
+
+    fn with_listener<R, F: FnOnce(&mut D) -> R>(&self, function: F) -> R {
+        function(&mut self.lock())
+    }
+}
+
+#[cfg(feature = "spin")]
+impl<D: ?Sized + Send + Sync + 'static> ListenerLock for spin::RwLock<D> {
+    type Listener = D;
+
+    fn with_listener<R, F: FnOnce(&mut D) -> R>(&self, function: F) -> R {
+        function(&mut self.write())
+    }
+
+    fn with_listener_ref<R, F: FnOnce(&D) -> R>(&self, function: F) -> Option<R> {
+        Some(function(&self.read()))
+    }
+}
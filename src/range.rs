@@ -0,0 +1,226 @@
+//! Subscriptions to ranges of ordered events.
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::hash::Hash;
+use std::ops::{Bound, RangeBounds};
+use std::sync::Arc;
+
+use super::{FnsAndTraits, ListenerId, UnsubscribeReason};
+
+/// The listener-collection of a range, shared with dispatches in progress.
+type RangeCollection<T, R> = Arc<FnsAndTraits<T, (), R>>;
+
+/// The bounds of a range of events listeners subscribed to.
+pub(crate) struct EventRange<T> {
+    start: Bound<T>,
+    end: Bound<T>,
+}
+
+impl<T: Ord + Clone> EventRange<T> {
+    pub(crate) fn new<B: RangeBounds<T>>(range: &B) -> Self {
+        EventRange {
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+
+    /// Returns the event the range starts at, if bounded.
+    pub(crate) fn start(&self) -> Option<&T> {
+        match self.start {
+            Bound::Included(ref start) | Bound::Excluded(ref start) => Some(start),
+            Bound::Unbounded => None,
+        }
+    }
+
+    fn contains(&self, event: &T) -> bool {
+        (self.start.as_ref(), self.end.as_ref()).contains(event)
+    }
+}
+
+/// Orders start-bounds, an unbounded start being the lowest.
+fn compare_starts<T: Ord>(first: &Bound<T>, second: &Bound<T>) -> Ordering {
+    match (first, second) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Less,
+        (_, Bound::Unbounded) => Ordering::Greater,
+        (Bound::Included(first), Bound::Excluded(second)) => {
+            first.cmp(second).then(Ordering::Less)
+        }
+        (Bound::Excluded(first), Bound::Included(second)) => {
+            first.cmp(second).then(Ordering::Greater)
+        }
+        (Bound::Included(first), Bound::Included(second))
+        | (Bound::Excluded(first), Bound::Excluded(second)) => first.cmp(second),
+    }
+}
+
+/// Orders end-bounds, an unbounded end being the highest.
+fn compare_ends<T: Ord>(first: &Bound<T>, second: &Bound<T>) -> Ordering {
+    match (first, second) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Greater,
+        (_, Bound::Unbounded) => Ordering::Less,
+        (Bound::Included(first), Bound::Excluded(second)) => {
+            first.cmp(second).then(Ordering::Greater)
+        }
+        (Bound::Excluded(first), Bound::Included(second)) => {
+            first.cmp(second).then(Ordering::Less)
+        }
+        (Bound::Included(first), Bound::Included(second))
+        | (Bound::Excluded(first), Bound::Excluded(second)) => first.cmp(second),
+    }
+}
+
+impl<T: Ord> Ord for EventRange<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_starts(&self.start, &other.start).then_with(|| compare_ends(&self.end, &other.end))
+    }
+}
+
+impl<T: Ord> PartialOrd for EventRange<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> PartialEq for EventRange<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord> Eq for EventRange<T> {}
+
+/// Listener-collections subscribed to ranges of events.
+///
+/// Implemented for events implementing [`Ord`] only, hence dispatchers
+/// store it as trait-object created once the first range is subscribed to.
+///
+/// [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
+pub(crate) trait RangeListeners<T, R>: Send + Sync
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Returns the listener-collection of `range`, inserting it if missing.
+    fn entry(&mut self, range: EventRange<T>) -> &mut FnsAndTraits<T, (), R>;
+
+    /// Returns shared handles to all listener-collections whose range
+    /// contains `event`, ordered by their ranges' start.
+    fn matching(&self, event: &T) -> Vec<(EventRange<T>, RangeCollection<T, R>)>;
+
+    /// Calls `function` with the listener-collection of `range`, if any,
+    /// and removes it if it has been left without listeners.
+    fn relocate(
+        &mut self,
+        range: &EventRange<T>,
+        function: &mut FnMut(&mut RangeCollection<T, R>),
+    );
+
+    /// Calls `function` with each listener-collection.
+    fn for_each_mut(&mut self, function: &mut FnMut(&mut RangeCollection<T, R>));
+
+    /// Removes the owned listener `id`, notifying it with the start of its
+    /// range, if bounded. Returns whether it has been found.
+    fn remove_owned(&mut self, id: ListenerId) -> bool;
+}
+
+/// Indexes listener-collections in a [`BTreeMap`] by their range, ordered
+/// by the range's start, hence ranges starting after an event are skipped.
+///
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+pub(crate) struct RangeIndex<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    ranges: BTreeMap<EventRange<T>, RangeCollection<T, R>>,
+}
+
+impl<T, R> RangeIndex<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    pub(crate) fn new() -> Self {
+        RangeIndex {
+            ranges: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T, R> RangeListeners<T, R> for RangeIndex<T, R>
+where
+    T: Ord + PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn entry(&mut self, range: EventRange<T>) -> &mut FnsAndTraits<T, (), R> {
+        Arc::make_mut(self.ranges.entry(range).or_default())
+    }
+
+    fn matching(&self, event: &T) -> Vec<(EventRange<T>, RangeCollection<T, R>)> {
+        let latest_start = EventRange {
+            start: Bound::Included(event.clone()),
+            end: Bound::Unbounded,
+        };
+
+        self.ranges
+            .range(..=latest_start)
+            .filter(|&(range, _)| range.contains(event))
+            .map(|(range, listener_collection)| {
+                let range = EventRange {
+                    start: range.start.clone(),
+                    end: range.end.clone(),
+                };
+
+                (range, Arc::clone(listener_collection))
+            })
+            .collect()
+    }
+
+    fn relocate(
+        &mut self,
+        range: &EventRange<T>,
+        function: &mut FnMut(&mut RangeCollection<T, R>),
+    ) {
+        let is_empty = match self.ranges.get_mut(range) {
+            Some(listener_collection) => {
+                function(listener_collection);
+                listener_collection.is_empty()
+            }
+            None => return,
+        };
+
+        if is_empty {
+            self.ranges.remove(range);
+        }
+    }
+
+    fn for_each_mut(&mut self, function: &mut FnMut(&mut RangeCollection<T, R>)) {
+        for listener_collection in self.ranges.values_mut() {
+            function(listener_collection);
+        }
+    }
+
+    fn remove_owned(&mut self, id: ListenerId) -> bool {
+        let range = self.ranges.iter_mut().find_map(|(range, listener_collection)| {
+            let owned = Arc::make_mut(listener_collection).take_owned(id)?;
+
+            if let Some(start) = range.start() {
+                owned
+                    .listener
+                    .on_unsubscribe(start, UnsubscribeReason::Removed);
+            }
+
+            Some(EventRange {
+                start: range.start.clone(),
+                end: range.end.clone(),
+            })
+        });
+
+        match range {
+            Some(range) => {
+                self.relocate(&range, &mut |_| {});
+
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,1388 @@
+//! A thread-safe event-dispatcher, registering and dispatching via `&self`.
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex as StdMutex, RwLock, Weak};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::{call_sync_listener, remove_dead, report_dead_listeners, translating_handler,
+            BoxedContextFn, BoxedEnvelopeFn, BoxedFn, BoxedListener, DeadListener,
+            DeadListenerHandler, DispatchContext, DispatchError, EmitErrorHandler,
+            ExecuteRequestsResult, ListenerId, ListenerLock, Listener, ListenerRequest,
+            LockedListener, Mutex, RequestHandler, SharedListener, SyncDispatcherRequest,
+            SyncRequestHandler, TranslatingListener, UnhandledHandler, UnsubscribeReason};
+use dead_letters::DeadLetters;
+use envelope::{Clock, CorrelationId, EnvelopeListener, EnvelopedListener, Stamper};
+use filtered::FilteredListener;
+use hierarchy::{EventHierarchy, Lineage, ParentFn};
+use weak_fn::WeakFn;
+use context::{dispatch_chain, Causer, DispatchChain, NestedDispatch,
+              DEFAULT_MAX_DISPATCH_DEPTH};
+
+type SharedListenerMap<T, R> = HashMap<T, Arc<SharedFnsAndTraits<T, R>>>;
+type SharedEventFunction<T, R> = Fn(&T, &mut DispatchContext<T>) -> Option<R> + Send + Sync;
+/// An owned listener, translating its requests when being added.
+type OwnedLockedListener<T> = LockedListener<T, ListenerRequest<T>>;
+type Replacement<T, R> = (Member<T, R>, Identified<OwnedLockedListener<T>>);
+
+/// An identified, reference-counted listener,
+/// allowing its listener-collection to be cloned.
+struct Identified<L: ?Sized> {
+    id: ListenerId,
+    listener: Arc<L>,
+}
+
+impl<L: ?Sized> Clone for Identified<L> {
+    fn clone(&self) -> Self {
+        Identified {
+            id: self.id,
+            listener: Arc::clone(&self.listener),
+        }
+    }
+}
+
+/// Yields closures and trait-objects.
+/// Opposed to `FnsAndTraits`, it can be cloned in order to
+/// be updated copy-on-write.
+struct SharedFnsAndTraits<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    traits: Vec<Weak<LockedListener<T, R>>>,
+    owned: Vec<Identified<OwnedLockedListener<T>>>,
+    shared: Vec<Weak<SharedListener<T, R> + Send + Sync + 'static>>,
+    fns: Vec<Identified<SharedEventFunction<T, R>>>,
+}
+
+impl<T, R> Default for SharedFnsAndTraits<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        SharedFnsAndTraits {
+            traits: vec![],
+            owned: vec![],
+            shared: vec![],
+            fns: vec![],
+        }
+    }
+}
+
+impl<T, R> SharedFnsAndTraits<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Calls `on_dispatcher_dropped` on all listeners,
+    /// skipping listeners contained by `notified`.
+    ///
+    /// Returns the amount of listeners dropped without unsubscribing.
+    fn notify_dispatcher_dropped(&self, notified: &mut SharedFnsAndTraits<T, R>) -> usize {
+        let mut dead = 0;
+
+        for weak_listener in &self.traits {
+            match weak_listener.upgrade() {
+                Some(listener) => {
+                    if !notified.traits.iter().any(|listener| listener.ptr_eq(weak_listener)) {
+                        listener.on_dispatcher_dropped();
+                        notified.traits.push(Weak::clone(weak_listener));
+                    }
+                }
+                None => dead += 1,
+            }
+        }
+
+        for weak_listener in &self.shared {
+            match weak_listener.upgrade() {
+                Some(listener) => {
+                    if !notified.shared.iter().any(|listener| listener.ptr_eq(weak_listener)) {
+                        listener.on_dispatcher_dropped();
+                        notified.shared.push(Weak::clone(weak_listener));
+                    }
+                }
+                None => dead += 1,
+            }
+        }
+
+        for owned in &self.owned {
+            if owned.listener.is_dead() {
+                dead += 1;
+            } else {
+                owned.listener.on_dispatcher_dropped();
+            }
+        }
+
+        dead
+    }
+
+    /// Returns whether `self` contains no listeners.
+    fn is_empty(&self) -> bool {
+        self.traits.is_empty() && self.owned.is_empty() && self.shared.is_empty()
+            && self.fns.is_empty()
+    }
+
+    /// Returns whether any listener is registered, ignoring
+    /// listeners dropped without unsubscribing.
+    fn has_listeners(&self) -> bool {
+        !self.fns.is_empty()
+            || self.owned.iter().any(|owned| !owned.listener.is_dead())
+            || self.traits.iter().any(|listener| listener.upgrade().is_some())
+            || self.shared.iter().any(|listener| listener.upgrade().is_some())
+    }
+}
+
+impl<T, R> Clone for SharedFnsAndTraits<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        SharedFnsAndTraits {
+            traits: self.traits.clone(),
+            owned: self.owned.clone(),
+            shared: self.shared.clone(),
+            fns: self.fns.clone(),
+        }
+    }
+}
+
+/// How a listener leaves its listener-collection.
+enum Removal<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    StopListening,
+    StopListeningToAll,
+    Replace(BoxedListener<T, ()>),
+}
+
+impl<T> Removal<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn reason(&self) -> UnsubscribeReason {
+        match *self {
+            Removal::StopListening => UnsubscribeReason::StopListening,
+            Removal::StopListeningToAll => UnsubscribeReason::StopListeningToAll,
+            Removal::Replace(_) => UnsubscribeReason::Replaced,
+        }
+    }
+}
+
+/// Identifies a listener of a listener-collection.
+enum Member<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    Referenced(Weak<LockedListener<T, R>>),
+    Shared(Weak<SharedListener<T, R> + Send + Sync + 'static>),
+    /// An owned listener or a closure.
+    Identified(ListenerId),
+}
+
+impl<T, R> Member<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Returns whether `listener_collection` still contains the listener.
+    fn is_in(&self, listener_collection: &SharedFnsAndTraits<T, R>) -> bool {
+        match *self {
+            Member::Referenced(ref weak_listener) => listener_collection
+                .traits
+                .iter()
+                .any(|listener| listener.ptr_eq(weak_listener)),
+            Member::Shared(ref weak_listener) => listener_collection
+                .shared
+                .iter()
+                .any(|listener| listener.ptr_eq(weak_listener)),
+            Member::Identified(id) => {
+                listener_collection.owned.iter().any(|owned| owned.id == id)
+                    || listener_collection.fns.iter().any(|callback| callback.id == id)
+            }
+        }
+    }
+}
+
+/// Collects listeners requesting removal during a dispatch.
+struct SharedRemovals<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    traits: Vec<Weak<LockedListener<T, R>>>,
+    shared: Vec<Weak<SharedListener<T, R> + Send + Sync + 'static>>,
+    ids: Vec<ListenerId>,
+    /// Replacing listeners alongside the listener they replace.
+    replacements: Vec<Replacement<T, R>>,
+}
+
+impl<T, R> SharedRemovals<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn new() -> Self {
+        SharedRemovals {
+            traits: vec![],
+            shared: vec![],
+            ids: vec![],
+            replacements: vec![],
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.traits.is_empty() && self.shared.is_empty() && self.ids.is_empty()
+            && self.replacements.is_empty()
+    }
+
+    /// Records `member` leaving via `removal`, either in `self` or,
+    /// if it stops listening to all events, in `detached`.
+    /// A replacing listener is handed over as owned listener, identified by `id`.
+    fn record(
+        &mut self,
+        detached: &mut SharedRemovals<T, R>,
+        removal: Removal<T>,
+        id: ListenerId,
+        member: Member<T, R>,
+    ) {
+        match removal {
+            Removal::StopListening => self.push(&member),
+            Removal::StopListeningToAll => detached.push(&member),
+            Removal::Replace(listener) => {
+                self.push(&member);
+                self.replacements.push((
+                    member,
+                    Identified {
+                        id,
+                        listener: Arc::new(Mutex::new(listener)) as Arc<OwnedLockedListener<T>>,
+                    },
+                ));
+            }
+        }
+    }
+
+    fn push(&mut self, member: &Member<T, R>) {
+        match *member {
+            Member::Referenced(ref weak_listener) => self.traits.push(Weak::clone(weak_listener)),
+            Member::Shared(ref weak_listener) => self.shared.push(Weak::clone(weak_listener)),
+            Member::Identified(id) => self.ids.push(id),
+        }
+    }
+
+    /// Notifies all listeners of `self` about leaving the events of
+    /// `events` other than `dispatched`, which they have left already.
+    fn notify_detached(&self, events: &SharedListenerMap<T, R>, dispatched: &T) {
+        for (event_identifier, listener_collection) in events {
+            if event_identifier == dispatched {
+                continue;
+            }
+
+            for weak_listener in &listener_collection.traits {
+                if self.traits.iter().any(|removed| removed.ptr_eq(weak_listener)) {
+                    if let Some(listener) = weak_listener.upgrade() {
+                        listener.on_unsubscribe(
+                            event_identifier,
+                            UnsubscribeReason::StopListeningToAll,
+                        );
+                    }
+                }
+            }
+
+            for weak_listener in &listener_collection.shared {
+                if self.shared.iter().any(|removed| removed.ptr_eq(weak_listener)) {
+                    if let Some(listener) = weak_listener.upgrade() {
+                        listener.on_unsubscribe(
+                            event_identifier,
+                            UnsubscribeReason::StopListeningToAll,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Removes all collected listeners from `listener_collection`.
+    ///
+    /// Replacements are only added in place of listeners still contained,
+    /// as concurrent dispatches may have replaced them already.
+    /// Returns all added replacements.
+    fn apply(
+        &self,
+        listener_collection: &mut SharedFnsAndTraits<T, R>,
+    ) -> Vec<Identified<OwnedLockedListener<T>>> {
+        let replacements: Vec<_> = self.replacements
+            .iter()
+            .filter(|(replaced, _)| replaced.is_in(listener_collection))
+            .map(|(_, replacement)| replacement.clone())
+            .collect();
+
+        listener_collection
+            .traits
+            .retain(|listener| !self.traits.iter().any(|removed| removed.ptr_eq(listener)));
+        listener_collection
+            .shared
+            .retain(|listener| !self.shared.iter().any(|removed| removed.ptr_eq(listener)));
+        listener_collection
+            .owned
+            .retain(|owned| !self.ids.contains(&owned.id));
+        listener_collection
+            .fns
+            .retain(|callback| !self.ids.contains(&callback.id));
+        listener_collection
+            .owned
+            .extend(replacements.iter().cloned());
+
+        replacements
+    }
+}
+
+/// Iterates over `slice` and applies `function` to each element.
+/// Opposed to `execute_sync_dispatcher_requests`, elements requesting
+/// to stop listening are not removed but passed to `remove`,
+/// alongside how they leave.
+/// Requests to change the priority-level are ignored.
+fn visit_sync_dispatcher_requests<E, T, F, D>(
+    slice: &[E],
+    mut function: F,
+    mut remove: D,
+) -> ExecuteRequestsResult
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    F: FnMut(&E) -> Option<ListenerRequest<T>>,
+    D: FnMut(&E, Removal<T>),
+{
+    for element in slice {
+        match function(element) {
+            None
+            | Some(ListenerRequest::Emit(_))
+            | Some(ListenerRequest::ChangePriority(())) => (),
+            Some(ListenerRequest::StopListening) => remove(element, Removal::StopListening),
+            Some(ListenerRequest::StopListeningToAll) => {
+                remove(element, Removal::StopListeningToAll)
+            }
+            Some(ListenerRequest::StopPropagation) => return ExecuteRequestsResult::Stopped,
+            Some(ListenerRequest::StopListeningAndPropagation) => {
+                remove(element, Removal::StopListening);
+                return ExecuteRequestsResult::Stopped;
+            }
+            Some(ListenerRequest::Replace(listener)) => {
+                remove(element, Removal::Replace(listener))
+            }
+        }
+    }
+
+    ExecuteRequestsResult::Finished
+}
+
+/// Owns a map of all listened event-variants,
+/// [`Weak`]-references to their listeners and [`Fn`]s.
+///
+/// Opposed to [`EventDispatcher`], all methods take `&self`, hence
+/// the dispatcher can be shared between threads without wrapping it
+/// into a [`Mutex`].
+/// Listeners are stored copy-on-write: Dispatching works on a snapshot
+/// of all listeners and registering replaces the snapshot.
+/// Therefore, neither blocks the other beyond swapping an [`Arc`].
+///
+/// **Note**: Listeners registered while an event is being dispatched
+/// will receive the next dispatched event.
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+/// [`Mutex`]: https://doc.rust-lang.org/std/sync/struct.Mutex.html
+/// [`EventDispatcher`]: struct.EventDispatcher.html
+pub struct SharedEventDispatcher<T, R = SyncDispatcherRequest>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: 'static,
+{
+    events: RwLock<Arc<SharedListenerMap<T, R>>>,
+    /// Listeners receiving all events, stored copy-on-write as well.
+    global: RwLock<Arc<SharedFnsAndTraits<T, R>>>,
+    request_handler: Arc<SyncRequestHandler<T, (), R>>,
+    writer: StdMutex<()>,
+    max_dispatch_depth: AtomicUsize,
+    dead_listener_handler: RwLock<Option<DeadListenerHandler<T>>>,
+    unhandled_handler: RwLock<Option<UnhandledHandler<T>>>,
+    emit_error_handler: RwLock<Option<EmitErrorHandler<T>>>,
+    dead_letters: StdMutex<DeadLetters<T>>,
+    parent: RwLock<Option<ParentFn<T>>>,
+    stamper: RwLock<Stamper>,
+}
+
+impl<T, R> Default for SharedEventDispatcher<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: Into<ListenerRequest<T>> + 'static,
+{
+    fn default() -> SharedEventDispatcher<T, R> {
+        SharedEventDispatcher::new_with_request_handler(|request: R| Some(request))
+    }
+}
+
+impl<T, R> SharedEventDispatcher<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: 'static,
+{
+    /// Creates a dispatcher whose listeners return requests of type `R`,
+    /// translated by `handler` into requests `O`, e.g.
+    /// [`SyncDispatcherRequest`] or [`ListenerRequest`].
+    ///
+    /// See [`RequestHandler`] for an example.
+    ///
+    /// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
+    /// [`ListenerRequest`]: enum.ListenerRequest.html
+    /// [`RequestHandler`]: trait.RequestHandler.html
+    pub fn new_with_request_handler<H, O>(handler: H) -> Self
+    where
+        H: RequestHandler<R, O> + 'static,
+        O: Into<ListenerRequest<T>> + 'static,
+    {
+        SharedEventDispatcher {
+            events: RwLock::new(Arc::new(SharedListenerMap::new())),
+            global: RwLock::new(Arc::new(SharedFnsAndTraits::default())),
+            request_handler: translating_handler(handler),
+            writer: StdMutex::new(()),
+            max_dispatch_depth: AtomicUsize::new(DEFAULT_MAX_DISPATCH_DEPTH),
+            dead_listener_handler: RwLock::new(None),
+            unhandled_handler: RwLock::new(None),
+            emit_error_handler: RwLock::new(None),
+            dead_letters: StdMutex::new(DeadLetters::new()),
+            parent: RwLock::new(None),
+            stamper: RwLock::new(Stamper::new()),
+        }
+    }
+
+    /// Returns the current snapshot of all listeners.
+    fn snapshot(&self) -> Arc<SharedListenerMap<T, R>> {
+        Arc::clone(&self.events.read().expect("Dispatcher's lock is poisoned."))
+    }
+
+    /// Applies `function` on a copy of the current snapshot and
+    /// replaces the snapshot with the copy afterwards.
+    ///
+    /// Concurrent updates are serialised, dispatching is not blocked.
+    fn update<O, F: FnOnce(&mut SharedListenerMap<T, R>) -> O>(&self, function: F) -> O {
+        let _writer = self.writer.lock().expect("Dispatcher's lock is poisoned.");
+        let mut events = (*self.snapshot()).clone();
+        let result = function(&mut events);
+        *self.events.write().expect("Dispatcher's lock is poisoned.") = Arc::new(events);
+
+        result
+    }
+
+    /// Returns the current snapshot of all global listeners.
+    fn global_snapshot(&self) -> Arc<SharedFnsAndTraits<T, R>> {
+        Arc::clone(&self.global.read().expect("Dispatcher's lock is poisoned."))
+    }
+
+    /// Applies `function` on a copy of the global listeners, equally to [`update`].
+    ///
+    /// [`update`]: #method.update
+    fn update_global<F: FnOnce(&mut SharedFnsAndTraits<T, R>)>(&self, function: F) {
+        let _writer = self.writer.lock().expect("Dispatcher's lock is poisoned.");
+        let mut global = (*self.global_snapshot()).clone();
+        function(&mut global);
+        *self.global.write().expect("Dispatcher's lock is poisoned.") = Arc::new(global);
+    }
+
+    /// Applies `function` on a copy of the listener-collection of `event_identifier`,
+    /// inserting the collection if `event_identifier` is a new key.
+    fn update_collection<F: FnOnce(&mut SharedFnsAndTraits<T, R>)>(
+        &self,
+        event_identifier: T,
+        function: F,
+    ) {
+        self.update(|events| {
+            function(Arc::make_mut(events
+                .entry(event_identifier)
+                .or_insert_with(|| Arc::new(SharedFnsAndTraits::default()))))
+        });
+    }
+
+    /// Adds a [`Listener`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// # Examples
+    ///
+    /// Adding a [`Listener`] to the dispatcher from another thread:
+    ///
+    /// ```rust
+    /// extern crate hey_listen;
+    /// use std::sync::{Arc, Mutex};
+    /// use std::thread;
+    ///
+    /// use hey_listen::{Listener, SharedEventDispatcher, SyncDispatcherRequest};
+    ///
+    /// #[derive(Clone, Eq, Hash, PartialEq)]
+    /// enum Event {
+    ///     EventType,
+    /// }
+    ///
+    /// struct ListenerStruct {}
+    ///
+    /// impl Listener<Event> for ListenerStruct {
+    ///     fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> { None }
+    /// }
+    ///
+    /// fn main() {
+    ///     let listener = Arc::new(Mutex::new(ListenerStruct {}));
+    ///     let dispatcher: Arc<SharedEventDispatcher<Event>> = Arc::default();
+    ///
+    ///     let thread_dispatcher = Arc::clone(&dispatcher);
+    ///     let thread_listener = Arc::clone(&listener);
+    ///
+    ///     thread::spawn(move || {
+    ///         thread_dispatcher.add_listener(Event::EventType, &thread_listener);
+    ///     }).join().unwrap();
+    ///
+    ///     dispatcher.dispatch_event(&Event::EventType);
+    /// }
+    /// ```
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_listener<L>(&self, event_identifier: T, listener: &Arc<L>)
+    where
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+    {
+        let listener = Arc::clone(listener) as Arc<LockedListener<T, R>>;
+        listener.on_subscribe(&event_identifier);
+        let weak_listener = Arc::downgrade(&listener);
+
+        self.update_collection(event_identifier, |listener_collection| {
+            listener_collection.traits.push(weak_listener)
+        });
+    }
+
+    /// Adds a [`Listener`] to listen for all events returned by
+    /// its [`subscriptions`]-method.
+    ///
+    /// See [`EventDispatcher::register`] for an example.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`subscriptions`]: trait.Listener.html#method.subscriptions
+    /// [`EventDispatcher::register`]: struct.EventDispatcher.html#method.register
+    pub fn register<L>(&self, listener: &Arc<L>)
+    where
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+    {
+        let subscriptions = listener.with_listener(|listener| listener.subscriptions());
+        self.add_listener_for(subscriptions, listener);
+    }
+
+    /// Adds a [`Listener`] to listen for all `event_identifiers`.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    pub fn add_listener_for<I, L>(&self, event_identifiers: I, listener: &Arc<L>)
+    where
+        I: IntoIterator<Item = T>,
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+    {
+        for event_identifier in event_identifiers {
+            self.add_listener(event_identifier, listener);
+        }
+    }
+
+    /// Hands a [`Listener`] over to the dispatcher, listening for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// The [`Listener`] lives until it requests to stop listening or is removed
+    /// via [`remove_listener`] using the returned [`ListenerId`].
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`ListenerId`]: struct.ListenerId.html
+    /// [`remove_listener`]: struct.SharedEventDispatcher.html#method.remove_listener
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_owned_listener<D: Listener<T, R> + Send + Sync + 'static>(
+        &self,
+        event_identifier: T,
+        listener: D,
+    ) -> ListenerId {
+        let listener = self.translating(Mutex::new(listener));
+
+        self.insert_owned(event_identifier, listener)
+    }
+
+    /// Adds a [`Listener`] to listen for an `event_identifier`, only
+    /// receiving events `predicate` returns `true` for.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// `predicate` is evaluated before the listener's lock is taken,
+    /// hence rejected events do not lock the listener at all.
+    /// The filter can be removed via [`remove_listener`] using the
+    /// returned [`ListenerId`].
+    ///
+    /// See [`EventDispatcher::add_listener_filtered`] for an example.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`ListenerId`]: struct.ListenerId.html
+    /// [`remove_listener`]: #method.remove_listener
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`EventDispatcher::add_listener_filtered`]: struct.EventDispatcher.html#method.add_listener_filtered
+    pub fn add_listener_filtered<L, F>(
+        &self,
+        event_identifier: T,
+        listener: &Arc<L>,
+        predicate: F,
+    ) -> ListenerId
+    where
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        let listener = self.translating(FilteredListener::new(listener, predicate));
+
+        self.insert_owned(event_identifier, listener)
+    }
+
+    /// Wraps `listener` to translate its requests via the request handler.
+    fn translating<L>(&self, listener: L) -> Arc<OwnedLockedListener<T>>
+    where
+        L: LockedListener<T, R> + 'static,
+    {
+        Arc::new(TranslatingListener {
+            listener,
+            handler: Arc::clone(&self.request_handler),
+        })
+    }
+
+    /// Adds the owned `listener` to the listener-collection of `event_identifier`.
+    fn insert_owned(
+        &self,
+        event_identifier: T,
+        listener: Arc<OwnedLockedListener<T>>,
+    ) -> ListenerId {
+        let id = ListenerId::new();
+        let owned = Identified { id, listener };
+        owned.listener.on_subscribe(&event_identifier);
+
+        self.update_collection(event_identifier, |listener_collection| {
+            listener_collection.owned.push(owned)
+        });
+
+        id
+    }
+
+    /// Adds a [`SharedListener`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// [`SharedListener`]: trait.SharedListener.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_shared_listener<D: SharedListener<T, R> + Send + Sync + 'static>(
+        &self,
+        event_identifier: T,
+        listener: &Arc<D>,
+    ) {
+        listener.on_subscribe(&event_identifier);
+        let weak_listener = Arc::downgrade(
+            &(Arc::clone(listener) as Arc<SharedListener<T, R> + Send + Sync + 'static>),
+        );
+
+        self.update_collection(event_identifier, |listener_collection| {
+            listener_collection.shared.push(weak_listener)
+        });
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier`.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_fn(&self, event_identifier: T, function: BoxedFn<T, R>) {
+        self.add_context_fn(
+            event_identifier,
+            Box::new(move |event: &T, _: &mut DispatchContext<T>| function(event)),
+        );
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier`, receiving
+    /// a [`DispatchContext`] to dispatch follow-up events with.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_context_fn(&self, event_identifier: T, function: BoxedContextFn<T, R>) {
+        let function = Identified {
+            id: ListenerId::new(),
+            listener: Arc::from(function),
+        };
+
+        self.update_collection(event_identifier, |listener_collection| {
+            listener_collection.fns.push(function)
+        });
+    }
+
+    /// Adds an [`EnvelopeListener`] to listen for an `event_identifier`,
+    /// receiving each dispatched event wrapped in its [`Envelope`].
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// See [`EventDispatcher::add_envelope_listener`] for details.
+    ///
+    /// [`EnvelopeListener`]: trait.EnvelopeListener.html
+    /// [`Envelope`]: struct.Envelope.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    /// [`EventDispatcher::add_envelope_listener`]: struct.EventDispatcher.html#method.add_envelope_listener
+    pub fn add_envelope_listener<L>(&self, event_identifier: T, listener: &Arc<L>) -> ListenerId
+    where
+        L: ListenerLock,
+        L::Listener: EnvelopeListener<T, R>,
+    {
+        let listener = self.translating(EnvelopedListener::new(listener));
+
+        self.insert_owned(event_identifier, listener)
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier`, receiving
+    /// each dispatched event wrapped in its [`Envelope`].
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Envelope`]: struct.Envelope.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_envelope_fn(&self, event_identifier: T, function: BoxedEnvelopeFn<T, R>) {
+        self.add_context_fn(
+            event_identifier,
+            Box::new(move |_: &T, context: &mut DispatchContext<T>| function(context.envelope())),
+        );
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier`, only
+    /// being called with events `predicate` returns `true` for.
+    /// If `event_identifier` is a new [`HashMap`]-key, it will be added.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn add_fn_filtered<F>(
+        &self,
+        event_identifier: T,
+        function: BoxedFn<T, R>,
+        predicate: F,
+    ) where
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        self.add_fn(
+            event_identifier,
+            Box::new(move |event: &T| {
+                if predicate(event) {
+                    function(event)
+                } else {
+                    None
+                }
+            }),
+        );
+    }
+
+    /// Adds a [`Fn`] to listen for an `event_identifier`, calling it with
+    /// the value guarded by `target` alongside the event.
+    /// Only a [`Weak`]-reference to `target` is held, once `target` has been
+    /// dropped, the [`Fn`] is removed.
+    ///
+    /// See [`EventDispatcher::add_weak_fn`] for an example.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    /// [`EventDispatcher::add_weak_fn`]: struct.EventDispatcher.html#method.add_weak_fn
+    pub fn add_weak_fn<L, F>(&self, event_identifier: T, target: &Arc<L>, function: F)
+    where
+        L: ListenerLock,
+        F: Fn(&mut L::Listener, &T) -> Option<R> + Send + Sync + 'static,
+    {
+        let listener = self.translating(WeakFn::new(target, function));
+        self.insert_owned(event_identifier, listener);
+    }
+
+    /// Adds one [`Fn`] to listen for all `event_identifiers`,
+    /// sharing one [`ListenerId`] among all events.
+    ///
+    /// See [`EventDispatcher::add_fn_for`] for an example.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`ListenerId`]: struct.ListenerId.html
+    /// [`EventDispatcher::add_fn_for`]: struct.EventDispatcher.html#method.add_fn_for
+    pub fn add_fn_for<I>(
+        &self,
+        event_identifiers: I,
+        function: BoxedFn<T, R>,
+    ) where
+        I: IntoIterator<Item = T>,
+    {
+        let function = Identified {
+            id: ListenerId::new(),
+            listener: Arc::new(move |event: &T, _: &mut DispatchContext<T>| function(event))
+                as Arc<SharedEventFunction<T, R>>,
+        };
+
+        self.update(|events| {
+            for event_identifier in event_identifiers {
+                Arc::make_mut(events
+                    .entry(event_identifier)
+                    .or_insert_with(|| Arc::new(SharedFnsAndTraits::default())))
+                    .fns
+                    .push(function.clone());
+            }
+        });
+    }
+
+    /// Adds a [`Listener`] to listen for all events.
+    ///
+    /// Global listeners receive an event after all listeners registered
+    /// for the event, unless propagation has been stopped.
+    /// As they do not subscribe to a specific event, [`on_subscribe`]
+    /// is not called.
+    ///
+    /// See [`EventDispatcher::add_global_listener`] for an example.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`on_subscribe`]: trait.Listener.html#method.on_subscribe
+    /// [`EventDispatcher::add_global_listener`]: struct.EventDispatcher.html#method.add_global_listener
+    pub fn add_global_listener<L>(&self, listener: &Arc<L>)
+    where
+        L: ListenerLock,
+        L::Listener: Listener<T, R>,
+    {
+        let listener = Arc::clone(listener) as Arc<LockedListener<T, R>>;
+        let weak_listener = Arc::downgrade(&listener);
+
+        self.update_global(|global| global.traits.push(weak_listener));
+    }
+
+    /// Adds a [`Fn`] to listen for all events.
+    ///
+    /// See [`add_global_listener`] for when global listeners are called.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`add_global_listener`]: #method.add_global_listener
+    pub fn add_global_fn(&self, function: BoxedFn<T, R>) {
+        let function = Identified {
+            id: ListenerId::new(),
+            listener: Arc::new(move |event: &T, _: &mut DispatchContext<T>| function(event))
+                as Arc<SharedEventFunction<T, R>>,
+        };
+
+        self.update_global(|global| global.fns.push(function));
+    }
+
+    /// Removes the owned [`Listener`] identified by `id` from
+    /// the dispatcher, dropping it.
+    /// Returns `false` if no such [`Listener`] has been found.
+    ///
+    /// **Note**: An ongoing dispatch may still call the [`Listener`].
+    ///
+    /// [`Listener`]: trait.Listener.html
+    pub fn remove_listener(&self, id: ListenerId) -> bool {
+        let removed = self.update(|events| {
+            let mut removed = vec![];
+
+            for (event_identifier, listener_collection) in events.iter_mut() {
+                let found = listener_collection
+                    .owned
+                    .iter()
+                    .find(|owned| owned.id == id)
+                    .map(|owned| Arc::clone(&owned.listener));
+
+                if let Some(listener) = found {
+                    Arc::make_mut(listener_collection)
+                        .owned
+                        .retain(|owned| owned.id != id);
+                    removed.push((event_identifier.clone(), listener));
+                }
+            }
+
+            removed
+        });
+
+        // Listeners are notified outside of the update, allowing them
+        // to register on this dispatcher.
+        for (event_identifier, listener) in &removed {
+            listener.on_unsubscribe(event_identifier, UnsubscribeReason::Removed);
+        }
+
+        !removed.is_empty()
+    }
+
+    /// All [`Listener`]s listening to a passed `event_identifier`
+    /// will be called via their implemented [`on_event`]-method.
+    /// Listeners returning a [`SyncDispatcherRequest`] to stop listening
+    /// will be removed from the event-dispatcher.
+    ///
+    /// Dispatching does not block other threads from dispatching or
+    /// registering listeners.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`on_event`]: trait.Listener.html#tymethod.on_event
+    /// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
+    pub fn dispatch_event(&self, event_identifier: &T) {
+        self.dispatch_correlated(event_identifier, CorrelationId::new());
+    }
+
+    /// Dispatches `event_identifier` like [`dispatch_event`], continuing
+    /// the correlation `correlation_id` instead of starting a new one.
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    pub fn dispatch_correlated(&self, event_identifier: &T, correlation_id: CorrelationId) {
+        let max_depth = self.max_dispatch_depth.load(Ordering::Relaxed);
+
+        dispatch_chain(&mut &*self, event_identifier, max_depth, correlation_id, None);
+    }
+
+    /// Sets the [`Clock`] providing the timestamps of [`Envelope`]s,
+    /// defaults to [`SystemClock`].
+    ///
+    /// [`Clock`]: trait.Clock.html
+    /// [`Envelope`]: struct.Envelope.html
+    /// [`SystemClock`]: struct.SystemClock.html
+    pub fn clock<C: Clock + 'static>(&self, clock: C) {
+        self.stamper
+            .write()
+            .expect("Dispatcher's lock is poisoned.")
+            .set_clock(Box::new(clock));
+    }
+
+    /// Sets the source ID carried by [`Envelope`]s, unset by default.
+    ///
+    /// [`Envelope`]: struct.Envelope.html
+    pub fn source_id<S: Into<String>>(&self, source_id: S) {
+        self.stamper
+            .write()
+            .expect("Dispatcher's lock is poisoned.")
+            .set_source_id(source_id.into());
+    }
+
+    /// Sets the maximum depth of follow-up events dispatched via
+    /// [`DispatchContext`], defaults to `32`.
+    ///
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    pub fn max_dispatch_depth(&self, depth: usize) {
+        self.max_dispatch_depth.store(depth, Ordering::Relaxed);
+    }
+
+    /// Dispatches events to the listeners of all their ancestors
+    /// as well, as reported by [`EventHierarchy`], the listeners of
+    /// the most specific key first.
+    ///
+    /// Propagation stopped by a listener also stops propagation to
+    /// the listeners of ancestors.
+    ///
+    /// See [`EventHierarchy`] for an example.
+    ///
+    /// [`EventHierarchy`]: trait.EventHierarchy.html
+    pub fn use_event_hierarchy(&self)
+    where
+        T: EventHierarchy,
+    {
+        *self.parent.write().expect("Dispatcher's lock is poisoned.") = Some(T::parent);
+    }
+
+    /// Sets `handler` to be called with a [`DeadListener`] for each
+    /// listener found dropped by its owner without unsubscribing,
+    /// either while dispatching or when the dispatcher drops.
+    ///
+    /// See [`EventDispatcher::on_dead_listener`] for an example.
+    ///
+    /// [`DeadListener`]: struct.DeadListener.html
+    /// [`EventDispatcher::on_dead_listener`]: struct.EventDispatcher.html#method.on_dead_listener
+    pub fn on_dead_listener<F>(&self, handler: F)
+    where
+        F: Fn(&DeadListener<T>) + Send + Sync + 'static,
+    {
+        *self.dead_listener_handler
+            .write()
+            .expect("Dispatcher's lock is poisoned.") = Some(Box::new(handler));
+    }
+
+    /// Passes a [`DeadListener`] to the handler set via [`on_dead_listener`]
+    /// for each of `count` listeners of `event_identifier`.
+    ///
+    /// [`DeadListener`]: struct.DeadListener.html
+    /// [`on_dead_listener`]: #method.on_dead_listener
+    fn report_dead_listeners(&self, event_identifier: &T, count: usize, missed_dispatch: bool) {
+        if count == 0 {
+            return;
+        }
+
+        if let Ok(handler) = self.dead_listener_handler.read() {
+            report_dead_listeners(&*handler, event_identifier, count, missed_dispatch);
+        }
+    }
+
+    /// Sets `handler` to be called with each unhandled event,
+    /// see [`EventDispatcher::on_unhandled`] for details and an example.
+    ///
+    /// [`EventDispatcher::on_unhandled`]: struct.EventDispatcher.html#method.on_unhandled
+    pub fn on_unhandled<F>(&self, handler: F)
+    where
+        F: Fn(&T) + Send + Sync + 'static,
+    {
+        *self.unhandled_handler
+            .write()
+            .expect("Dispatcher's lock is poisoned.") = Some(Box::new(handler));
+    }
+
+    /// Sets `handler` to be called with each event emitted via
+    /// [`ListenerRequest::Emit`] that is rejected with a [`DispatchError`].
+    ///
+    /// See [`EventDispatcher::on_emit_error`] for an example.
+    ///
+    /// [`ListenerRequest::Emit`]: enum.ListenerRequest.html#variant.Emit
+    /// [`DispatchError`]: enum.DispatchError.html
+    /// [`EventDispatcher::on_emit_error`]: struct.EventDispatcher.html#method.on_emit_error
+    pub fn on_emit_error<F>(&self, handler: F)
+    where
+        F: Fn(&T, &DispatchError) + Send + Sync + 'static,
+    {
+        *self.emit_error_handler
+            .write()
+            .expect("Dispatcher's lock is poisoned.") = Some(Box::new(handler));
+    }
+
+    /// Keeps up to `capacity` unhandled events, as described at
+    /// [`on_unhandled`], until taken via [`take_dead_letters`].
+    /// Once full, the oldest event is dropped.
+    /// Defaults to `0`, keeping no events.
+    ///
+    /// [`on_unhandled`]: #method.on_unhandled
+    /// [`take_dead_letters`]: #method.take_dead_letters
+    pub fn dead_letter_capacity(&self, capacity: usize) {
+        self.dead_letters
+            .lock()
+            .expect("Dispatcher's lock is poisoned.")
+            .set_capacity(capacity);
+    }
+
+    /// Removes all kept unhandled events and returns them,
+    /// the oldest event being first.
+    pub fn take_dead_letters(&self) -> Vec<T> {
+        self.dead_letters
+            .lock()
+            .expect("Dispatcher's lock is poisoned.")
+            .take()
+    }
+
+    /// Passes `event_identifier` to the handler set via [`on_unhandled`]
+    /// and keeps it as dead letter.
+    ///
+    /// [`on_unhandled`]: #method.on_unhandled
+    fn report_unhandled(&self, event_identifier: &T) {
+        if let Ok(handler) = self.unhandled_handler.read() {
+            if let Some(ref handler) = *handler {
+                handler(event_identifier);
+            }
+        }
+
+        if let Ok(mut dead_letters) = self.dead_letters.lock() {
+            dead_letters.push(event_identifier);
+        }
+    }
+}
+
+impl<T, R> NestedDispatch<T> for &SharedEventDispatcher<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: 'static,
+{
+    fn dispatch_nested(&mut self, event_identifier: &T, chain: &mut DispatchChain<T>) {
+        let snapshot = self.snapshot();
+        let parent = *self.parent.read().expect("Dispatcher's lock is poisoned.");
+        let mut ancestors = Lineage::new(event_identifier, parent, chain.max_depth);
+        let lineage: Vec<_> = ancestors
+            .by_ref()
+            .map(|key| {
+                let listener_collection = snapshot.get(&*key).cloned();
+
+                (key, listener_collection)
+            })
+            .collect();
+
+        if let Some(error) = ancestors.take_error() {
+            self.report_emit_error(event_identifier, &error);
+        }
+        let mut global = self.global_snapshot();
+
+        // Removals and detached listeners per dispatched key,
+        // the most specific key being first.
+        let mut levels: Vec<(_, _, SharedRemovals<T, R>)> = Vec::with_capacity(lineage.len());
+        let mut global_removals = SharedRemovals::new();
+        let mut result = ExecuteRequestsResult::Finished;
+
+        let handled = lineage.iter().any(|(_, listener_collection)| {
+            listener_collection
+                .as_ref()
+                .is_some_and(|listener_collection| listener_collection.has_listeners())
+        });
+
+        if !handled {
+            self.report_unhandled(event_identifier);
+        }
+
+        let envelope = self.stamper
+            .read()
+            .expect("Dispatcher's lock is poisoned.")
+            .stamp(event_identifier, chain.correlation_id);
+        chain.enter(envelope);
+
+        for (key, listener_collection) in lineage {
+            let mut removals = SharedRemovals::new();
+            let mut detached = SharedRemovals::new();
+
+            if let Some(mut listener_collection) = listener_collection {
+                // Ancestors must not call listeners detached by descendants.
+                for (_, _, previous) in &levels {
+                    if !previous.is_empty() {
+                        previous.apply(Arc::make_mut(&mut listener_collection));
+                    }
+                }
+
+                let (collection_result, dead) = self.dispatch_to_collection(
+                    &listener_collection,
+                    &key,
+                    event_identifier,
+                    chain,
+                    &mut removals,
+                    &mut detached,
+                );
+                result = collection_result;
+                self.report_dead_listeners(&key, dead, true);
+            }
+
+            levels.push((key, removals, detached));
+
+            if let ExecuteRequestsResult::Stopped = result {
+                break;
+            }
+        }
+
+        if let ExecuteRequestsResult::Finished = result {
+            if !global.is_empty() {
+                // Global listeners must not be called once detached.
+                for (_, _, detached) in &levels {
+                    if !detached.is_empty() {
+                        detached.apply(Arc::make_mut(&mut global));
+                    }
+                }
+
+                let (_, dead) = self.dispatch_to_collection(
+                    &global,
+                    event_identifier,
+                    event_identifier,
+                    chain,
+                    &mut global_removals,
+                    &mut levels[0].2,
+                );
+                self.report_dead_listeners(event_identifier, dead, true);
+            }
+        }
+
+        chain.leave();
+
+        let any_detached = levels.iter().any(|(_, _, detached)| !detached.is_empty());
+
+        if any_detached {
+            let snapshot = self.snapshot();
+
+            for (key, _, detached) in &levels {
+                detached.notify_detached(&snapshot, key);
+            }
+        }
+
+        // Replacements are notified outside of the updates, allowing them
+        // to register on this dispatcher.
+        let mut replacements = vec![];
+
+        if any_detached || levels.iter().any(|(_, removals, _)| !removals.is_empty()) {
+            self.update(|events| {
+                for (key, removals, _) in &levels {
+                    if removals.is_empty() {
+                        continue;
+                    }
+
+                    if let Some(listener_collection) = events.get_mut(&**key) {
+                        let added = removals.apply(Arc::make_mut(listener_collection));
+                        replacements.extend(added.into_iter().map(|added| (key.clone(), added)));
+                    }
+                }
+
+                for (_, _, detached) in &levels {
+                    if detached.is_empty() {
+                        continue;
+                    }
+
+                    for listener_collection in events.values_mut() {
+                        detached.apply(Arc::make_mut(listener_collection));
+                    }
+                }
+            });
+        }
+
+        if !global_removals.is_empty() || any_detached {
+            self.update_global(|global| {
+                let added = global_removals.apply(global);
+                replacements.extend(
+                    added
+                        .into_iter()
+                        .map(|added| (Cow::Borrowed(event_identifier), added)),
+                );
+
+                for (_, _, detached) in &levels {
+                    detached.apply(global);
+                }
+            });
+        }
+
+        for (key, replacement) in &replacements {
+            replacement.listener.on_subscribe(key);
+        }
+    }
+
+    fn report_emit_error(&self, event: &T, error: &DispatchError) {
+        if let Ok(handler) = self.emit_error_handler.read() {
+            if let Some(ref handler) = *handler {
+                handler(event, error);
+            }
+        }
+    }
+}
+
+impl<T, R> SharedEventDispatcher<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: 'static,
+{
+    /// Dispatches `event` to all listeners inside `listener_collection`
+    /// of `event_identifier`, collecting all listeners requesting to be
+    /// removed in `removals` and those requesting to stop listening to
+    /// all events in `detached`.
+    ///
+    /// Returns whether propagation has been stopped, alongside
+    /// the amount of listeners dropped without unsubscribing.
+    fn dispatch_to_collection(
+        &self,
+        listener_collection: &SharedFnsAndTraits<T, R>,
+        event_identifier: &T,
+        event: &T,
+        chain: &mut DispatchChain<T>,
+        removals: &mut SharedRemovals<T, R>,
+        detached: &mut SharedRemovals<T, R>,
+    ) -> (ExecuteRequestsResult, usize) {
+        let mut dispatcher = self;
+        let handler = &*self.request_handler;
+        let mut dead = 0;
+
+        if let ExecuteRequestsResult::Stopped = visit_sync_dispatcher_requests(
+            &listener_collection.traits,
+            |weak_listener| {
+                let listener = match weak_listener.upgrade() {
+                    Some(listener) => listener,
+                    None => return remove_dead(&mut dead),
+                };
+                let causer = Causer::referenced(weak_listener);
+
+                call_sync_listener(&mut dispatcher, chain, causer, |context| {
+                    listener
+                        .on_event(event, context)
+                        .and_then(|request| handler.handle(request))
+                })
+            },
+            |weak_listener, removal| {
+                if let Some(listener) = weak_listener.upgrade() {
+                    listener.on_unsubscribe(event_identifier, removal.reason());
+                }
+
+                let member = Member::Referenced(Weak::clone(weak_listener));
+                removals.record(detached, removal, ListenerId::new(), member)
+            },
+        ) {
+            return (ExecuteRequestsResult::Stopped, dead);
+        }
+
+        if let ExecuteRequestsResult::Stopped = visit_sync_dispatcher_requests(
+            &listener_collection.owned,
+            |owned| {
+                if owned.listener.is_dead() {
+                    return remove_dead(&mut dead);
+                }
+
+                let causer = Causer::Owned(owned.id);
+
+                call_sync_listener(&mut dispatcher, chain, causer, |context| {
+                    owned.listener.on_event(event, context)
+                })
+            },
+            |owned, removal| {
+                owned.listener.on_unsubscribe(event_identifier, removal.reason());
+                removals.record(detached, removal, owned.id, Member::Identified(owned.id))
+            },
+        ) {
+            return (ExecuteRequestsResult::Stopped, dead);
+        }
+
+        if let ExecuteRequestsResult::Stopped = visit_sync_dispatcher_requests(
+            &listener_collection.shared,
+            |weak_listener| {
+                let listener = match weak_listener.upgrade() {
+                    Some(listener) => listener,
+                    None => return remove_dead(&mut dead),
+                };
+                let causer = Causer::referenced(weak_listener);
+
+                call_sync_listener(&mut dispatcher, chain, causer, |context| {
+                    listener
+                        .on_event_with_context(event, context)
+                        .and_then(|request| handler.handle(request))
+                })
+            },
+            |weak_listener, removal| {
+                if let Some(listener) = weak_listener.upgrade() {
+                    listener.on_unsubscribe(event_identifier, removal.reason());
+                }
+
+                let member = Member::Shared(Weak::clone(weak_listener));
+                removals.record(detached, removal, ListenerId::new(), member)
+            },
+        ) {
+            return (ExecuteRequestsResult::Stopped, dead);
+        }
+
+        let result = visit_sync_dispatcher_requests(
+            &listener_collection.fns,
+            |callback| {
+                let causer = Causer::function(&callback.listener);
+
+                call_sync_listener(&mut dispatcher, chain, causer, |context| {
+                    (callback.listener)(event, context).and_then(|request| handler.handle(request))
+                })
+            },
+            |callback, removal| {
+                let member = Member::Identified(callback.id);
+                removals.record(detached, removal, ListenerId::new(), member)
+            },
+        );
+
+        (result, dead)
+    }
+}
+
+impl<T, R> Drop for SharedEventDispatcher<T, R>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    R: 'static,
+{
+    fn drop(&mut self) {
+        let mut notified = SharedFnsAndTraits::default();
+
+        let events = match self.events.get_mut() {
+            Ok(events) => Arc::clone(events),
+            Err(_) => return,
+        };
+
+        for (event_identifier, listener_collection) in events.iter() {
+            let dead = listener_collection.notify_dispatcher_dropped(&mut notified);
+            self.report_dead_listeners(event_identifier, dead, false);
+        }
+
+        // Dead listeners are not reported, see `EventDispatcher::on_dead_listener`.
+        self.global_snapshot().notify_dispatcher_dropped(&mut notified);
+    }
+}
@@ -0,0 +1,550 @@
+//! An event-dispatcher keyed by slash-separated topics, supporting wildcards.
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use failure::Fail;
+
+use super::{report_dead_listeners, translating_handler, BoxedContextFn, BoxedFn, DeadListener,
+            DeadListenerHandler, DispatchContext, DispatchError, EmitErrorHandler,
+            ExecuteRequestsResult, FnsAndTraits, ListenerId, ListenerLock, Listener,
+            ListenerRequest, LockedListener, Mutex, OwnedListener, OwnedLockedListener,
+            RequestHandler, SharedListener, SyncDispatcherRequest, SyncRequestHandler,
+            TranslatingListener};
+use context::{dispatch_chain, DispatchChain, NestedDispatch, DEFAULT_MAX_DISPATCH_DEPTH};
+use envelope::{CorrelationId, Stamper};
+
+/// Separates the levels of a topic.
+const LEVEL_SEPARATOR: char = '/';
+/// Levels matching exactly one level of a topic, `*` being an alias of `+`.
+const SINGLE_LEVEL_WILDCARDS: [&str; 2] = ["+", "*"];
+/// Level matching any amount of remaining levels, including none.
+const MULTI_LEVEL_WILDCARD: &str = "#";
+
+/// Errors for filters rejected by a [`TopicDispatcher`].
+///
+/// [`TopicDispatcher`]: struct.TopicDispatcher.html
+#[derive(Debug, PartialEq)]
+pub enum FilterError {
+    /// The multi-level wildcard `#` is followed by further levels.
+    MultiLevelWildcardNotLast(String),
+    /// A wildcard shares its level with other characters, e.g. `sensor+`.
+    WildcardWithinLevel(String),
+}
+
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FilterError::MultiLevelWildcardNotLast(ref filter) => write!(
+                f,
+                "The multi-level wildcard must be the last level of filter {:?}.",
+                filter
+            ),
+            FilterError::WildcardWithinLevel(ref filter) => write!(
+                f,
+                "Wildcards must occupy an entire level of filter {:?}.",
+                filter
+            ),
+        }
+    }
+}
+
+impl Fail for FilterError {}
+
+/// Returns an error if `filter` places a wildcard where it cannot match.
+fn validate_filter(filter: &str) -> Result<(), FilterError> {
+    let mut levels = filter.split(LEVEL_SEPARATOR).peekable();
+
+    while let Some(level) = levels.next() {
+        let is_wildcard =
+            SINGLE_LEVEL_WILDCARDS.contains(&level) || level == MULTI_LEVEL_WILDCARD;
+
+        if !is_wildcard && level.contains(['+', '*', '#']) {
+            return Err(FilterError::WildcardWithinLevel(filter.to_string()));
+        }
+
+        if level == MULTI_LEVEL_WILDCARD && levels.peek().is_some() {
+            return Err(FilterError::MultiLevelWildcardNotLast(filter.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// A level of the topic-trie, holding the listeners of the filter
+/// ending at this level.
+struct TopicNode<R> {
+    filter: String,
+    listeners: Arc<FnsAndTraits<String, (), R>>,
+    children: HashMap<String, TopicNode<R>>,
+}
+
+impl<R> TopicNode<R> {
+    fn new(filter: &str) -> Self {
+        TopicNode {
+            filter: filter.to_string(),
+            listeners: Arc::default(),
+            children: HashMap::new(),
+        }
+    }
+
+    /// Returns the node of `filter`, inserting all missing levels.
+    fn entry(&mut self, filter: &str) -> &mut TopicNode<R> {
+        let mut node = self;
+        let mut end = 0;
+
+        for level in filter.split(LEVEL_SEPARATOR) {
+            end += level.len();
+            node = node.children
+                .entry(level.to_string())
+                .or_insert_with(|| TopicNode::new(&filter[..end]));
+            end += LEVEL_SEPARATOR.len_utf8();
+        }
+
+        node
+    }
+
+    /// Returns the node of `filter`, if it exists.
+    fn get_mut(&mut self, filter: &str) -> Option<&mut TopicNode<R>> {
+        filter
+            .split(LEVEL_SEPARATOR)
+            .try_fold(self, |node, level| node.children.get_mut(level))
+    }
+
+    /// Appends the filters of all nodes matching the remaining `levels`
+    /// of a topic to `matched`, exact levels before wildcards.
+    fn collect_matching(&self, levels: &[&str], matched: &mut Vec<String>) {
+        match levels.split_first() {
+            Some((level, remaining)) => {
+                if let Some(child) = self.children.get(*level) {
+                    child.collect_matching(remaining, matched);
+                }
+
+                let wildcards = SINGLE_LEVEL_WILDCARDS
+                    .iter()
+                    .filter(|wildcard| *wildcard != level);
+
+                for wildcard in wildcards {
+                    if let Some(child) = self.children.get(*wildcard) {
+                        child.collect_matching(remaining, matched);
+                    }
+                }
+            }
+            None => self.push_filter(matched),
+        }
+
+        if let Some(child) = self.children.get(MULTI_LEVEL_WILDCARD) {
+            child.push_filter(matched);
+        }
+    }
+
+    /// Appends the filter of `self` to `matched`, unless `self` holds
+    /// no listeners or the filter has been matched already.
+    fn push_filter(&self, matched: &mut Vec<String>) {
+        if !self.listeners.is_empty() && !matched.contains(&self.filter) {
+            matched.push(self.filter.clone());
+        }
+    }
+
+    /// Removes all nodes along the remaining `levels` of a filter
+    /// neither holding listeners nor children.
+    fn prune(&mut self, levels: &[&str]) {
+        if let Some((level, remaining)) = levels.split_first() {
+            let is_empty = match self.children.get_mut(*level) {
+                Some(child) => {
+                    child.prune(remaining);
+
+                    child.listeners.is_empty() && child.children.is_empty()
+                }
+                None => false,
+            };
+
+            if is_empty {
+                self.children.remove(*level);
+            }
+        }
+    }
+
+    /// Removes the owned listener identified by `id` from the first node
+    /// holding it, returning the node's filter.
+    fn remove_owned(&mut self, id: ListenerId) -> Option<String> {
+        if Arc::make_mut(&mut self.listeners).remove_owned(&self.filter, id) {
+            return Some(self.filter.clone());
+        }
+
+        self.children
+            .values_mut()
+            .find_map(|child| child.remove_owned(id))
+    }
+
+    /// Calls `function` with `self` and all nodes below.
+    fn for_each_mut<F: FnMut(&mut TopicNode<R>)>(&mut self, function: &mut F) {
+        function(self);
+
+        for child in self.children.values_mut() {
+            child.for_each_mut(function);
+        }
+    }
+}
+
+/// Owns a trie of topic-filters, [`Weak`]-references to their
+/// listeners and [`Fn`]s.
+///
+/// Events are topics, strings consisting of levels separated by `/`,
+/// e.g. `sensor/42/temp`. Listeners subscribe to filters, which are
+/// topics optionally containing wildcards:
+///
+/// - `+` or `*` matches exactly one level, e.g. `sensor/+/temp`.
+/// - `#` as last level matches any amount of remaining levels,
+///   including none, e.g. `sensor/#` matches `sensor` and `sensor/42/temp`.
+///
+/// Wildcards occupy an entire level, filters placing them elsewhere
+/// are rejected with a [`FilterError`].
+///
+/// A dispatched topic is received by the listeners of every matching
+/// filter, found by walking the trie along the topic's levels.
+/// Filters matching a level exactly are dispatched to before filters
+/// matching it via a wildcard.
+///
+/// # Examples
+///
+/// ```rust
+/// extern crate hey_listen;
+///
+/// use hey_listen::TopicDispatcher;
+///
+/// fn main() {
+///     let mut dispatcher: TopicDispatcher = TopicDispatcher::default();
+///
+///     dispatcher.add_fn("sensor/+/temp", Box::new(|topic: &String| {
+///         println!("Received a temperature via {}.", topic);
+///
+///         None
+///     })).expect("The filter is valid.");
+///
+///     dispatcher.dispatch_event("sensor/42/temp");
+/// }
+/// ```
+///
+/// [`FilterError`]: enum.FilterError.html
+/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+pub struct TopicDispatcher<R = SyncDispatcherRequest> {
+    filters: TopicNode<R>,
+    request_handler: Arc<SyncRequestHandler<String, (), R>>,
+    max_dispatch_depth: usize,
+    /// Listeners stopping to listen to all filters during the current dispatch.
+    detached: FnsAndTraits<String, (), R>,
+    dead_listener_handler: Option<DeadListenerHandler<String>>,
+    emit_error_handler: Option<EmitErrorHandler<String>>,
+    stamper: Stamper,
+}
+
+impl<R> Default for TopicDispatcher<R>
+where
+    R: Into<ListenerRequest<String>> + 'static,
+{
+    fn default() -> TopicDispatcher<R> {
+        TopicDispatcher::new_with_request_handler(|request: R| Some(request))
+    }
+}
+
+impl<R: 'static> TopicDispatcher<R> {
+    /// Creates a dispatcher whose listeners return requests of type `R`,
+    /// translated by `handler` into requests `O`, e.g.
+    /// [`SyncDispatcherRequest`] or [`ListenerRequest`].
+    ///
+    /// See [`RequestHandler`] for an example.
+    ///
+    /// [`SyncDispatcherRequest`]: enum.SyncDispatcherRequest.html
+    /// [`ListenerRequest`]: enum.ListenerRequest.html
+    /// [`RequestHandler`]: trait.RequestHandler.html
+    pub fn new_with_request_handler<H, O>(handler: H) -> Self
+    where
+        H: RequestHandler<R, O> + 'static,
+        O: Into<ListenerRequest<String>> + 'static,
+    {
+        TopicDispatcher {
+            filters: TopicNode::new(""),
+            request_handler: translating_handler(handler),
+            max_dispatch_depth: DEFAULT_MAX_DISPATCH_DEPTH,
+            detached: FnsAndTraits::default(),
+            dead_listener_handler: None,
+            emit_error_handler: None,
+            stamper: Stamper::new(),
+        }
+    }
+
+    /// Adds a [`Listener`] to listen for all topics matching `filter`.
+    /// Returns a [`FilterError`] if `filter` is invalid.
+    ///
+    /// See [`EventDispatcher::add_listener`] for an example.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`FilterError`]: enum.FilterError.html
+    /// [`EventDispatcher::add_listener`]: struct.EventDispatcher.html#method.add_listener
+    pub fn add_listener<L>(&mut self, filter: &str, listener: &Arc<L>) -> Result<(), FilterError>
+    where
+        L: ListenerLock,
+        L::Listener: Listener<String, R>,
+    {
+        validate_filter(filter)?;
+
+        let listener = Arc::clone(listener) as Arc<LockedListener<String, R>>;
+        let node = self.filters.entry(filter);
+        listener.on_subscribe(&node.filter);
+        Arc::make_mut(&mut node.listeners)
+            .traits
+            .push(Arc::downgrade(&listener));
+
+        Ok(())
+    }
+
+    /// Hands `listener` over to the dispatcher to listen for all topics
+    /// matching `filter`, returning a [`ListenerId`] to remove it via
+    /// [`remove_listener`] or a [`FilterError`] if `filter` is invalid.
+    ///
+    /// [`ListenerId`]: struct.ListenerId.html
+    /// [`remove_listener`]: #method.remove_listener
+    /// [`FilterError`]: enum.FilterError.html
+    pub fn add_owned_listener<D: Listener<String, R> + Send + Sync + 'static>(
+        &mut self,
+        filter: &str,
+        listener: D,
+    ) -> Result<ListenerId, FilterError> {
+        validate_filter(filter)?;
+
+        let id = ListenerId::new();
+        let mut listener = TranslatingListener {
+            listener,
+            handler: Arc::clone(&self.request_handler),
+        };
+        let node = self.filters.entry(filter);
+        listener.on_subscribe(&node.filter);
+        Arc::make_mut(&mut node.listeners).owned.push(OwnedListener {
+            id,
+            listener: Arc::new(Mutex::new(listener)) as Arc<OwnedLockedListener<String, ()>>,
+        });
+
+        Ok(id)
+    }
+
+    /// Adds a [`SharedListener`] to listen for all topics matching `filter`.
+    /// Returns a [`FilterError`] if `filter` is invalid.
+    ///
+    /// [`SharedListener`]: trait.SharedListener.html
+    /// [`FilterError`]: enum.FilterError.html
+    pub fn add_shared_listener<D: SharedListener<String, R> + Send + Sync + 'static>(
+        &mut self,
+        filter: &str,
+        listener: &Arc<D>,
+    ) -> Result<(), FilterError> {
+        validate_filter(filter)?;
+
+        let weak_listener = Arc::downgrade(
+            &(Arc::clone(listener) as Arc<SharedListener<String, R> + Send + Sync + 'static>),
+        );
+
+        let node = self.filters.entry(filter);
+        listener.on_subscribe(&node.filter);
+        Arc::make_mut(&mut node.listeners).shared.push(weak_listener);
+
+        Ok(())
+    }
+
+    /// Removes the owned [`Listener`] identified by `id` from
+    /// the dispatcher, dropping it.
+    /// Returns `false` if no such [`Listener`] has been found.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    pub fn remove_listener(&mut self, id: ListenerId) -> bool {
+        match self.filters.remove_owned(id) {
+            Some(filter) => {
+                self.prune(&filter);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Adds a [`Fn`] to listen for all topics matching `filter`.
+    /// Returns a [`FilterError`] if `filter` is invalid.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`FilterError`]: enum.FilterError.html
+    pub fn add_fn(
+        &mut self,
+        filter: &str,
+        function: BoxedFn<String, R>,
+    ) -> Result<(), FilterError> {
+        self.add_context_fn(
+            filter,
+            Box::new(move |topic: &String, _: &mut DispatchContext<String>| function(topic)),
+        )
+    }
+
+    /// Adds a [`Fn`] to listen for all topics matching `filter`,
+    /// receiving a [`DispatchContext`] to dispatch follow-up topics.
+    /// Returns a [`FilterError`] if `filter` is invalid.
+    ///
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    /// [`FilterError`]: enum.FilterError.html
+    pub fn add_context_fn(
+        &mut self,
+        filter: &str,
+        function: BoxedContextFn<String, R>,
+    ) -> Result<(), FilterError> {
+        validate_filter(filter)?;
+
+        Arc::make_mut(&mut self.filters.entry(filter).listeners)
+            .fns
+            .push(Arc::from(function));
+
+        Ok(())
+    }
+
+    /// All [`Listener`]s and [`Fn`]s subscribed to a filter matching `topic`
+    /// will receive `topic`, the listeners of each filter in the same order
+    /// as [`EventDispatcher::dispatch_event`] does.
+    ///
+    /// Propagation stopped by a listener also stops propagation to the
+    /// listeners of all remaining filters.
+    ///
+    /// [`Listener`]: trait.Listener.html
+    /// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+    /// [`EventDispatcher::dispatch_event`]: struct.EventDispatcher.html#method.dispatch_event
+    pub fn dispatch_event(&mut self, topic: &str) {
+        dispatch_chain(
+            self,
+            &topic.to_string(),
+            self.max_dispatch_depth,
+            CorrelationId::new(),
+            None,
+        );
+        self.detached = FnsAndTraits::default();
+    }
+
+    /// Sets the maximum depth of follow-up topics dispatched via
+    /// [`DispatchContext`], defaults to `32`.
+    ///
+    /// [`DispatchContext`]: struct.DispatchContext.html
+    pub fn max_dispatch_depth(&mut self, depth: usize) {
+        self.max_dispatch_depth = depth;
+    }
+
+    /// Sets `handler` to be called with a [`DeadListener`] for each
+    /// listener found dropped by its owner without unsubscribing,
+    /// reporting the filter it has been subscribed to.
+    ///
+    /// See [`EventDispatcher::on_dead_listener`] for an example.
+    ///
+    /// [`DeadListener`]: struct.DeadListener.html
+    /// [`EventDispatcher::on_dead_listener`]: struct.EventDispatcher.html#method.on_dead_listener
+    pub fn on_dead_listener<F>(&mut self, handler: F)
+    where
+        F: Fn(&DeadListener<String>) + Send + Sync + 'static,
+    {
+        self.dead_listener_handler = Some(Box::new(handler));
+    }
+
+    /// Sets `handler` to be called with each event emitted via
+    /// [`ListenerRequest::Emit`] that is rejected with a [`DispatchError`].
+    ///
+    /// See [`EventDispatcher::on_emit_error`] for an example.
+    ///
+    /// [`ListenerRequest::Emit`]: enum.ListenerRequest.html#variant.Emit
+    /// [`DispatchError`]: enum.DispatchError.html
+    /// [`EventDispatcher::on_emit_error`]: struct.EventDispatcher.html#method.on_emit_error
+    pub fn on_emit_error<F>(&mut self, handler: F)
+    where
+        F: Fn(&String, &DispatchError) + Send + Sync + 'static,
+    {
+        self.emit_error_handler = Some(Box::new(handler));
+    }
+
+    /// Removes the nodes of `filter` left without listeners.
+    fn prune(&mut self, filter: &str) {
+        let levels: Vec<_> = filter.split(LEVEL_SEPARATOR).collect();
+        self.filters.prune(&levels);
+    }
+}
+
+impl<R: 'static> NestedDispatch<String> for TopicDispatcher<R> {
+    fn dispatch_nested(&mut self, topic: &String, chain: &mut DispatchChain<String>) {
+        let handler = Arc::clone(&self.request_handler);
+        let levels: Vec<_> = topic.split(LEVEL_SEPARATOR).collect();
+        let mut filters = vec![];
+        self.filters.collect_matching(&levels, &mut filters);
+
+        chain.enter(self.stamper.stamp(topic, chain.correlation_id));
+
+        // Listeners are called via collections shared with nested dispatches,
+        // changed copy-on-write, hence follow-up topics matching the same
+        // filters reach them as well.
+        for filter in filters {
+            let mut listener_collection = match self.filters.get_mut(&filter) {
+                Some(node) => Arc::clone(&node.listeners),
+                None => continue,
+            };
+
+            if !self.detached.is_empty() {
+                listener_collection = Arc::new(listener_collection.without(&self.detached));
+            }
+
+            let (result, mut relocations) =
+                listener_collection.dispatch(self, &*handler, &filter, topic, chain);
+            drop(listener_collection);
+            report_dead_listeners(&self.dead_listener_handler, &filter, relocations.dead, true);
+            let detached = ::std::mem::take(&mut relocations.detached);
+            self.detached.append(detached);
+
+            // Without priority-levels, moved listeners stay in their collection.
+            let is_empty = match self.filters.get_mut(&filter) {
+                Some(node) => {
+                    relocations.apply(&filter, &mut node.listeners);
+
+                    node.listeners.is_empty()
+                }
+                None => false,
+            };
+
+            if is_empty {
+                self.prune(&filter);
+            }
+
+            if let ExecuteRequestsResult::Stopped = result {
+                break;
+            }
+        }
+
+        chain.leave();
+
+        if !self.detached.is_empty() {
+            let detached = &self.detached;
+
+            self.filters.for_each_mut(&mut |node| {
+                FnsAndTraits::remove_detached(&mut node.listeners, &node.filter, detached);
+            });
+        }
+    }
+
+    fn report_emit_error(&self, topic: &String, error: &DispatchError) {
+        if let Some(ref handler) = self.emit_error_handler {
+            handler(topic, error);
+        }
+    }
+}
+
+impl<R> Drop for TopicDispatcher<R> {
+    fn drop(&mut self) {
+        let mut notified = FnsAndTraits::default();
+        let dead_listener_handler = &self.dead_listener_handler;
+
+        self.filters.for_each_mut(&mut |node| {
+            let dead = node.listeners.notify_dispatcher_dropped(&mut notified);
+            report_dead_listeners(dead_listener_handler, &node.filter, dead, false);
+        });
+    }
+}
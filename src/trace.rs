@@ -0,0 +1,239 @@
+//! Tracing which listeners received an event and which follow-up events they caused.
+use std::fmt::Debug;
+use std::sync::{Arc, Weak};
+
+use super::ListenerId;
+
+/// The traced event and the listener a follow-up event has been caused by.
+type TraceCause = (usize, TracedListener);
+
+/// Identifies a listener that received a traced event.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TracedListener {
+    /// A listener the dispatcher holds a [`Weak`]-reference to,
+    /// identified by the address of the [`Arc`] it has been added with,
+    /// compare it via [`is`].
+    ///
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    /// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+    /// [`is`]: #method.is
+    Referenced(usize),
+    /// A listener owned by the dispatcher, including filtered listeners.
+    Owned(ListenerId),
+    /// A closure.
+    Fn,
+}
+
+impl TracedListener {
+    pub(crate) fn referenced<L: ?Sized>(listener: &Weak<L>) -> Self {
+        TracedListener::Referenced(Weak::as_ptr(listener) as *const u8 as usize)
+    }
+
+    /// Returns whether `self` refers to `listener`,
+    /// the [`Arc`] it has been added with.
+    ///
+    /// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+    pub fn is<L: ?Sized>(&self, listener: &Arc<L>) -> bool {
+        *self == TracedListener::Referenced(Arc::as_ptr(listener) as *const u8 as usize)
+    }
+}
+
+/// An event recorded by a [`Trace`].
+///
+/// [`Trace`]: struct.Trace.html
+#[derive(Clone, Debug)]
+pub struct TracedEvent<T> {
+    id: usize,
+    event: T,
+    caused_by: Option<TraceCause>,
+    listeners: Vec<TracedListener>,
+}
+
+impl<T> TracedEvent<T> {
+    /// Returns the ID of this dispatch of the event,
+    /// unique among all events traced by its dispatcher.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the dispatched event.
+    pub fn event(&self) -> &T {
+        &self.event
+    }
+
+    /// Returns the ID of the traced event and the listener this event
+    /// has been dispatched by as a follow-up event, `None` for events
+    /// dispatched by the dispatcher's owner.
+    pub fn caused_by(&self) -> Option<(usize, TracedListener)> {
+        self.caused_by
+    }
+
+    /// Returns all listeners that received the event,
+    /// in the order they received it.
+    pub fn listeners(&self) -> &[TracedListener] {
+        &self.listeners
+    }
+}
+
+/// The causal tree of traced events: which listeners received an event
+/// and which follow-up events each of them dispatched.
+///
+/// Tracing is enabled via a dispatcher's `enable_tracing`-method,
+/// recorded events are taken via `take_trace`.
+///
+/// # Examples
+///
+/// ```rust
+/// extern crate hey_listen;
+///
+/// use hey_listen::{DispatchContext, EventDispatcher};
+///
+/// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Pressed,
+///     Clicked,
+/// }
+///
+/// fn main() {
+///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+///     dispatcher.enable_tracing();
+///
+///     dispatcher.add_context_fn(Event::Pressed, Box::new(|_: &Event, context: &mut DispatchContext<Event>| {
+///         context.dispatch(Event::Clicked).expect("Clicked causes no cycle.");
+///
+///         None
+///     }));
+///
+///     dispatcher.dispatch_event(&Event::Pressed);
+///
+///     let trace = dispatcher.take_trace();
+///     let pressed = trace.roots().next().unwrap();
+///     let clicked = trace.follow_ups(pressed.id()).next().unwrap();
+///
+///     assert_eq!(clicked.event(), &Event::Clicked);
+///     println!("{}", trace.to_dot());
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Trace<T> {
+    events: Vec<TracedEvent<T>>,
+}
+
+impl<T> Default for Trace<T> {
+    fn default() -> Self {
+        Trace { events: vec![] }
+    }
+}
+
+impl<T> Trace<T> {
+    /// Returns all traced events in the order they have been dispatched.
+    pub fn events(&self) -> &[TracedEvent<T>] {
+        &self.events
+    }
+
+    /// Returns all traced events dispatched by the dispatcher's owner.
+    pub fn roots(&self) -> impl Iterator<Item = &TracedEvent<T>> {
+        self.events
+            .iter()
+            .filter(|traced| traced.caused_by.is_none())
+    }
+
+    /// Returns all follow-up events caused by the traced event `id`.
+    pub fn follow_ups(&self, id: usize) -> impl Iterator<Item = &TracedEvent<T>> {
+        self.events
+            .iter()
+            .filter(move |traced| traced.caused_by.map(|(cause, _)| cause) == Some(id))
+    }
+
+    /// Exports the trace as a [Graphviz] `digraph`, one node per traced
+    /// event and one edge per follow-up event, labelled with the
+    /// listener that dispatched it.
+    ///
+    /// [Graphviz]: https://graphviz.org/doc/info/lang.html
+    pub fn to_dot(&self) -> String
+    where
+        T: Debug,
+    {
+        let mut dot = String::from("digraph trace {\n");
+
+        for traced in &self.events {
+            let label = format!("{:?}", traced.event).replace('"', "\\\"");
+            dot.push_str(&format!("    {} [label=\"{}\"];\n", traced.id, label));
+        }
+
+        for traced in &self.events {
+            if let Some((cause, listener)) = traced.caused_by {
+                dot.push_str(&format!(
+                    "    {} -> {} [label=\"{:?}\"];\n",
+                    cause, traced.id, listener
+                ));
+            }
+        }
+
+        dot.push('}');
+
+        dot
+    }
+}
+
+/// Records the events of a dispatcher while tracing is enabled.
+pub(crate) struct Tracer<T> {
+    next_id: usize,
+    events: Vec<TracedEvent<T>>,
+    /// IDs of the events currently being dispatched,
+    /// the top-level event being first.
+    dispatching: Vec<usize>,
+}
+
+impl<T: Clone> Tracer<T> {
+    pub(crate) fn new() -> Self {
+        Tracer {
+            next_id: 0,
+            events: vec![],
+            dispatching: vec![],
+        }
+    }
+
+    /// Records the dispatch of `event`, caused by `caused_by`.
+    pub(crate) fn enter(&mut self, event: &T, caused_by: Option<TraceCause>) {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.events.push(TracedEvent {
+            id,
+            event: event.clone(),
+            caused_by,
+            listeners: vec![],
+        });
+        self.dispatching.push(id);
+    }
+
+    /// Records the end of the current event's dispatch.
+    pub(crate) fn leave(&mut self) {
+        self.dispatching.pop();
+    }
+
+    /// Returns the ID of the event currently being dispatched.
+    pub(crate) fn current(&self) -> Option<usize> {
+        self.dispatching.last().cloned()
+    }
+
+    /// Records `listener` receiving the current event and returns the
+    /// cause of follow-up events dispatched by it.
+    pub(crate) fn record(&mut self, listener: TracedListener) -> Option<TraceCause> {
+        let id = self.current()?;
+        // IDs of recorded events are consecutive.
+        let index = id - (self.next_id - self.events.len());
+        self.events[index].listeners.push(listener);
+
+        Some((id, listener))
+    }
+
+    /// Takes all recorded events, IDs of later events continue
+    /// after the taken events.
+    pub(crate) fn take(&mut self) -> Trace<T> {
+        Trace {
+            events: ::std::mem::take(&mut self.events),
+        }
+    }
+}
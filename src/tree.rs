@@ -0,0 +1,254 @@
+//! Dispatchers arranged in a tree, propagating events from the root to
+//! their target and back.
+use std::collections::BTreeMap;
+use std::hash::Hash;
+
+use super::{EventDispatcher, ExecuteRequestsResult};
+use envelope::CorrelationId;
+
+/// Identifies a node of a [`DispatcherTree`].
+///
+/// IDs are never reused, even once their node has been removed.
+///
+/// [`DispatcherTree`]: struct.DispatcherTree.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+/// A node of the tree, holding the dispatchers of both phases.
+struct TreeNode<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    parent: Option<NodeId>,
+    capture: EventDispatcher<T>,
+    bubble: EventDispatcher<T>,
+}
+
+impl<T> TreeNode<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn new(parent: Option<NodeId>) -> Self {
+        TreeNode {
+            parent,
+            capture: EventDispatcher::default(),
+            bubble: EventDispatcher::default(),
+        }
+    }
+}
+
+/// A tree of [`EventDispatcher`]s, e.g. one node per widget, propagating
+/// events like the DOM does: An event dispatched at a target node is
+/// dispatched by the capture-dispatcher of each node from the root down to
+/// the target, followed by the bubble-dispatcher of each node from the
+/// target up to the root.
+///
+/// [`StopPropagation`] stops the traversal as well,
+/// skipping all remaining nodes and phases.
+///
+/// # Examples
+///
+/// ```rust
+/// extern crate hey_listen;
+///
+/// use hey_listen::{DispatcherTree, SyncDispatcherRequest};
+///
+/// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Click,
+/// }
+///
+/// fn main() {
+///     let mut tree: DispatcherTree<Event> = DispatcherTree::default();
+///     let window = tree.add_root();
+///     let button = tree.add_child(window).expect("Window exists.");
+///
+///     tree.capture(window).unwrap().add_fn(Event::Click, Box::new(|_: &Event| {
+///         println!("Window captures the click first.");
+///
+///         None
+///     }));
+///
+///     tree.bubble(button).unwrap().add_fn(Event::Click, Box::new(|_: &Event| {
+///         println!("Button handles the click.");
+///
+///         Some(SyncDispatcherRequest::StopPropagation)
+///     }));
+///
+///     tree.bubble(window).unwrap().add_fn(Event::Click, Box::new(|_: &Event| {
+///         panic!("The button stopped the click from bubbling up.");
+///     }));
+///
+///     tree.dispatch_event(button, &Event::Click);
+/// }
+/// ```
+///
+/// [`EventDispatcher`]: struct.EventDispatcher.html
+/// [`StopPropagation`]: enum.SyncDispatcherRequest.html#variant.StopPropagation
+pub struct DispatcherTree<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    next_id: usize,
+    /// Ordered by ID, hence parents precede their children.
+    nodes: BTreeMap<NodeId, TreeNode<T>>,
+}
+
+impl<T> Default for DispatcherTree<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        DispatcherTree {
+            next_id: 0,
+            nodes: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T> DispatcherTree<T>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Adds a node without a parent, the root of its own tree.
+    pub fn add_root(&mut self) -> NodeId {
+        self.insert(None)
+    }
+
+    /// Adds a node below `parent`.
+    ///
+    /// Returns `None` if `parent` does not exist.
+    pub fn add_child(&mut self, parent: NodeId) -> Option<NodeId> {
+        if self.nodes.contains_key(&parent) {
+            Some(self.insert(Some(parent)))
+        } else {
+            None
+        }
+    }
+
+    fn insert(&mut self, parent: Option<NodeId>) -> NodeId {
+        let id = NodeId(self.next_id);
+        self.next_id += 1;
+        self.nodes.insert(id, TreeNode::new(parent));
+
+        id
+    }
+
+    /// Removes `node` and all nodes below, dropping their dispatchers.
+    ///
+    /// Returns whether `node` existed.
+    pub fn remove_node(&mut self, node: NodeId) -> bool {
+        if !self.nodes.contains_key(&node) {
+            return false;
+        }
+
+        let mut removed = vec![node];
+
+        for (id, tree_node) in self.nodes.range(node..) {
+            if let Some(parent) = tree_node.parent {
+                if removed.contains(&parent) {
+                    removed.push(*id);
+                }
+            }
+        }
+
+        for id in removed {
+            self.nodes.remove(&id);
+        }
+
+        true
+    }
+
+    /// Returns the parent of `node`,
+    /// `None` for roots and nodes that do not exist.
+    pub fn parent(&self, node: NodeId) -> Option<NodeId> {
+        self.nodes.get(&node).and_then(|tree_node| tree_node.parent)
+    }
+
+    /// Returns whether `node` exists.
+    pub fn contains(&self, node: NodeId) -> bool {
+        self.nodes.contains_key(&node)
+    }
+
+    /// Returns the dispatcher of `node` for the capture-phase, receiving
+    /// events dispatched at `node` or below on their way down.
+    pub fn capture(&mut self, node: NodeId) -> Option<&mut EventDispatcher<T>> {
+        self.nodes
+            .get_mut(&node)
+            .map(|tree_node| &mut tree_node.capture)
+    }
+
+    /// Returns the dispatcher of `node` for the bubble-phase, receiving
+    /// events dispatched at `node` or below on their way up.
+    pub fn bubble(&mut self, node: NodeId) -> Option<&mut EventDispatcher<T>> {
+        self.nodes
+            .get_mut(&node)
+            .map(|tree_node| &mut tree_node.bubble)
+    }
+
+    /// Dispatches `event_identifier` at `target`: First via the
+    /// capture-dispatchers from the root down to `target`, then via the
+    /// bubble-dispatchers from `target` up to the root.
+    ///
+    /// Each node's dispatcher dispatches like
+    /// [`EventDispatcher::dispatch_event`], all of them continuing one
+    /// correlation. Events dispatched at a node that does not exist
+    /// are ignored.
+    ///
+    /// [`EventDispatcher::dispatch_event`]: struct.EventDispatcher.html#method.dispatch_event
+    pub fn dispatch_event(&mut self, target: NodeId, event_identifier: &T) {
+        self.dispatch_correlated(target, event_identifier, CorrelationId::new());
+    }
+
+    /// Dispatches `event_identifier` at `target` like [`dispatch_event`],
+    /// continuing the correlation `correlation_id`.
+    ///
+    /// [`dispatch_event`]: #method.dispatch_event
+    pub fn dispatch_correlated(
+        &mut self,
+        target: NodeId,
+        event_identifier: &T,
+        correlation_id: CorrelationId,
+    ) {
+        let path = self.path(target);
+        let capturing = path.iter().map(|id| (id, true));
+        let bubbling = path.iter().rev().map(|id| (id, false));
+
+        for (id, capture) in capturing.chain(bubbling) {
+            if let Some(tree_node) = self.nodes.get_mut(id) {
+                let dispatcher = if capture {
+                    &mut tree_node.capture
+                } else {
+                    &mut tree_node.bubble
+                };
+
+                let result = dispatcher.dispatch_propagating(event_identifier, correlation_id);
+
+                if let ExecuteRequestsResult::Stopped = result {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Returns the nodes from the root down to `target`,
+    /// empty if `target` does not exist.
+    fn path(&self, target: NodeId) -> Vec<NodeId> {
+        let mut path = vec![];
+        let mut current = Some(target);
+
+        while let Some(id) = current {
+            match self.nodes.get(&id) {
+                Some(tree_node) => {
+                    path.push(id);
+                    current = tree_node.parent;
+                }
+                None => break,
+            }
+        }
+
+        path.reverse();
+
+        path
+    }
+}
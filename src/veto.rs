@@ -0,0 +1,157 @@
+//! Listeners asked before an event is dispatched, able to cancel it.
+use std::fmt;
+use std::sync::{Arc, Weak};
+
+use failure::Fail;
+
+use super::{BoxedVetoFn, ListenerLock};
+
+/// Asks a veto-listener whether an event may be dispatched,
+/// `None` if the listener has been dropped.
+type VetoCheck<T> = Box<Fn(&T) -> Option<Result<(), Veto>> + Send + Sync>;
+
+/// Cancels an event during the "before"-phase of a cancellable dispatch,
+/// telling the dispatching caller why.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Veto {
+    reason: String,
+}
+
+impl Veto {
+    /// Creates a veto, giving `reason` to the dispatching caller.
+    pub fn new<S: Into<String>>(reason: S) -> Self {
+        Veto {
+            reason: reason.into(),
+        }
+    }
+
+    /// Returns why the event has been vetoed.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for Veto {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "The event has been vetoed: {}", self.reason)
+    }
+}
+
+impl Fail for Veto {}
+
+/// Receives events before they are dispatched via a dispatcher's
+/// `dispatch_cancellable`-method, able to cancel their dispatch,
+/// e.g. to keep unsaved changes from being closed.
+///
+/// # Examples
+///
+/// ```rust
+/// extern crate hey_listen;
+///
+/// use std::sync::{Arc, Mutex};
+///
+/// use hey_listen::{EventDispatcher, Veto, VetoListener};
+///
+/// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// enum Event {
+///     Close,
+/// }
+///
+/// struct Editor {
+///     unsaved_changes: bool,
+/// }
+///
+/// impl VetoListener<Event> for Editor {
+///     fn before_event(&mut self, _: &Event) -> Result<(), Veto> {
+///         if self.unsaved_changes {
+///             Err(Veto::new("There are unsaved changes."))
+///         } else {
+///             Ok(())
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     let editor = Arc::new(Mutex::new(Editor { unsaved_changes: true }));
+///     let mut dispatcher: EventDispatcher<Event> = EventDispatcher::default();
+///
+///     dispatcher.add_veto_listener(Event::Close, &editor);
+///     dispatcher.add_fn(Event::Close, Box::new(|_: &Event| {
+///         println!("Closing.");
+///
+///         None
+///     }));
+///
+///     let veto = dispatcher.dispatch_cancellable(&Event::Close).unwrap_err();
+///     assert_eq!(veto.reason(), "There are unsaved changes.");
+///
+///     editor.lock().unwrap().unsaved_changes = false;
+///     assert!(dispatcher.dispatch_cancellable(&Event::Close).is_ok());
+/// }
+/// ```
+pub trait VetoListener<T> {
+    /// This function will be called before a listened event-type `T`
+    /// is dispatched, returning a [`Veto`] cancels the dispatch.
+    ///
+    /// [`Veto`]: struct.Veto.html
+    fn before_event(&mut self, event: &T) -> Result<(), Veto>;
+}
+
+/// The veto-listeners and closures of one event,
+/// asked in the order they have been added.
+pub(crate) struct VetoListeners<T> {
+    checks: Vec<VetoCheck<T>>,
+}
+
+impl<T> Default for VetoListeners<T> {
+    fn default() -> Self {
+        VetoListeners { checks: vec![] }
+    }
+}
+
+impl<T: 'static> VetoListeners<T> {
+    /// Adds a [`Weak`]-reference to `listener`,
+    /// removed once the listener has been dropped.
+    ///
+    /// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+    pub(crate) fn push_listener<L>(&mut self, listener: &Arc<L>)
+    where
+        L: ListenerLock,
+        L::Listener: VetoListener<T>,
+    {
+        let listener: Weak<L> = Arc::downgrade(listener);
+
+        self.checks.push(Box::new(move |event: &T| {
+            listener
+                .upgrade()
+                .map(|listener| listener.with_listener(|listener| listener.before_event(event)))
+        }));
+    }
+
+    pub(crate) fn push_fn(&mut self, function: BoxedVetoFn<T>) {
+        self.checks
+            .push(Box::new(move |event: &T| Some(function(event))));
+    }
+
+    /// Asks all veto-listeners whether `event` may be dispatched, removing
+    /// dropped listeners, until the first of them vetoes.
+    ///
+    /// Returns the first veto alongside the number of removed listeners.
+    pub(crate) fn check(&mut self, event: &T) -> (Result<(), Veto>, usize) {
+        let mut index = 0;
+        let mut dead = 0;
+
+        while index < self.checks.len() {
+            match (self.checks[index])(event) {
+                Some(Ok(())) => index += 1,
+                Some(Err(veto)) => return (Err(veto), dead),
+                None => {
+                    drop(self.checks.remove(index));
+                    dead += 1;
+                }
+            }
+        }
+
+        (Ok(()), dead)
+    }
+}
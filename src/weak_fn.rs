@@ -0,0 +1,86 @@
+//! Closures called with a weakly referenced target.
+use std::hash::Hash;
+use std::sync::{Arc, Weak};
+
+use super::{DispatchContext, ListenerLock, LockedListener, ParallelLockedListener,
+            UnsubscribeReason};
+
+/// Holds a [`Weak`]-reference to the lock of a target, calling `function`
+/// with the locked target alongside each event.
+///
+/// Once the target has been dropped, the dispatcher removes the closure.
+///
+/// [`Weak`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+pub(crate) struct WeakFn<L, F> {
+    target: Weak<L>,
+    function: F,
+}
+
+impl<L, F> WeakFn<L, F>
+where
+    L: ListenerLock,
+{
+    pub(crate) fn new(target: &Arc<L>, function: F) -> Self {
+        WeakFn {
+            target: Arc::downgrade(target),
+            function,
+        }
+    }
+
+    /// Calls `function` with the locked target and `event`,
+    /// unless the target has been dropped.
+    fn call<T, R>(&self, event: &T) -> Option<R>
+    where
+        F: Fn(&mut L::Listener, &T) -> Option<R>,
+    {
+        self.target
+            .upgrade()
+            .and_then(|target| target.with_listener(|target| (self.function)(target, event)))
+    }
+}
+
+impl<T, R, L, F> LockedListener<T, R> for WeakFn<L, F>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    L: ListenerLock,
+    F: Fn(&mut L::Listener, &T) -> Option<R> + Send + Sync,
+{
+    fn on_event(&self, event: &T, _context: &mut DispatchContext<T>) -> Option<R> {
+        self.call(event)
+    }
+
+    fn on_subscribe(&self, _event: &T) {}
+
+    fn on_unsubscribe(&self, _event: &T, _reason: UnsubscribeReason) {}
+
+    fn on_dispatcher_dropped(&self) {}
+
+    fn is_dead(&self) -> bool {
+        self.target.upgrade().is_none()
+    }
+}
+
+impl<T, R, L, F> ParallelLockedListener<T, R> for WeakFn<L, F>
+where
+    T: PartialEq + Eq + Hash + Clone + Send + Sync + 'static,
+    L: ListenerLock,
+    F: Fn(&mut L::Listener, &T) -> Option<R> + Send + Sync,
+{
+    fn on_event(&self, event: &T) -> Option<R> {
+        self.call(event)
+    }
+
+    fn accepts(&self, _event: &T) -> bool {
+        true
+    }
+
+    fn on_subscribe(&self, _event: &T) {}
+
+    fn on_unsubscribe(&self, _event: &T, _reason: UnsubscribeReason) {}
+
+    fn on_dispatcher_dropped(&self) {}
+
+    fn is_dead(&self) -> bool {
+        self.target.upgrade().is_none()
+    }
+}
@@ -0,0 +1,29 @@
+//! Fixtures shared by the integration tests.
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+/// Names of called closures alongside the events they received.
+pub type Record = Arc<Mutex<Vec<String>>>;
+/// A closure recording the events it receives.
+pub type RecordingFn<T, R> = Box<Fn(&T) -> Option<R> + Send + Sync>;
+
+/// Returns a closure pushing `name` and each received event to `record`,
+/// returning the request created by `reply`.
+pub fn recording<T, R, S>(
+    record: &Record,
+    name: S,
+    reply: fn() -> Option<R>,
+) -> RecordingFn<T, R>
+where
+    T: Debug + 'static,
+    R: 'static,
+    S: Into<String>,
+{
+    let (record, name) = (Arc::clone(record), name.into());
+
+    Box::new(move |event: &T| {
+        record.lock().unwrap().push(format!("{} {:?}", name, event));
+
+        reply()
+    })
+}
@@ -0,0 +1,136 @@
+extern crate hey_listen;
+
+mod common;
+
+use common::recording;
+use hey_listen::{DispatcherTree, SyncDispatcherRequest};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Event {
+    Click,
+    Escape,
+}
+
+fn stop_propagation() -> Option<SyncDispatcherRequest> {
+    Some(SyncDispatcherRequest::StopPropagation)
+}
+
+#[test]
+fn events_are_captured_down_and_bubble_up() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut tree: DispatcherTree<Event> = DispatcherTree::default();
+    let window = tree.add_root();
+    let panel = tree.add_child(window).unwrap();
+    let button = tree.add_child(panel).unwrap();
+    let sibling = tree.add_child(panel).unwrap();
+
+    for &(node, name) in &[(window, "window"), (panel, "panel"), (button, "button")] {
+        let capture = recording(&record, format!("capture {}", name), || None);
+        let bubble = recording(&record, format!("bubble {}", name), || None);
+
+        tree.capture(node).unwrap().add_fn(Event::Click, capture);
+        tree.bubble(node).unwrap().add_fn(Event::Click, bubble);
+    }
+
+    tree.bubble(sibling)
+        .unwrap()
+        .add_fn(Event::Click, recording(&record, "bubble sibling", || None));
+
+    tree.dispatch_event(button, &Event::Click);
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [
+            "capture window Click",
+            "capture panel Click",
+            "capture button Click",
+            "bubble button Click",
+            "bubble panel Click",
+            "bubble window Click",
+        ]
+    );
+
+    record.try_lock().unwrap().clear();
+    tree.dispatch_event(panel, &Event::Escape);
+    tree.dispatch_event(panel, &Event::Click);
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [
+            "capture window Click",
+            "capture panel Click",
+            "bubble panel Click",
+            "bubble window Click",
+        ]
+    );
+}
+
+#[test]
+fn stop_propagation_stops_the_traversal() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut tree: DispatcherTree<Event> = DispatcherTree::default();
+    let window = tree.add_root();
+    let button = tree.add_child(window).unwrap();
+
+    tree.capture(window)
+        .unwrap()
+        .add_fn(Event::Escape, recording(&record, "capture window", stop_propagation));
+    tree.capture(window)
+        .unwrap()
+        .add_fn(Event::Escape, recording(&record, "capture window again", || None));
+    tree.bubble(button)
+        .unwrap()
+        .add_fn(Event::Escape, recording(&record, "bubble button", || None));
+
+    tree.bubble(button)
+        .unwrap()
+        .add_fn(Event::Click, recording(&record, "bubble button", stop_propagation));
+    tree.bubble(window)
+        .unwrap()
+        .add_fn(Event::Click, recording(&record, "bubble window", || None));
+
+    tree.dispatch_event(button, &Event::Escape);
+    tree.dispatch_event(button, &Event::Click);
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        ["capture window Escape", "bubble button Click"]
+    );
+}
+
+#[test]
+fn removing_a_node_removes_its_descendants() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut tree: DispatcherTree<Event> = DispatcherTree::default();
+    let window = tree.add_root();
+    let panel = tree.add_child(window).unwrap();
+    let button = tree.add_child(panel).unwrap();
+    let other = tree.add_root();
+
+    tree.bubble(window)
+        .unwrap()
+        .add_fn(Event::Click, recording(&record, "bubble window", || None));
+
+    assert_eq!(tree.parent(button), Some(panel));
+    assert_eq!(tree.parent(window), None);
+
+    assert!(tree.remove_node(panel));
+    assert!(!tree.remove_node(panel));
+
+    assert!(tree.contains(window));
+    assert!(tree.contains(other));
+    assert!(!tree.contains(panel));
+    assert!(!tree.contains(button));
+    assert!(tree.add_child(button).is_none());
+    assert!(tree.bubble(button).is_none());
+
+    tree.dispatch_event(button, &Event::Click);
+    assert!(record.try_lock().unwrap().is_empty());
+
+    let new_panel = tree.add_child(window).unwrap();
+    assert_ne!(new_panel, panel);
+
+    tree.dispatch_event(new_panel, &Event::Click);
+    assert_eq!(*record.try_lock().unwrap(), ["bubble window Click"]);
+}
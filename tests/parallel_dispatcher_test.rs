@@ -1,7 +1,13 @@
 extern crate hey_listen;
 
-use hey_listen::{ParallelDispatcherRequest, ParallelEventDispatcher, ParallelListener};
-use std::sync::{Arc, Mutex};
+mod common;
+
+use common::recording;
+use hey_listen::{EventHierarchy, ParallelDispatcherRequest, ParallelEventDispatcher,
+                 ParallelListener, ParallelSharedListener, TracedListener, UnsubscribeReason};
+use std::sync::{Arc, Mutex, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::{self, ThreadId};
 
 #[derive(Clone, Eq, Hash, PartialEq)]
 enum Event {
@@ -199,8 +205,644 @@ fn stop_listening_parallel_for_fns() {
     assert_eq!(counter_b.try_lock().unwrap().counter, 2);
 }
 
+#[test]
+fn owned_listeners_parallel() {
+    struct CountingEventListener {
+        dispatch_counter: Arc<Mutex<usize>>,
+    }
+
+    impl ParallelListener<Event> for CountingEventListener {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+            *self.dispatch_counter.lock().unwrap() += 1;
+
+            Some(ParallelDispatcherRequest::StopListening)
+        }
+    }
+
+    let counter = Arc::new(Mutex::new(0));
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+
+    let id = dispatcher.add_owned_listener(
+        Event::VariantA,
+        CountingEventListener {
+            dispatch_counter: Arc::clone(&counter),
+        },
+    );
+    dispatcher.add_owned_listener(
+        Event::VariantA,
+        CountingEventListener {
+            dispatch_counter: Arc::clone(&counter),
+        },
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(*counter.try_lock().unwrap(), 2);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(*counter.try_lock().unwrap(), 2);
+    assert!(!dispatcher.remove_listener(id));
+}
+
+#[test]
+fn shared_listener_receives_events_of_multiple_variants() {
+    struct SharedCountingListener {
+        dispatch_counter: AtomicUsize,
+    }
+
+    impl ParallelSharedListener<Event> for SharedCountingListener {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+            if self.dispatch_counter.fetch_add(1, Ordering::SeqCst) == 2 {
+                Some(ParallelDispatcherRequest::StopListening)
+            } else {
+                None
+            }
+        }
+    }
+
+    let listener = Arc::new(SharedCountingListener {
+        dispatch_counter: AtomicUsize::new(0),
+    });
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+    dispatcher.add_shared_listener(Event::VariantA, &listener);
+    dispatcher.add_shared_listener(Event::VariantB, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert_eq!(listener.dispatch_counter.load(Ordering::SeqCst), 2);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(listener.dispatch_counter.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn dispatch_parallel_to_listener_behind_rw_lock() {
+    #[derive(Default)]
+    struct CountingEventListener {
+        dispatch_counter: usize,
+    }
+
+    impl ParallelListener<Event> for CountingEventListener {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+            self.dispatch_counter += 1;
+
+            None
+        }
+    }
+
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+    let listener = Arc::new(RwLock::new(CountingEventListener::default()));
+
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.add_listener(Event::VariantB, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert_eq!(listener.read().unwrap().dispatch_counter, 2);
+}
+
 #[test]
 fn is_send_and_sync() {
     fn assert_send<T: Send + Sync>(_: &T) {};
     assert_send(&ParallelEventDispatcher::<Event>::default());
 }
+
+#[test]
+fn custom_requests_are_translated_by_request_handler() {
+    enum Request {
+        Done,
+        Again,
+    }
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = ParallelEventDispatcher::<Event, Request>::new_with_request_handler(
+        |request: Request| match request {
+            Request::Done => Some(ParallelDispatcherRequest::StopListening),
+            Request::Again => None,
+        },
+    );
+
+    let done_counter = Arc::clone(&counter);
+    dispatcher.add_fn(
+        Event::VariantA,
+        Box::new(move |_: &Event| {
+            done_counter.fetch_add(1, Ordering::SeqCst);
+
+            Some(Request::Done)
+        }),
+    );
+    let again_counter = Arc::clone(&counter);
+    dispatcher.add_fn(
+        Event::VariantA,
+        Box::new(move |_: &Event| {
+            again_counter.fetch_add(1, Ordering::SeqCst);
+
+            Some(Request::Again)
+        }),
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn stop_listening_to_all_events() {
+    #[derive(Default)]
+    struct LeavingListener {
+        dispatch_counter: AtomicUsize,
+    }
+
+    impl ParallelSharedListener<Event> for LeavingListener {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+            self.dispatch_counter.fetch_add(1, Ordering::SeqCst);
+
+            Some(ParallelDispatcherRequest::StopListeningToAll)
+        }
+    }
+
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+    let listener = Arc::new(LeavingListener::default());
+
+    dispatcher.add_shared_listener(Event::VariantA, &listener);
+    dispatcher.add_shared_listener(Event::VariantB, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert_eq!(listener.dispatch_counter.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn listeners_are_notified_about_their_lifecycle() {
+    #[derive(Default)]
+    struct LifecycleListener {
+        subscriptions: usize,
+        unsubscriptions: Vec<UnsubscribeReason>,
+        dispatcher_dropped: usize,
+    }
+
+    impl ParallelListener<Event> for LifecycleListener {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+            Some(ParallelDispatcherRequest::StopListeningToAll)
+        }
+
+        fn on_subscribe(&mut self, _event: &Event) {
+            self.subscriptions += 1;
+        }
+
+        fn on_unsubscribe(&mut self, _event: &Event, reason: UnsubscribeReason) {
+            self.unsubscriptions.push(reason);
+        }
+
+        fn on_dispatcher_dropped(&mut self) {
+            self.dispatcher_dropped += 1;
+        }
+    }
+
+    let leaving = Arc::new(Mutex::new(LifecycleListener::default()));
+    let staying = Arc::new(Mutex::new(LifecycleListener::default()));
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+
+    dispatcher.add_listener(Event::VariantA, &leaving);
+    dispatcher.add_listener(Event::VariantB, &leaving);
+    dispatcher.add_listener(Event::VariantB, &staying);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    drop(dispatcher);
+
+    let leaving = leaving.try_lock().unwrap();
+    assert_eq!(leaving.subscriptions, 2);
+    assert_eq!(
+        leaving.unsubscriptions,
+        [
+            UnsubscribeReason::StopListeningToAll,
+            UnsubscribeReason::StopListeningToAll,
+        ]
+    );
+    assert_eq!(leaving.dispatcher_dropped, 0);
+
+    let staying = staying.try_lock().unwrap();
+    assert!(staying.unsubscriptions.is_empty());
+    assert_eq!(staying.dispatcher_dropped, 1);
+}
+
+#[test]
+fn shared_listeners_are_notified_about_their_lifecycle() {
+    #[derive(Default)]
+    struct SharedLifecycleListener {
+        subscriptions: AtomicUsize,
+        unsubscriptions: Mutex<Vec<UnsubscribeReason>>,
+        dispatcher_dropped: AtomicUsize,
+    }
+
+    impl ParallelSharedListener<Event> for SharedLifecycleListener {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+            Some(ParallelDispatcherRequest::StopListeningToAll)
+        }
+
+        fn on_subscribe(&self, _event: &Event) {
+            self.subscriptions.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_unsubscribe(&self, _event: &Event, reason: UnsubscribeReason) {
+            self.unsubscriptions.lock().unwrap().push(reason);
+        }
+
+        fn on_dispatcher_dropped(&self) {
+            self.dispatcher_dropped.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let leaving = Arc::new(SharedLifecycleListener::default());
+    let staying = Arc::new(SharedLifecycleListener::default());
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+
+    dispatcher.add_shared_listener(Event::VariantA, &leaving);
+    dispatcher.add_shared_listener(Event::VariantB, &leaving);
+    dispatcher.add_shared_listener(Event::VariantB, &staying);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    drop(dispatcher);
+
+    assert_eq!(leaving.subscriptions.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        *leaving.unsubscriptions.lock().unwrap(),
+        [
+            UnsubscribeReason::StopListeningToAll,
+            UnsubscribeReason::StopListeningToAll,
+        ]
+    );
+    assert_eq!(leaving.dispatcher_dropped.load(Ordering::SeqCst), 0);
+
+    assert!(staying.unsubscriptions.lock().unwrap().is_empty());
+    assert_eq!(staying.dispatcher_dropped.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn dropped_listeners_are_reported() {
+    struct SharedCountingListener {
+        dispatch_counter: AtomicUsize,
+    }
+
+    impl ParallelSharedListener<Event> for SharedCountingListener {
+        fn on_event(&self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+            self.dispatch_counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        }
+    }
+
+    let missed_dispatches = Arc::new(AtomicUsize::new(0));
+    let dropped_on_drop = Arc::new(AtomicUsize::new(0));
+    let listener = Arc::new(SharedCountingListener {
+        dispatch_counter: AtomicUsize::new(0),
+    });
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+
+    let (missed, dropped) = (Arc::clone(&missed_dispatches), Arc::clone(&dropped_on_drop));
+    dispatcher.on_dead_listener(move |dead| {
+        assert!(*dead.event() == Event::VariantA);
+
+        if dead.missed_dispatch() {
+            missed.fetch_add(1, Ordering::SeqCst);
+        } else {
+            dropped.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+    dispatcher.add_shared_listener(Event::VariantA, &listener);
+
+    drop(listener);
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+    drop(dispatcher);
+
+    assert_eq!(missed_dispatches.load(Ordering::SeqCst), 1);
+    assert_eq!(dropped_on_drop.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn register_listener_for_its_subscriptions() {
+    #[derive(Default)]
+    struct SubscribingListener {
+        dispatch_counter: usize,
+    }
+
+    impl ParallelListener<Event> for SubscribingListener {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+            self.dispatch_counter += 1;
+
+            None
+        }
+
+        fn subscriptions(&self) -> Vec<Event> {
+            vec![Event::VariantA, Event::VariantB]
+        }
+    }
+
+    let listener = Arc::new(Mutex::new(SubscribingListener::default()));
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+    dispatcher.register(&listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(listener.try_lock().unwrap().dispatch_counter, 2);
+}
+
+#[test]
+fn add_fn_for_multiple_events() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let leaving_counter = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+
+    let fn_counter = Arc::clone(&counter);
+    dispatcher.add_fn_for(
+        vec![Event::VariantA, Event::VariantB],
+        Box::new(move |_: &Event| {
+            fn_counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        }),
+    );
+
+    let fn_counter = Arc::clone(&leaving_counter);
+    dispatcher.add_fn_for(
+        vec![Event::VariantA, Event::VariantB],
+        Box::new(move |_: &Event| {
+            fn_counter.fetch_add(1, Ordering::SeqCst);
+
+            Some(ParallelDispatcherRequest::StopListeningToAll)
+        }),
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+    assert_eq!(leaving_counter.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn weak_fn_is_removed_once_target_drops() {
+    let dead_listeners = Arc::new(AtomicUsize::new(0));
+    let reported = Arc::clone(&dead_listeners);
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+    dispatcher.on_dead_listener(move |_| {
+        reported.fetch_add(1, Ordering::SeqCst);
+    });
+    let target = Arc::new(Mutex::new(0usize));
+
+    dispatcher.add_weak_fn(Event::VariantA, &target, |received: &mut usize, _: &Event| {
+        *received += 1;
+
+        None
+    });
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(*target.try_lock().unwrap(), 1);
+
+    drop(target);
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert_eq!(dead_listeners.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn global_listeners_receive_all_events() {
+    #[derive(Default)]
+    struct GlobalListener {
+        dispatch_counter: usize,
+    }
+
+    impl ParallelListener<Event> for GlobalListener {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+            self.dispatch_counter += 1;
+
+            None
+        }
+    }
+
+    let global = Arc::new(Mutex::new(GlobalListener::default()));
+    let fn_counter = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+
+    dispatcher.add_global_listener(&global);
+
+    let counter = Arc::clone(&fn_counter);
+    dispatcher.add_global_fn(Box::new(move |_: &Event| {
+        counter.fetch_add(1, Ordering::SeqCst);
+
+        Some(ParallelDispatcherRequest::StopListening)
+    }));
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(global.try_lock().unwrap().dispatch_counter, 2);
+    assert_eq!(fn_counter.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn unhandled_events_are_reported_and_kept() {
+    let unhandled = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+
+    let counter = Arc::clone(&unhandled);
+    dispatcher.on_unhandled(move |event| {
+        assert!(*event == Event::VariantB);
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+    dispatcher.dead_letter_capacity(4);
+    dispatcher.add_fn(Event::VariantA, Box::new(|_: &Event| None));
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(unhandled.load(Ordering::SeqCst), 2);
+    assert!(dispatcher.take_dead_letters() == [Event::VariantB, Event::VariantB]);
+}
+
+#[test]
+fn filtered_listeners_and_fns() {
+    #[derive(Default)]
+    struct CountingListener {
+        dispatch_counter: usize,
+    }
+
+    impl ParallelListener<Event> for CountingListener {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+            self.dispatch_counter += 1;
+
+            None
+        }
+    }
+
+    let accepted = Arc::new(Mutex::new(CountingListener::default()));
+    let rejected = Arc::new(Mutex::new(CountingListener::default()));
+    let fn_counter = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+
+    dispatcher.add_listener_filtered(Event::VariantA, &accepted, |_| true);
+    let id = dispatcher.add_listener_filtered(Event::VariantA, &rejected, |_| false);
+
+    let counter = Arc::clone(&fn_counter);
+    dispatcher.add_fn_filtered(
+        Event::VariantA,
+        Box::new(move |_: &Event| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        }),
+        |event| *event == Event::VariantB,
+    );
+
+    // Rejected events do not lock the listener.
+    let guard = rejected.try_lock().unwrap();
+    dispatcher.dispatch_event(&Event::VariantA);
+    drop(guard);
+
+    assert_eq!(accepted.try_lock().unwrap().dispatch_counter, 1);
+    assert_eq!(rejected.try_lock().unwrap().dispatch_counter, 0);
+    assert_eq!(fn_counter.load(Ordering::SeqCst), 0);
+    assert!(dispatcher.remove_listener(id));
+}
+
+#[test]
+fn rejected_events_are_not_dispatched() {
+    #[derive(Default)]
+    struct AcceptingListener {
+        dispatch_counter: usize,
+    }
+
+    impl ParallelListener<Event> for AcceptingListener {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+            self.dispatch_counter += 1;
+
+            None
+        }
+
+        fn accepts(&self, event: &Event) -> bool {
+            *event == Event::VariantA
+        }
+    }
+
+    let read_write_locked = Arc::new(RwLock::new(AcceptingListener::default()));
+    let locked = Arc::new(Mutex::new(AcceptingListener::default()));
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+
+    dispatcher.add_listener_for(vec![Event::VariantA, Event::VariantB], &read_write_locked);
+    dispatcher.add_listener_for(vec![Event::VariantA, Event::VariantB], &locked);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(read_write_locked.try_read().unwrap().dispatch_counter, 1);
+    assert_eq!(locked.try_lock().unwrap().dispatch_counter, 1);
+}
+
+#[test]
+fn mutex_guarded_listeners_are_asked_before_spawning() {
+    struct ThreadRecordingListener {
+        accepting_threads: Arc<Mutex<Vec<ThreadId>>>,
+    }
+
+    impl ParallelListener<Event> for ThreadRecordingListener {
+        fn on_event(&mut self, _event: &Event) -> Option<ParallelDispatcherRequest> {
+            None
+        }
+
+        fn accepts(&self, event: &Event) -> bool {
+            self.accepting_threads
+                .try_lock()
+                .unwrap()
+                .push(thread::current().id());
+
+            *event == Event::VariantA
+        }
+    }
+
+    let accepting_threads = Arc::new(Mutex::new(Vec::new()));
+    let listener = Arc::new(Mutex::new(ThreadRecordingListener {
+        accepting_threads: Arc::clone(&accepting_threads),
+    }));
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantB, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    // Without a thread-pool, work is spawned on `rayon`'s global pool,
+    // hence the rejecting listener has only been asked by this thread.
+    assert_eq!(*accepting_threads.try_lock().unwrap(), [thread::current().id()]);
+}
+
+#[test]
+fn event_hierarchy_dispatches_to_ancestors() {
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Input {
+        Any,
+        Key,
+        KeyDown,
+    }
+
+    impl EventHierarchy for Input {
+        fn parent(&self) -> Option<Input> {
+            match *self {
+                Input::Any => None,
+                Input::Key => Some(Input::Any),
+                Input::KeyDown => Some(Input::Key),
+            }
+        }
+    }
+
+    fn stop_listening() -> Option<ParallelDispatcherRequest> {
+        Some(ParallelDispatcherRequest::StopListening)
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = ParallelEventDispatcher::<Input>::default();
+
+    dispatcher.use_event_hierarchy();
+    dispatcher.add_fn(Input::Any, recording(&record, "any", stop_listening));
+    dispatcher.add_fn(Input::Key, recording(&record, "key", stop_listening));
+    dispatcher.add_fn(Input::KeyDown, recording(&record, "key down", stop_listening));
+
+    dispatcher.dispatch_event(&Input::Key);
+    dispatcher.dispatch_event(&Input::KeyDown);
+
+    assert_eq!(
+        *record.lock().unwrap(),
+        ["key Key", "any Key", "key down KeyDown"]
+    );
+}
+
+#[test]
+fn trace_records_listeners_of_dispatched_events() {
+    struct SilentListener;
+
+    impl ParallelListener<Event> for SilentListener {
+        fn on_event(&mut self, _: &Event) -> Option<ParallelDispatcherRequest> {
+            None
+        }
+    }
+
+    let listener = Arc::new(Mutex::new(SilentListener));
+    let mut dispatcher = ParallelEventDispatcher::<Event>::default();
+    dispatcher.enable_tracing();
+
+    dispatcher.add_listener(Event::VariantA, &listener);
+    let id = dispatcher.add_owned_listener(Event::VariantA, SilentListener);
+    dispatcher.add_global_fn(Box::new(|_: &Event| None));
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    let trace = dispatcher.take_trace();
+    let listeners = trace.events()[0].listeners();
+
+    assert_eq!(trace.roots().count(), 2);
+    assert!(listeners[0].is(&listener));
+    assert_eq!(listeners[1..], [TracedListener::Owned(id), TracedListener::Fn]);
+    assert_eq!(trace.events()[1].listeners(), [TracedListener::Fn]);
+}
@@ -1,6 +1,11 @@
 extern crate hey_listen;
 
-use hey_listen::{Listener, PriorityEventDispatcher, SyncDispatcherRequest};
+mod common;
+
+use common::recording;
+use hey_listen::{DispatchContext, Envelope, EnvelopeListener, EventHierarchy, Listener,
+                 ListenerRequest, PriorityEventDispatcher, SharedListener, SubscriptionPriority,
+                 SyncDispatcherRequest, TracedListener, Veto};
 use std::sync::{Arc, Mutex};
 
 #[derive(Clone, Eq, Hash, PartialEq)]
@@ -254,8 +259,636 @@ fn stop_listening_and_propagation_of_fns() {
     );
 }
 
+#[test]
+fn owned_listeners_dispatch_in_correct_order() {
+    let names_record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityEventDispatcher::<u32, Event>::default();
+
+    dispatcher.add_owned_listener(
+        Event::EventType,
+        EventListener {
+            name: "2".to_string(),
+            name_record: Arc::clone(&names_record),
+        },
+        2,
+    );
+    let id = dispatcher.add_owned_listener(
+        Event::EventType,
+        EventListener {
+            name: "1".to_string(),
+            name_record: Arc::clone(&names_record),
+        },
+        1,
+    );
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.try_lock().unwrap(), ["1", "2"]);
+
+    assert!(dispatcher.remove_listener(id));
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.try_lock().unwrap(), ["1", "2", "2"]);
+}
+
+#[test]
+fn shared_listeners_dispatch_in_correct_order() {
+    struct SharedEventListener {
+        name: String,
+        name_record: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl SharedListener<Event> for SharedEventListener {
+        fn on_event(&self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            self.name_record.try_lock().unwrap().push(self.name.clone());
+
+            None
+        }
+    }
+
+    let names_record = Arc::new(Mutex::new(Vec::new()));
+    let second = Arc::new(SharedEventListener {
+        name: "2".to_string(),
+        name_record: Arc::clone(&names_record),
+    });
+    let first = Arc::new(SharedEventListener {
+        name: "1".to_string(),
+        name_record: Arc::clone(&names_record),
+    });
+
+    let mut dispatcher = PriorityEventDispatcher::<u32, Event>::default();
+    dispatcher.add_shared_listener(Event::EventType, &second, 2);
+    dispatcher.add_shared_listener(Event::EventType, &first, 1);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    assert_eq!(*names_record.try_lock().unwrap(), ["1", "2"]);
+}
+
 #[test]
 fn is_send_and_sync() {
     fn assert_send<T: Send + Sync>(_: &T) {};
     assert_send(&PriorityEventDispatcher::<u32, Event>::default());
 }
+
+#[test]
+fn follow_up_events_dispatch_in_correct_order() {
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Step {
+        Start,
+        FollowUp,
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityEventDispatcher::<u32, Step>::default();
+
+    let start_record = Arc::clone(&record);
+    dispatcher.add_context_fn(
+        Step::Start,
+        Box::new(move |_: &Step, context: &mut DispatchContext<Step>| {
+            start_record.try_lock().unwrap().push("start-1");
+            context.dispatch(Step::FollowUp).unwrap();
+
+            None
+        }),
+        1,
+    );
+
+    let late_record = Arc::clone(&record);
+    dispatcher.add_fn(
+        Step::Start,
+        Box::new(move |_: &Step| {
+            late_record.try_lock().unwrap().push("start-2");
+
+            None
+        }),
+        2,
+    );
+
+    for &(name, priority) in &[("follow-up-2", 2), ("follow-up-1", 1)] {
+        let record = Arc::clone(&record);
+        dispatcher.add_fn(
+            Step::FollowUp,
+            Box::new(move |_: &Step| {
+                record.try_lock().unwrap().push(name);
+
+                None
+            }),
+            priority,
+        );
+    }
+
+    dispatcher.dispatch_event(&Step::Start);
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        ["start-1", "follow-up-1", "follow-up-2", "start-2"]
+    );
+}
+
+#[test]
+fn flush_queued_events() {
+    let names_record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityEventDispatcher::<u32, Event>::default();
+
+    dispatcher.add_owned_listener(
+        Event::EventType,
+        EventListener {
+            name: "1".to_string(),
+            name_record: Arc::clone(&names_record),
+        },
+        1,
+    );
+
+    dispatcher.queue_event(Event::EventType);
+    dispatcher.queue_event(Event::EventType);
+    assert!(names_record.try_lock().unwrap().is_empty());
+
+    dispatcher.flush();
+    assert_eq!(*names_record.try_lock().unwrap(), ["1", "1"]);
+}
+
+#[test]
+fn listeners_change_their_priority() {
+    let names_record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher: PriorityEventDispatcher<u32, Event, ListenerRequest<Event, u32>> =
+        PriorityEventDispatcher::default();
+
+    let record = Arc::clone(&names_record);
+    dispatcher.add_fn(
+        Event::EventType,
+        Box::new(move |_: &Event| {
+            record.try_lock().unwrap().push("2".to_string());
+
+            None
+        }),
+        2,
+    );
+
+    let record = Arc::clone(&names_record);
+    dispatcher.add_fn(
+        Event::EventType,
+        Box::new(move |_: &Event| {
+            record.try_lock().unwrap().push("demoted".to_string());
+
+            Some(ListenerRequest::ChangePriority(3))
+        }),
+        1,
+    );
+
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(
+        *names_record.try_lock().unwrap(),
+        ["demoted", "2", "2", "demoted"]
+    );
+}
+
+#[test]
+fn stop_listening_to_all_priority_levels() {
+    struct LeavingListener {
+        dispatch_counter: usize,
+    }
+
+    impl Listener<Event> for LeavingListener {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            self.dispatch_counter += 1;
+
+            Some(SyncDispatcherRequest::StopListeningToAll)
+        }
+    }
+
+    let mut dispatcher = PriorityEventDispatcher::<u32, Event>::default();
+    let listener = Arc::new(Mutex::new(LeavingListener {
+        dispatch_counter: 0,
+    }));
+
+    dispatcher.add_listener(Event::EventType, &listener, 1);
+    dispatcher.add_listener(Event::EventType, &listener, 2);
+    dispatcher.add_listener(Event::EventType, &listener, 3);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(listener.try_lock().unwrap().dispatch_counter, 1);
+}
+
+#[test]
+fn register_on_declared_priority_levels() {
+    struct DeclaringListener {
+        name: &'static str,
+        priority: Option<u32>,
+        name_record: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Listener<Event> for DeclaringListener {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            self.name_record
+                .try_lock()
+                .unwrap()
+                .push(self.name.to_string());
+
+            None
+        }
+
+        fn subscriptions(&self) -> Vec<Event> {
+            vec![Event::EventType]
+        }
+    }
+
+    impl SubscriptionPriority<Event, u32> for DeclaringListener {
+        fn subscription_priority(&self, _event: &Event) -> Option<u32> {
+            self.priority
+        }
+    }
+
+    let names_record = Arc::new(Mutex::new(Vec::new()));
+    let declaring = Arc::new(Mutex::new(DeclaringListener {
+        name: "declared",
+        priority: Some(1),
+        name_record: Arc::clone(&names_record),
+    }));
+    let falling_back = Arc::new(Mutex::new(DeclaringListener {
+        name: "fallback",
+        priority: None,
+        name_record: Arc::clone(&names_record),
+    }));
+    let added = Arc::new(Mutex::new(EventListener {
+        name: "added".to_string(),
+        name_record: Arc::clone(&names_record),
+    }));
+
+    let mut dispatcher = PriorityEventDispatcher::<u32, Event>::default();
+    dispatcher.register_prioritised(&falling_back, 3);
+    dispatcher.add_listener(Event::EventType, &added, 2);
+    dispatcher.register_prioritised(&declaring, 3);
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(
+        *names_record.try_lock().unwrap(),
+        ["declared", "added", "fallback"]
+    );
+}
+
+#[test]
+fn add_listener_for_multiple_events() {
+    let names_record = Arc::new(Mutex::new(Vec::new()));
+    let listener = Arc::new(Mutex::new(EventListener {
+        name: "listener".to_string(),
+        name_record: Arc::clone(&names_record),
+    }));
+    let mut dispatcher = PriorityEventDispatcher::<u32, Event>::default();
+
+    let fn_record = Arc::clone(&names_record);
+    dispatcher.add_fn_for(
+        vec![Event::EventType],
+        Box::new(move |_: &Event| {
+            fn_record.try_lock().unwrap().push("fn".to_string());
+
+            None
+        }),
+        1,
+    );
+    dispatcher.add_listener_for(vec![Event::EventType], &listener, 0);
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.try_lock().unwrap(), ["listener", "fn"]);
+}
+
+#[test]
+fn global_listeners_take_part_in_priority_order() {
+    let names_record = Arc::new(Mutex::new(Vec::new()));
+    let global = Arc::new(Mutex::new(EventListener {
+        name: "global 2".to_string(),
+        name_record: Arc::clone(&names_record),
+    }));
+    let specific = Arc::new(Mutex::new(EventListener {
+        name: "specific 2".to_string(),
+        name_record: Arc::clone(&names_record),
+    }));
+    let mut dispatcher = PriorityEventDispatcher::<u32, Event>::default();
+
+    let fn_record = Arc::clone(&names_record);
+    dispatcher.add_global_fn(
+        Box::new(move |_: &Event| {
+            fn_record.try_lock().unwrap().push("global 1".to_string());
+
+            None
+        }),
+        1,
+    );
+    dispatcher.add_global_listener(&global, 2);
+    dispatcher.add_listener(Event::EventType, &specific, 2);
+
+    let fn_record = Arc::clone(&names_record);
+    dispatcher.add_fn(
+        Event::EventType,
+        Box::new(move |_: &Event| {
+            fn_record.try_lock().unwrap().push("specific 3".to_string());
+
+            None
+        }),
+        3,
+    );
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(
+        *names_record.try_lock().unwrap(),
+        ["global 1", "specific 2", "global 2", "specific 3"]
+    );
+}
+
+#[test]
+fn unhandled_events_are_reported_and_kept() {
+    let names_record = Arc::new(Mutex::new(Vec::new()));
+    let listener = Arc::new(Mutex::new(EventListener {
+        name: "listener".to_string(),
+        name_record: Arc::clone(&names_record),
+    }));
+    let mut dispatcher = PriorityEventDispatcher::<u32, Event>::default();
+
+    let unhandled_record = Arc::clone(&names_record);
+    dispatcher.on_unhandled(move |_| {
+        unhandled_record.try_lock().unwrap().push("unhandled".to_string())
+    });
+    dispatcher.dead_letter_capacity(1);
+    dispatcher.add_listener(Event::EventType, &listener, 1);
+
+    dispatcher.dispatch_event(&Event::EventType);
+    drop(listener);
+    dispatcher.dispatch_event(&Event::EventType);
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(
+        *names_record.try_lock().unwrap(),
+        ["listener", "unhandled", "unhandled"]
+    );
+    assert_eq!(dispatcher.take_dead_letters().len(), 1);
+}
+
+#[test]
+fn filtered_listeners_take_part_in_priority_order() {
+    let names_record = Arc::new(Mutex::new(Vec::new()));
+    let filtered = Arc::new(Mutex::new(EventListener {
+        name: "filtered".to_string(),
+        name_record: Arc::clone(&names_record),
+    }));
+    let rejecting = Arc::new(Mutex::new(EventListener {
+        name: "rejecting".to_string(),
+        name_record: Arc::clone(&names_record),
+    }));
+    let mut dispatcher = PriorityEventDispatcher::<u32, Event>::default();
+
+    let fn_record = Arc::clone(&names_record);
+    dispatcher.add_fn_filtered(
+        Event::EventType,
+        Box::new(move |_: &Event| {
+            fn_record.try_lock().unwrap().push("fn".to_string());
+
+            None
+        }),
+        |_| true,
+        1,
+    );
+    dispatcher.add_listener_filtered(Event::EventType, &filtered, |_| true, 0);
+    dispatcher.add_listener_filtered(Event::EventType, &rejecting, |_| false, 0);
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*names_record.try_lock().unwrap(), ["filtered", "fn"]);
+}
+
+#[test]
+fn event_hierarchy_merges_priority_levels_of_ancestors() {
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Input {
+        Any,
+        Key,
+        KeyDown,
+    }
+
+    impl EventHierarchy for Input {
+        fn parent(&self) -> Option<Input> {
+            match *self {
+                Input::Any => None,
+                Input::Key => Some(Input::Any),
+                Input::KeyDown => Some(Input::Key),
+            }
+        }
+    }
+
+    let names_record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityEventDispatcher::<u32, Input>::default();
+
+    dispatcher.use_event_hierarchy();
+    dispatcher.add_fn(Input::Any, recording(&names_record, "any 2", || None), 2);
+    dispatcher.add_fn(Input::Key, recording(&names_record, "key 1", || None), 1);
+    dispatcher.add_fn(Input::Key, recording(&names_record, "key 3", || None), 3);
+    dispatcher.add_fn(Input::KeyDown, recording(&names_record, "key down 2", || None), 2);
+    dispatcher.add_global_fn(recording(&names_record, "global 3", || None), 3);
+
+    dispatcher.dispatch_event(&Input::KeyDown);
+
+    assert_eq!(
+        *names_record.try_lock().unwrap(),
+        [
+            "key 1 KeyDown",
+            "key down 2 KeyDown",
+            "any 2 KeyDown",
+            "key 3 KeyDown",
+            "global 3 KeyDown",
+        ]
+    );
+
+    names_record.try_lock().unwrap().clear();
+    dispatcher.add_fn(
+        Input::KeyDown,
+        Box::new(|_: &Input| Some(SyncDispatcherRequest::StopPropagation)),
+        2,
+    );
+
+    dispatcher.dispatch_event(&Input::KeyDown);
+
+    assert_eq!(
+        *names_record.try_lock().unwrap(),
+        ["key 1 KeyDown", "key down 2 KeyDown"]
+    );
+}
+
+#[test]
+fn envelope_listeners_respect_priority_levels() {
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Event {
+        Request,
+        Response,
+    }
+
+    struct SequenceListener {
+        record: Arc<Mutex<Vec<(Event, u64)>>>,
+    }
+
+    impl EnvelopeListener<Event> for SequenceListener {
+        fn on_envelope(
+            &mut self,
+            envelope: &Envelope<Event>,
+        ) -> Option<SyncDispatcherRequest> {
+            self.record
+                .try_lock()
+                .unwrap()
+                .push((envelope.event().clone(), envelope.sequence()));
+
+            None
+        }
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let listener = Arc::new(Mutex::new(SequenceListener {
+        record: Arc::clone(&record),
+    }));
+    let correlations = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityEventDispatcher::<u32, Event>::default();
+
+    dispatcher.source_id("gateway");
+    dispatcher.add_envelope_listener(Event::Request, &listener, 2);
+    dispatcher.add_envelope_listener(Event::Response, &listener, 0);
+
+    let fn_correlations = Arc::clone(&correlations);
+    dispatcher.add_envelope_fn(
+        Event::Request,
+        Box::new(move |envelope: &Envelope<Event>| {
+            assert_eq!(envelope.source_id(), Some("gateway"));
+            fn_correlations
+                .try_lock()
+                .unwrap()
+                .push(envelope.correlation_id());
+
+            None
+        }),
+        1,
+    );
+    let fn_correlations = Arc::clone(&correlations);
+    dispatcher.add_context_fn(
+        Event::Request,
+        Box::new(move |_: &Event, context: &mut DispatchContext<Event>| {
+            fn_correlations
+                .try_lock()
+                .unwrap()
+                .push(context.envelope().correlation_id());
+            context.dispatch(Event::Response).unwrap();
+
+            None
+        }),
+        0,
+    );
+
+    dispatcher.dispatch_event(&Event::Request);
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [(Event::Response, 1), (Event::Request, 0)]
+    );
+    let correlations = correlations.try_lock().unwrap();
+    assert_eq!(correlations.len(), 2);
+    assert_eq!(correlations[0], correlations[1]);
+}
+
+#[test]
+fn nested_dispatches_reach_listeners_of_the_dispatched_event() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityEventDispatcher::<u32, Event>::default();
+
+    let repeating_record = Arc::clone(&record);
+    dispatcher.add_context_fn(
+        Event::EventType,
+        Box::new(move |event: &Event, context: &mut DispatchContext<Event>| {
+            repeating_record.try_lock().unwrap().push("0");
+
+            if context.dispatch(event.clone()).is_err() {
+                repeating_record.try_lock().unwrap().push("cycle");
+            }
+
+            None
+        }),
+        0,
+    );
+    let later_record = Arc::clone(&record);
+    dispatcher.add_fn(
+        Event::EventType,
+        Box::new(move |_: &Event| {
+            later_record.try_lock().unwrap().push("1");
+
+            None
+        }),
+        1,
+    );
+
+    dispatcher.dispatch_event(&Event::EventType);
+
+    assert_eq!(*record.try_lock().unwrap(), ["0", "0", "cycle", "1", "1"]);
+}
+
+#[test]
+fn trace_records_follow_ups_across_priority_levels() {
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Event {
+        Close,
+        Save,
+    }
+
+    let mut dispatcher = PriorityEventDispatcher::<u32, Event>::default();
+    dispatcher.enable_tracing();
+
+    dispatcher.add_fn(Event::Close, Box::new(|_: &Event| None), 0);
+    dispatcher.add_context_fn(
+        Event::Close,
+        Box::new(|_: &Event, context: &mut DispatchContext<Event>| {
+            context.enqueue(Event::Save).unwrap();
+
+            None
+        }),
+        1,
+    );
+    dispatcher.queue_event(Event::Close);
+    dispatcher.flush();
+
+    let trace = dispatcher.take_trace();
+    let close = &trace.events()[0];
+    let save = &trace.events()[1];
+
+    assert_eq!(close.event(), &Event::Close);
+    assert_eq!(close.listeners(), [TracedListener::Fn, TracedListener::Fn]);
+    assert_eq!(save.caused_by(), Some((close.id(), TracedListener::Fn)));
+    assert_eq!(trace.roots().count(), 1);
+}
+
+#[test]
+fn veto_listeners_are_asked_by_priority_level() {
+    let asked = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = PriorityEventDispatcher::<u32, Event>::default();
+
+    for &(priority, vetoes) in &[(3, true), (1, false), (2, true)] {
+        let asked = Arc::clone(&asked);
+
+        dispatcher.add_veto_fn(
+            Event::EventType,
+            Box::new(move |_: &Event| {
+                asked.try_lock().unwrap().push(priority);
+
+                if vetoes {
+                    Err(Veto::new(format!("vetoed on {}", priority)))
+                } else {
+                    Ok(())
+                }
+            }),
+            priority,
+        );
+    }
+
+    let veto = dispatcher.dispatch_cancellable(&Event::EventType).unwrap_err();
+
+    assert_eq!(veto.reason(), "vetoed on 2");
+    assert_eq!(veto.to_string(), "The event has been vetoed: vetoed on 2");
+    assert_eq!(*asked.try_lock().unwrap(), [1, 2]);
+}
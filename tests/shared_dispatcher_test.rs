@@ -0,0 +1,476 @@
+extern crate hey_listen;
+
+use hey_listen::{CorrelationId, DispatchContext, DispatchError, Envelope, EventHierarchy,
+                 Listener, SharedEventDispatcher, SharedListener, SyncDispatcherRequest};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum Event {
+    VariantA,
+    VariantB,
+}
+
+#[derive(Default)]
+struct CountingEventListener {
+    dispatch_counter: usize,
+}
+
+impl Listener<Event> for CountingEventListener {
+    fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+        self.dispatch_counter += 1;
+
+        None
+    }
+}
+
+#[test]
+fn dispatch_to_listeners_and_fns() {
+    let dispatcher = SharedEventDispatcher::<Event>::default();
+    let listener = Arc::new(Mutex::new(CountingEventListener::default()));
+    let fn_counter = Arc::new(AtomicUsize::new(0));
+    let closure_counter = Arc::clone(&fn_counter);
+
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.add_fn(
+        Event::VariantA,
+        Box::new(move |_: &Event| {
+            closure_counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        }),
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(listener.try_lock().unwrap().dispatch_counter, 1);
+    assert_eq!(fn_counter.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn stop_listening_and_propagation() {
+    let dispatcher = SharedEventDispatcher::<Event>::default();
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let closure_counter = Arc::clone(&counter);
+    dispatcher.add_fn(
+        Event::VariantA,
+        Box::new(move |_: &Event| {
+            closure_counter.fetch_add(1, Ordering::SeqCst);
+
+            Some(SyncDispatcherRequest::StopListeningAndPropagation)
+        }),
+    );
+
+    let closure_counter = Arc::clone(&counter);
+    dispatcher.add_fn(
+        Event::VariantA,
+        Box::new(move |_: &Event| {
+            closure_counter.fetch_add(10, Ordering::SeqCst);
+
+            None
+        }),
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(counter.load(Ordering::SeqCst), 11);
+}
+
+#[test]
+fn owned_and_shared_listeners() {
+    struct SharedCountingListener {
+        dispatch_counter: AtomicUsize,
+    }
+
+    impl SharedListener<Event> for SharedCountingListener {
+        fn on_event(&self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            self.dispatch_counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        }
+    }
+
+    let dispatcher = SharedEventDispatcher::<Event>::default();
+    let shared_listener = Arc::new(SharedCountingListener {
+        dispatch_counter: AtomicUsize::new(0),
+    });
+
+    dispatcher.add_shared_listener(Event::VariantB, &shared_listener);
+    let id = dispatcher.add_owned_listener(Event::VariantB, CountingEventListener::default());
+
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert_eq!(shared_listener.dispatch_counter.load(Ordering::SeqCst), 1);
+
+    assert!(dispatcher.remove_listener(id));
+    assert!(!dispatcher.remove_listener(id));
+}
+
+#[test]
+fn register_while_dispatching_from_other_threads() {
+    let dispatcher = Arc::new(SharedEventDispatcher::<Event>::default());
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let dispatching_threads: Vec<_> = (0..4)
+        .map(|_| {
+            let dispatcher = Arc::clone(&dispatcher);
+
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    dispatcher.dispatch_event(&Event::VariantA);
+                }
+            })
+        })
+        .collect();
+
+    for _ in 0..10 {
+        let closure_counter = Arc::clone(&counter);
+
+        dispatcher.add_fn(
+            Event::VariantA,
+            Box::new(move |_: &Event| {
+                closure_counter.fetch_add(1, Ordering::SeqCst);
+
+                None
+            }),
+        );
+    }
+
+    for thread in dispatching_threads {
+        thread.join().unwrap();
+    }
+
+    let before = counter.load(Ordering::SeqCst);
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(counter.load(Ordering::SeqCst), before + 10);
+}
+
+#[test]
+fn is_send_and_sync() {
+    fn assert_send<T: Send + Sync>(_: &T) {}
+    assert_send(&SharedEventDispatcher::<Event>::default());
+}
+
+#[test]
+fn dispatch_follow_up_events() {
+    let dispatcher = SharedEventDispatcher::<Event>::default();
+    let listener = Arc::new(Mutex::new(CountingEventListener::default()));
+    let results = Arc::new(Mutex::new(Vec::new()));
+    dispatcher.add_listener(Event::VariantB, &listener);
+
+    let closure_results = Arc::clone(&results);
+    dispatcher.add_context_fn(
+        Event::VariantA,
+        Box::new(move |_: &Event, context: &mut DispatchContext<Event>| {
+            context.enqueue(Event::VariantB).unwrap();
+            closure_results
+                .try_lock()
+                .unwrap()
+                .push(context.enqueue(Event::VariantA));
+
+            None
+        }),
+    );
+
+    // The closure may emit the event it received once, but not again.
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(*results.try_lock().unwrap(), vec![Ok(()), Err(DispatchError::Cycle)]);
+    assert_eq!(listener.try_lock().unwrap().dispatch_counter, 2);
+}
+
+#[test]
+fn stop_listening_to_all_events() {
+    struct LeavingListener {
+        dispatch_counter: usize,
+    }
+
+    impl Listener<Event> for LeavingListener {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            self.dispatch_counter += 1;
+
+            Some(SyncDispatcherRequest::StopListeningToAll)
+        }
+    }
+
+    let dispatcher = SharedEventDispatcher::<Event>::default();
+    let listener = Arc::new(Mutex::new(LeavingListener {
+        dispatch_counter: 0,
+    }));
+
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.add_listener(Event::VariantB, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert_eq!(listener.try_lock().unwrap().dispatch_counter, 1);
+}
+
+#[test]
+fn dropped_listeners_are_reported() {
+    let listener = Arc::new(Mutex::new(CountingEventListener::default()));
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let dispatcher = SharedEventDispatcher::<Event>::default();
+
+    let thread_reports = Arc::clone(&reports);
+    dispatcher.on_dead_listener(move |dead| {
+        let event = match *dead.event() {
+            Event::VariantA => "A",
+            Event::VariantB => "B",
+        };
+
+        thread_reports
+            .try_lock()
+            .unwrap()
+            .push((event, dead.missed_dispatch()));
+    });
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.add_listener(Event::VariantB, &listener);
+
+    drop(listener);
+    dispatcher.dispatch_event(&Event::VariantA);
+    drop(dispatcher);
+
+    assert_eq!(*reports.try_lock().unwrap(), [("A", true), ("B", false)]);
+}
+
+#[test]
+fn add_fn_for_multiple_events() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let dispatcher = SharedEventDispatcher::<Event>::default();
+
+    let fn_counter = Arc::clone(&counter);
+    dispatcher.add_fn_for(
+        vec![Event::VariantA, Event::VariantB],
+        Box::new(move |_: &Event| {
+            fn_counter.fetch_add(1, Ordering::SeqCst);
+
+            Some(SyncDispatcherRequest::StopListeningToAll)
+        }),
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn global_listeners_receive_all_events() {
+    let global = Arc::new(Mutex::new(CountingEventListener::default()));
+    let dispatcher = SharedEventDispatcher::<Event>::default();
+
+    dispatcher.add_global_listener(&global);
+    dispatcher.add_fn(
+        Event::VariantB,
+        Box::new(|_: &Event| Some(SyncDispatcherRequest::StopPropagation)),
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(global.try_lock().unwrap().dispatch_counter, 1);
+}
+
+#[test]
+fn unhandled_events_are_reported_and_kept() {
+    let listener = Arc::new(Mutex::new(CountingEventListener::default()));
+    let unhandled = Arc::new(AtomicUsize::new(0));
+    let dispatcher = SharedEventDispatcher::<Event>::default();
+
+    let counter = Arc::clone(&unhandled);
+    dispatcher.on_unhandled(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+    dispatcher.dead_letter_capacity(4);
+    dispatcher.add_listener(Event::VariantA, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    drop(listener);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert_eq!(unhandled.load(Ordering::SeqCst), 2);
+    assert!(dispatcher.take_dead_letters() == [Event::VariantB, Event::VariantA]);
+}
+
+#[test]
+fn filtered_listeners_and_fns() {
+    let accepted = Arc::new(Mutex::new(CountingEventListener::default()));
+    let rejected = Arc::new(Mutex::new(CountingEventListener::default()));
+    let fn_counter = Arc::new(AtomicUsize::new(0));
+    let dispatcher = SharedEventDispatcher::<Event>::default();
+
+    dispatcher.add_listener_filtered(Event::VariantA, &accepted, |_| true);
+    dispatcher.add_listener_filtered(Event::VariantA, &rejected, |_| false);
+
+    let counter = Arc::clone(&fn_counter);
+    dispatcher.add_fn_filtered(
+        Event::VariantA,
+        Box::new(move |_: &Event| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        }),
+        |event| *event == Event::VariantA,
+    );
+
+    // Rejected events do not lock the listener.
+    let guard = rejected.try_lock().unwrap();
+    dispatcher.dispatch_event(&Event::VariantA);
+    drop(guard);
+
+    assert_eq!(accepted.try_lock().unwrap().dispatch_counter, 1);
+    assert_eq!(rejected.try_lock().unwrap().dispatch_counter, 0);
+    assert_eq!(fn_counter.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn event_hierarchy_dispatches_to_ancestors() {
+    #[derive(Clone, Eq, Hash, PartialEq)]
+    enum Input {
+        Any,
+        Key,
+        KeyDown,
+    }
+
+    impl EventHierarchy for Input {
+        fn parent(&self) -> Option<Input> {
+            match *self {
+                Input::Any => None,
+                Input::Key => Some(Input::Any),
+                Input::KeyDown => Some(Input::Key),
+            }
+        }
+    }
+
+    struct StoppingListener {
+        received: Vec<Input>,
+    }
+
+    impl Listener<Input> for StoppingListener {
+        fn on_event(&mut self, event: &Input) -> Option<SyncDispatcherRequest> {
+            self.received.push(event.clone());
+
+            Some(SyncDispatcherRequest::StopListeningAndPropagation)
+        }
+    }
+
+    let listener = Arc::new(Mutex::new(StoppingListener { received: vec![] }));
+    let any_counter = Arc::new(AtomicUsize::new(0));
+    let unhandled = Arc::new(AtomicUsize::new(0));
+    let dispatcher = SharedEventDispatcher::<Input>::default();
+
+    let counter = Arc::clone(&unhandled);
+    dispatcher.on_unhandled(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+    dispatcher.use_event_hierarchy();
+    dispatcher.add_listener(Input::Key, &listener);
+
+    let counter = Arc::clone(&any_counter);
+    dispatcher.add_fn(
+        Input::Any,
+        Box::new(move |_: &Input| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        }),
+    );
+
+    dispatcher.dispatch_event(&Input::KeyDown);
+    dispatcher.dispatch_event(&Input::KeyDown);
+    dispatcher.dispatch_event(&Input::Any);
+
+    assert!(listener.try_lock().unwrap().received == [Input::KeyDown]);
+    assert_eq!(any_counter.load(Ordering::SeqCst), 2);
+    assert_eq!(unhandled.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn envelopes_share_sequence_across_threads() {
+    let dispatcher = Arc::new(SharedEventDispatcher::<Event>::default());
+    let sequences = Arc::new(Mutex::new(Vec::new()));
+
+    dispatcher.source_id("worker");
+    let fn_sequences = Arc::clone(&sequences);
+    dispatcher.add_envelope_fn(
+        Event::VariantA,
+        Box::new(move |envelope: &Envelope<Event>| {
+            assert_eq!(envelope.source_id(), Some("worker"));
+            fn_sequences.lock().unwrap().push(envelope.sequence());
+
+            None
+        }),
+    );
+    dispatcher.add_context_fn(
+        Event::VariantB,
+        Box::new(|_: &Event, context: &mut DispatchContext<Event>| {
+            assert_eq!(context.envelope().correlation_id(), CorrelationId::from(3));
+            context.enqueue(Event::VariantA).unwrap();
+
+            None
+        }),
+    );
+
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let dispatcher = Arc::clone(&dispatcher);
+
+            thread::spawn(move || dispatcher.dispatch_event(&Event::VariantA))
+        })
+        .collect();
+
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    dispatcher.dispatch_correlated(&Event::VariantB, CorrelationId::from(3));
+
+    let mut sequences = sequences.lock().unwrap();
+    sequences.sort();
+    assert_eq!(*sequences, [0, 1, 2, 3, 5]);
+}
+
+#[test]
+fn custom_requests_are_translated_by_request_handler() {
+    enum Request {
+        Retire,
+        Ignore,
+    }
+
+    let dispatcher = SharedEventDispatcher::<Event, Request>::new_with_request_handler(
+        |request: Request| match request {
+            Request::Retire => Some(SyncDispatcherRequest::StopListening),
+            Request::Ignore => None,
+        },
+    );
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let retiring = Arc::clone(&counter);
+    dispatcher.add_fn(
+        Event::VariantA,
+        Box::new(move |_: &Event| {
+            retiring.fetch_add(1, Ordering::SeqCst);
+
+            Some(Request::Retire)
+        }),
+    );
+    let ignoring = Arc::clone(&counter);
+    dispatcher.add_fn(
+        Event::VariantA,
+        Box::new(move |_: &Event| {
+            ignoring.fetch_add(10, Ordering::SeqCst);
+
+            Some(Request::Ignore)
+        }),
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert_eq!(counter.load(Ordering::SeqCst), 21);
+}
@@ -1,8 +1,18 @@
 extern crate hey_listen;
-
-use hey_listen::{EventDispatcher, Listener, SyncDispatcherRequest};
-use std::sync::{Arc, Mutex};
+#[cfg(feature = "parking_lot")]
+extern crate parking_lot;
+
+mod common;
+
+use common::{recording, Record, RecordingFn};
+use hey_listen::{CorrelationId, DispatchContext, DispatchError, Envelope, EnvelopeListener,
+                 EventDispatcher, EventHierarchy, Listener, ListenerLock, ListenerRequest,
+                 SharedListener, SyncDispatcherRequest, TracedListener, UnsubscribeReason, Veto,
+                 VetoListener};
+use std::sync::{Arc, Mutex, RwLock};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::ops::Deref;
+use std::time::{Duration, UNIX_EPOCH};
 
 #[derive(Clone, Eq, Hash, PartialEq)]
 enum Event {
@@ -428,8 +438,1734 @@ fn stop_propagation_and_listening_on_sync_dispatcher_of_fns() {
     assert_eq!(counter, 2);
 }
 
+#[test]
+fn owned_listener_lives_until_it_stops_listening() {
+    struct EventListener {
+        dispatch_counter: Arc<Mutex<usize>>,
+    }
+
+    impl Listener<Event> for EventListener {
+        fn on_event(&mut self, _: &Event) -> Option<SyncDispatcherRequest> {
+            let mut counter = self.dispatch_counter.try_lock().unwrap();
+            *counter += 1;
+
+            if *counter == 2 {
+                Some(SyncDispatcherRequest::StopListening)
+            } else {
+                None
+            }
+        }
+    }
+
+    let counter = Arc::new(Mutex::new(0));
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    dispatcher.add_owned_listener(
+        Event::VariantA,
+        EventListener {
+            dispatch_counter: Arc::clone(&counter),
+        },
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(*counter.try_lock().unwrap(), 1);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(*counter.try_lock().unwrap(), 2);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(*counter.try_lock().unwrap(), 2);
+}
+
+#[test]
+fn remove_owned_listener_by_id() {
+    struct EventListener {
+        dispatch_counter: Arc<Mutex<usize>>,
+    }
+
+    impl Listener<Event> for EventListener {
+        fn on_event(&mut self, _: &Event) -> Option<SyncDispatcherRequest> {
+            *self.dispatch_counter.try_lock().unwrap() += 1;
+
+            None
+        }
+    }
+
+    let counter = Arc::new(Mutex::new(0));
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    let id = dispatcher.add_owned_listener(
+        Event::VariantA,
+        EventListener {
+            dispatch_counter: Arc::clone(&counter),
+        },
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(*counter.try_lock().unwrap(), 1);
+
+    assert!(dispatcher.remove_listener(id));
+    assert!(!dispatcher.remove_listener(id));
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(*counter.try_lock().unwrap(), 1);
+}
+
+#[test]
+fn dispatch_to_shared_listener_without_mutex() {
+    struct EventListener {
+        dispatch_counter: AtomicUsize,
+    }
+
+    impl SharedListener<Event> for EventListener {
+        fn on_event(&self, _: &Event) -> Option<SyncDispatcherRequest> {
+            self.dispatch_counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        }
+    }
+
+    let listener = Arc::new(EventListener {
+        dispatch_counter: AtomicUsize::new(0),
+    });
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    dispatcher.add_shared_listener(Event::VariantA, &listener);
+    dispatcher.add_shared_listener(Event::VariantB, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert_eq!(listener.dispatch_counter.load(Ordering::SeqCst), 2);
+
+    drop(listener);
+    dispatcher.dispatch_event(&Event::VariantA);
+}
+
+#[test]
+fn dispatch_to_listener_behind_rw_lock() {
+    let listener = Arc::new(RwLock::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert!(listener.read().unwrap().received_variant_a);
+    assert!(!listener.read().unwrap().received_variant_b);
+}
+
+#[test]
+fn dispatch_to_listener_behind_custom_lock() {
+    struct CountingLock<D> {
+        lock_counter: AtomicUsize,
+        listener: Mutex<D>,
+    }
+
+    impl<D: Send + 'static> ListenerLock for CountingLock<D> {
+        type Listener = D;
+
+        fn with_listener<R, F: FnOnce(&mut D) -> R>(&self, function: F) -> R {
+            self.lock_counter.fetch_add(1, Ordering::SeqCst);
+
+            function(&mut self.listener.lock().unwrap())
+        }
+    }
+
+    let listener = Arc::new(CountingLock {
+        lock_counter: AtomicUsize::new(0),
+        listener: Mutex::new(EventListener {
+            received_variant_a: false,
+            received_variant_b: false,
+        }),
+    });
+
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    // Locked once for subscribing and once for dispatching.
+    assert_eq!(listener.lock_counter.load(Ordering::SeqCst), 2);
+    assert!(listener.listener.lock().unwrap().received_variant_a);
+}
+
+#[cfg(feature = "parking_lot")]
+#[test]
+fn dispatch_to_listener_behind_parking_lot_mutex() {
+    let listener = Arc::new(parking_lot::Mutex::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantB, &listener);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert!(listener.lock().received_variant_b);
+}
+
 #[test]
 fn is_send_and_sync() {
     fn assert_send<T: Send + Sync>(_: &T) {};
     assert_send(&EventDispatcher::<Event>::default());
 }
+
+#[test]
+fn follow_up_events_dispatch_immediately_or_queued() {
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Step {
+        Start,
+        Immediate,
+        Queued,
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = EventDispatcher::<Step>::default();
+
+    let first_record = Arc::clone(&record);
+    dispatcher.add_context_fn(
+        Step::Start,
+        Box::new(move |_: &Step, context: &mut DispatchContext<Step>| {
+            first_record.try_lock().unwrap().push("start-1");
+            context.enqueue(Step::Queued).unwrap();
+            context.dispatch(Step::Immediate).unwrap();
+
+            None
+        }),
+    );
+
+    let second_record = Arc::clone(&record);
+    dispatcher.add_fn(
+        Step::Start,
+        Box::new(move |_: &Step| {
+            second_record.try_lock().unwrap().push("start-2");
+
+            None
+        }),
+    );
+
+    let immediate_record = Arc::clone(&record);
+    dispatcher.add_context_fn(
+        Step::Immediate,
+        Box::new(move |_: &Step, context: &mut DispatchContext<Step>| {
+            assert_eq!(context.depth(), 1);
+            assert_eq!(
+                context.causes().collect::<Vec<_>>(),
+                [&Step::Immediate, &Step::Start]
+            );
+            immediate_record.try_lock().unwrap().push("immediate");
+
+            None
+        }),
+    );
+
+    let queued_record = Arc::clone(&record);
+    dispatcher.add_fn(
+        Step::Queued,
+        Box::new(move |_: &Step| {
+            queued_record.try_lock().unwrap().push("queued");
+
+            None
+        }),
+    );
+
+    dispatcher.dispatch_event(&Step::Start);
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        ["start-1", "immediate", "start-2", "queued"]
+    );
+}
+
+#[test]
+fn follow_up_events_reject_cycles_and_exceeding_depth() {
+    #[derive(Default)]
+    struct ForwardingListener {
+        errors: Vec<DispatchError>,
+    }
+
+    impl Listener<Event> for ForwardingListener {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            None
+        }
+
+        fn on_event_with_context(
+            &mut self,
+            event: &Event,
+            context: &mut DispatchContext<Event>,
+        ) -> Option<SyncDispatcherRequest> {
+            let follow_up = match *event {
+                Event::VariantA => Event::VariantB,
+                Event::VariantB => Event::VariantA,
+            };
+
+            if let Err(error) = context.dispatch(follow_up) {
+                self.errors.push(error);
+            }
+
+            None
+        }
+    }
+
+    let listener = Arc::new(Mutex::new(ForwardingListener::default()));
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.add_listener(Event::VariantB, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(listener.try_lock().unwrap().errors, [DispatchError::Cycle]);
+
+    listener.try_lock().unwrap().errors.clear();
+    dispatcher.max_dispatch_depth(0);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(
+        listener.try_lock().unwrap().errors,
+        [DispatchError::DepthExceeded(0)]
+    );
+}
+
+#[test]
+fn nested_dispatches_reach_listeners_of_the_dispatched_event() {
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    struct Code(u16);
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let results = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = EventDispatcher::<Code>::default();
+
+    let repeating_record = Arc::clone(&record);
+    let repeating_results = Arc::clone(&results);
+    dispatcher.add_context_fn(
+        Code(1),
+        Box::new(move |code: &Code, context: &mut DispatchContext<Code>| {
+            repeating_record.try_lock().unwrap().push("keyed");
+            let result = context.dispatch(code.clone());
+            repeating_results.try_lock().unwrap().push(result);
+
+            None
+        }),
+    );
+    let range_record = Arc::clone(&record);
+    dispatcher.add_fn_range(
+        Code(0)..Code(2),
+        Box::new(move |_: &Code| {
+            range_record.try_lock().unwrap().push("range");
+
+            None
+        }),
+    );
+    let global_record = Arc::clone(&record);
+    dispatcher.add_global_fn(Box::new(move |_: &Code| {
+        global_record.try_lock().unwrap().push("global");
+
+        None
+    }));
+
+    // The closure may dispatch the event it received once, but not again.
+    dispatcher.dispatch_event(&Code(1));
+
+    assert_eq!(
+        *results.try_lock().unwrap(),
+        [Ok(()), Err(DispatchError::Cycle)]
+    );
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        ["keyed", "keyed", "range", "global", "range", "global"]
+    );
+}
+
+#[test]
+fn flush_queued_events_in_order() {
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Tick {
+        Input(u32),
+        Render,
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = EventDispatcher::<Tick>::default();
+
+    for &input in &[1, 2] {
+        let record = Arc::clone(&record);
+        dispatcher.add_context_fn(
+            Tick::Input(input),
+            Box::new(move |event: &Tick, context: &mut DispatchContext<Tick>| {
+                record.try_lock().unwrap().push(event.clone());
+                context.enqueue(Tick::Render).unwrap();
+
+                None
+            }),
+        );
+    }
+
+    let render_record = Arc::clone(&record);
+    dispatcher.add_fn(
+        Tick::Render,
+        Box::new(move |event: &Tick| {
+            render_record.try_lock().unwrap().push(event.clone());
+
+            None
+        }),
+    );
+
+    dispatcher.queue_event(Tick::Input(2));
+    dispatcher.queue_event(Tick::Input(1));
+    assert!(record.try_lock().unwrap().is_empty());
+
+    dispatcher.flush();
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [Tick::Input(2), Tick::Input(1), Tick::Render, Tick::Render]
+    );
+
+    record.try_lock().unwrap().clear();
+    dispatcher.max_flush_events(2);
+    dispatcher.queue_event(Tick::Input(1));
+    dispatcher.queue_event(Tick::Input(2));
+
+    dispatcher.flush();
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [Tick::Input(1), Tick::Input(2)]
+    );
+
+    dispatcher.flush();
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [Tick::Input(1), Tick::Input(2), Tick::Render, Tick::Render]
+    );
+}
+
+#[test]
+fn listeners_emit_events_and_replace_themselves() {
+    struct Greeter {
+        greeting: &'static str,
+        record: Arc<Mutex<Vec<&'static str>>>,
+    }
+
+    impl Listener<Event, ListenerRequest<Event>> for Greeter {
+        fn on_event(&mut self, event: &Event) -> Option<ListenerRequest<Event>> {
+            self.record.try_lock().unwrap().push(self.greeting);
+
+            match *event {
+                Event::VariantA => Some(ListenerRequest::Emit(Event::VariantB)),
+                Event::VariantB => Some(ListenerRequest::Replace(Box::new(Greeter {
+                    greeting: "goodbye",
+                    record: Arc::clone(&self.record),
+                }))),
+            }
+        }
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = EventDispatcher::<Event, ListenerRequest<Event>>::default();
+    dispatcher.add_owned_listener(
+        Event::VariantA,
+        Greeter {
+            greeting: "hello",
+            record: Arc::clone(&record),
+        },
+    );
+    let id = dispatcher.add_owned_listener(
+        Event::VariantB,
+        Greeter {
+            greeting: "hello",
+            record: Arc::clone(&record),
+        },
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(*record.try_lock().unwrap(), ["hello", "hello"]);
+
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert_eq!(*record.try_lock().unwrap(), ["hello", "hello", "goodbye"]);
+
+    assert!(dispatcher.remove_listener(id));
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert_eq!(*record.try_lock().unwrap(), ["hello", "hello", "goodbye"]);
+}
+
+#[test]
+fn rejected_emits_are_reported() {
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = EventDispatcher::<Event, ListenerRequest<Event>>::default();
+
+    let reported = Arc::clone(&errors);
+    dispatcher.on_emit_error(move |event, error| {
+        assert!(*event == Event::VariantB);
+        reported.try_lock().unwrap().push(error.to_string());
+    });
+    dispatcher.add_fn(
+        Event::VariantA,
+        Box::new(|_: &Event| Some(ListenerRequest::Emit(Event::VariantB))),
+    );
+    dispatcher.add_fn(
+        Event::VariantB,
+        Box::new(|_: &Event| Some(ListenerRequest::Emit(Event::VariantA))),
+    );
+
+    // Each closure emits its event once, until the first one emits `VariantB` again.
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(
+        *errors.try_lock().unwrap(),
+        [DispatchError::Cycle.to_string()]
+    );
+}
+
+#[test]
+fn custom_requests_are_translated_by_request_handler() {
+    enum Request {
+        MarkHandled,
+        Retire,
+        Ignore,
+    }
+
+    struct Handler {
+        request: fn() -> Request,
+        dispatch_counter: usize,
+    }
+
+    impl Listener<Event, Request> for Handler {
+        fn on_event(&mut self, _event: &Event) -> Option<Request> {
+            self.dispatch_counter += 1;
+
+            Some((self.request)())
+        }
+    }
+
+    let mut dispatcher = EventDispatcher::<Event, Request>::new_with_request_handler(
+        |request: Request| match request {
+            Request::MarkHandled => Some(SyncDispatcherRequest::StopPropagation),
+            Request::Retire => Some(SyncDispatcherRequest::StopListening),
+            Request::Ignore => None,
+        },
+    );
+
+    let retiring = Arc::new(Mutex::new(Handler {
+        request: || Request::Retire,
+        dispatch_counter: 0,
+    }));
+    let handling = Arc::new(Mutex::new(Handler {
+        request: || Request::MarkHandled,
+        dispatch_counter: 0,
+    }));
+    let ignored = Arc::new(Mutex::new(Handler {
+        request: || Request::Ignore,
+        dispatch_counter: 0,
+    }));
+
+    dispatcher.add_listener(Event::VariantA, &ignored);
+    dispatcher.add_listener(Event::VariantA, &retiring);
+    dispatcher.add_listener(Event::VariantA, &handling);
+
+    let unreached = Arc::new(AtomicUsize::new(0));
+    let fn_unreached = Arc::clone(&unreached);
+    dispatcher.add_fn(
+        Event::VariantA,
+        Box::new(move |_: &Event| {
+            fn_unreached.fetch_add(1, Ordering::SeqCst);
+
+            None
+        }),
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert_eq!(ignored.try_lock().unwrap().dispatch_counter, 2);
+    assert_eq!(retiring.try_lock().unwrap().dispatch_counter, 1);
+    assert_eq!(handling.try_lock().unwrap().dispatch_counter, 2);
+    assert_eq!(unreached.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn stop_listening_to_this_or_all_events() {
+    struct Session {
+        request: fn() -> SyncDispatcherRequest,
+        dispatch_counter: usize,
+    }
+
+    impl Listener<Event> for Session {
+        fn on_event(&mut self, _event: &Event) -> Option<SyncDispatcherRequest> {
+            self.dispatch_counter += 1;
+
+            Some((self.request)())
+        }
+    }
+
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    let leaving_one = Arc::new(Mutex::new(Session {
+        request: || SyncDispatcherRequest::StopListening,
+        dispatch_counter: 0,
+    }));
+    let leaving_all = Arc::new(Mutex::new(Session {
+        request: || SyncDispatcherRequest::StopListeningToAll,
+        dispatch_counter: 0,
+    }));
+
+    for listener in &[&leaving_one, &leaving_all] {
+        dispatcher.add_listener(Event::VariantA, listener);
+        dispatcher.add_listener(Event::VariantB, listener);
+    }
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(leaving_one.try_lock().unwrap().dispatch_counter, 2);
+    assert_eq!(leaving_all.try_lock().unwrap().dispatch_counter, 1);
+}
+
+#[derive(Debug, PartialEq)]
+enum Lifecycle {
+    Subscribed(&'static str),
+    Unsubscribed(&'static str, UnsubscribeReason),
+    DispatcherDropped,
+}
+
+fn event_name(event: &Event) -> &'static str {
+    match *event {
+        Event::VariantA => "A",
+        Event::VariantB => "B",
+    }
+}
+
+struct LifecycleListener {
+    record: Arc<Mutex<Vec<Lifecycle>>>,
+}
+
+impl Listener<Event> for LifecycleListener {
+    fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> {
+        match *event {
+            Event::VariantA => Some(SyncDispatcherRequest::StopListening),
+            Event::VariantB => None,
+        }
+    }
+
+    fn on_subscribe(&mut self, event: &Event) {
+        self.record
+            .try_lock()
+            .unwrap()
+            .push(Lifecycle::Subscribed(event_name(event)));
+    }
+
+    fn on_unsubscribe(&mut self, event: &Event, reason: UnsubscribeReason) {
+        self.record
+            .try_lock()
+            .unwrap()
+            .push(Lifecycle::Unsubscribed(event_name(event), reason));
+    }
+
+    fn on_dispatcher_dropped(&mut self) {
+        self.record
+            .try_lock()
+            .unwrap()
+            .push(Lifecycle::DispatcherDropped);
+    }
+}
+
+impl EnvelopeListener<Event> for LifecycleListener {
+    fn on_envelope(&mut self, envelope: &Envelope<Event>) -> Option<SyncDispatcherRequest> {
+        self.on_event(envelope.event())
+    }
+
+    fn on_subscribe(&mut self, event: &Event) {
+        Listener::on_subscribe(self, event);
+    }
+
+    fn on_unsubscribe(&mut self, event: &Event, reason: UnsubscribeReason) {
+        Listener::on_unsubscribe(self, event, reason);
+    }
+
+    fn on_dispatcher_dropped(&mut self) {
+        Listener::on_dispatcher_dropped(self);
+    }
+}
+
+#[test]
+fn listeners_are_notified_about_their_lifecycle() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let listener = Arc::new(Mutex::new(LifecycleListener {
+        record: Arc::clone(&record),
+    }));
+
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.add_listener(Event::VariantB, &listener);
+    let id = dispatcher.add_owned_listener(
+        Event::VariantB,
+        LifecycleListener {
+            record: Arc::clone(&record),
+        },
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(dispatcher.remove_listener(id));
+    drop(dispatcher);
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [
+            Lifecycle::Subscribed("A"),
+            Lifecycle::Subscribed("B"),
+            Lifecycle::Subscribed("B"),
+            Lifecycle::Unsubscribed("A", UnsubscribeReason::StopListening),
+            Lifecycle::Unsubscribed("B", UnsubscribeReason::Removed),
+            Lifecycle::DispatcherDropped,
+        ]
+    );
+}
+
+#[test]
+fn shared_listeners_are_notified_about_their_lifecycle() {
+    struct SharedLifecycleListener {
+        record: Mutex<Vec<Lifecycle>>,
+    }
+
+    impl SharedListener<Event> for SharedLifecycleListener {
+        fn on_event(&self, event: &Event) -> Option<SyncDispatcherRequest> {
+            match *event {
+                Event::VariantA => Some(SyncDispatcherRequest::StopListening),
+                Event::VariantB => None,
+            }
+        }
+
+        fn on_subscribe(&self, event: &Event) {
+            self.record
+                .try_lock()
+                .unwrap()
+                .push(Lifecycle::Subscribed(event_name(event)));
+        }
+
+        fn on_unsubscribe(&self, event: &Event, reason: UnsubscribeReason) {
+            self.record
+                .try_lock()
+                .unwrap()
+                .push(Lifecycle::Unsubscribed(event_name(event), reason));
+        }
+
+        fn on_dispatcher_dropped(&self) {
+            self.record
+                .try_lock()
+                .unwrap()
+                .push(Lifecycle::DispatcherDropped);
+        }
+    }
+
+    let listener = Arc::new(SharedLifecycleListener {
+        record: Mutex::new(Vec::new()),
+    });
+
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    dispatcher.add_shared_listener(Event::VariantA, &listener);
+    dispatcher.add_shared_listener(Event::VariantB, &listener);
+    dispatcher.add_shared_listener(Event::VariantB, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    drop(dispatcher);
+
+    assert_eq!(
+        *listener.record.try_lock().unwrap(),
+        [
+            Lifecycle::Subscribed("A"),
+            Lifecycle::Subscribed("B"),
+            Lifecycle::Subscribed("B"),
+            Lifecycle::Unsubscribed("A", UnsubscribeReason::StopListening),
+            Lifecycle::DispatcherDropped,
+        ]
+    );
+}
+
+#[test]
+fn envelope_listeners_are_notified_about_their_lifecycle() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let listener = Arc::new(Mutex::new(LifecycleListener {
+        record: Arc::clone(&record),
+    }));
+
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    dispatcher.add_envelope_listener(Event::VariantA, &listener);
+    let id = dispatcher.add_envelope_listener(Event::VariantB, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(dispatcher.remove_listener(id));
+    dispatcher.add_envelope_listener(Event::VariantB, &listener);
+    drop(dispatcher);
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [
+            Lifecycle::Subscribed("A"),
+            Lifecycle::Subscribed("B"),
+            Lifecycle::Unsubscribed("A", UnsubscribeReason::StopListening),
+            Lifecycle::Unsubscribed("B", UnsubscribeReason::Removed),
+            Lifecycle::Subscribed("B"),
+            Lifecycle::DispatcherDropped,
+        ]
+    );
+}
+
+#[test]
+fn dropped_listeners_are_reported() {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let dropped_early = Arc::new(Mutex::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let dropped_late = Arc::new(Mutex::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    let thread_reports = Arc::clone(&reports);
+    dispatcher.on_dead_listener(move |dead| {
+        thread_reports
+            .try_lock()
+            .unwrap()
+            .push((event_name(dead.event()), dead.missed_dispatch()));
+    });
+    dispatcher.add_listener(Event::VariantA, &dropped_early);
+    dispatcher.add_listener(Event::VariantB, &dropped_late);
+
+    drop(dropped_early);
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+    drop(dropped_late);
+    drop(dispatcher);
+
+    assert_eq!(*reports.try_lock().unwrap(), [("A", true), ("B", false)]);
+}
+
+#[test]
+fn dropped_filtered_listeners_are_reported() {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let dropped_early = Arc::new(Mutex::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let dropped_late = Arc::new(Mutex::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    let thread_reports = Arc::clone(&reports);
+    dispatcher.on_dead_listener(move |dead| {
+        thread_reports
+            .try_lock()
+            .unwrap()
+            .push((event_name(dead.event()), dead.missed_dispatch()));
+    });
+    // Dropped listeners are removed even if their predicate rejects the event.
+    let id = dispatcher.add_listener_filtered(Event::VariantA, &dropped_early, |_| false);
+    dispatcher.add_listener_filtered(Event::VariantB, &dropped_late, |_| true);
+
+    drop(dropped_early);
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert!(!dispatcher.remove_listener(id));
+    drop(dropped_late);
+    drop(dispatcher);
+
+    assert_eq!(*reports.try_lock().unwrap(), [("A", true), ("B", false)]);
+}
+
+#[test]
+fn register_listener_for_its_subscriptions() {
+    struct SubscribingListener {
+        received: Vec<&'static str>,
+    }
+
+    impl Listener<Event> for SubscribingListener {
+        fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> {
+            self.received.push(event_name(event));
+
+            None
+        }
+
+        fn subscriptions(&self) -> Vec<Event> {
+            vec![Event::VariantB]
+        }
+    }
+
+    let listener = Arc::new(Mutex::new(SubscribingListener { received: vec![] }));
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    dispatcher.register(&listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(listener.try_lock().unwrap().received, ["B"]);
+}
+
+#[test]
+fn add_listener_and_fn_for_multiple_events() {
+    let listener = Arc::new(Mutex::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = EventDispatcher::<Event>::default();
+
+    dispatcher.add_listener_for(vec![Event::VariantA, Event::VariantB], &listener);
+
+    let fn_calls = Arc::clone(&calls);
+    dispatcher.add_fn_for(
+        vec![Event::VariantA, Event::VariantB],
+        Box::new(move |event: &Event| {
+            fn_calls.try_lock().unwrap().push(event_name(event));
+
+            Some(SyncDispatcherRequest::StopListening)
+        }),
+    );
+
+    let fn_calls = Arc::clone(&calls);
+    dispatcher.add_fn_for(
+        vec![Event::VariantA, Event::VariantB],
+        Box::new(move |_: &Event| {
+            fn_calls.try_lock().unwrap().push("all");
+
+            Some(SyncDispatcherRequest::StopListeningToAll)
+        }),
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    let listener = listener.try_lock().unwrap();
+    assert!(listener.received_variant_a);
+    assert!(listener.received_variant_b);
+    assert_eq!(*calls.try_lock().unwrap(), ["A", "all", "B"]);
+}
+
+#[test]
+fn weak_fn_is_removed_once_target_drops() {
+    struct Target {
+        received: usize,
+    }
+
+    // Lacks a conversion from `SyncDispatcherRequest`.
+    enum Request {
+        Ignore,
+    }
+
+    let dead_listeners = Arc::new(AtomicUsize::new(0));
+    let reported = Arc::clone(&dead_listeners);
+    let mut dispatcher = EventDispatcher::<Event, Request>::new_with_request_handler(
+        |request: Request| match request {
+            Request::Ignore => None::<SyncDispatcherRequest>,
+        },
+    );
+    dispatcher.on_dead_listener(move |_| {
+        reported.fetch_add(1, Ordering::SeqCst);
+    });
+    let target = Arc::new(Mutex::new(Target { received: 0 }));
+
+    dispatcher.add_weak_fn(Event::VariantA, &target, |target: &mut Target, _: &Event| {
+        target.received += 1;
+
+        Some(Request::Ignore)
+    });
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(target.try_lock().unwrap().received, 1);
+
+    drop(target);
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert_eq!(dead_listeners.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn global_listeners_receive_all_events() {
+    struct GlobalListener {
+        received: Vec<&'static str>,
+    }
+
+    impl Listener<Event> for GlobalListener {
+        fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> {
+            self.received.push(event_name(event));
+
+            None
+        }
+    }
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let global = Arc::new(Mutex::new(GlobalListener { received: vec![] }));
+    let mut dispatcher = EventDispatcher::<Event>::default();
+
+    dispatcher.add_global_listener(&global);
+
+    let fn_order = Arc::clone(&order);
+    dispatcher.add_global_fn(Box::new(move |_: &Event| {
+        fn_order.try_lock().unwrap().push("global");
+
+        Some(SyncDispatcherRequest::StopListening)
+    }));
+
+    let fn_order = Arc::clone(&order);
+    dispatcher.add_fn(
+        Event::VariantA,
+        Box::new(move |_: &Event| {
+            fn_order.try_lock().unwrap().push("specific");
+
+            None
+        }),
+    );
+
+    dispatcher.add_fn(
+        Event::VariantB,
+        Box::new(|_: &Event| Some(SyncDispatcherRequest::StopPropagation)),
+    );
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert_eq!(global.try_lock().unwrap().received, ["A", "A"]);
+    assert_eq!(
+        *order.try_lock().unwrap(),
+        ["specific", "global", "specific"]
+    );
+}
+
+#[test]
+fn unhandled_events_are_reported_and_kept() {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let listener = Arc::new(Mutex::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let mut dispatcher = EventDispatcher::<Event>::default();
+
+    let thread_reports = Arc::clone(&reports);
+    dispatcher.on_unhandled(move |event| {
+        thread_reports.try_lock().unwrap().push(event_name(event));
+    });
+    dispatcher.dead_letter_capacity(2);
+    dispatcher.add_listener(Event::VariantA, &listener);
+    dispatcher.add_global_fn(Box::new(|_: &Event| None));
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    drop(listener);
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(*reports.try_lock().unwrap(), ["B", "A", "B"]);
+
+    let dead_letters = dispatcher.take_dead_letters();
+    assert_eq!(
+        dead_letters.iter().map(event_name).collect::<Vec<_>>(),
+        ["A", "B"]
+    );
+    assert!(dispatcher.take_dead_letters().is_empty());
+}
+
+#[test]
+fn events_of_dropped_filtered_listeners_and_weak_fns_are_unhandled() {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let listener = Arc::new(Mutex::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let target = Arc::new(Mutex::new(0usize));
+    let mut dispatcher = EventDispatcher::<Event>::default();
+
+    let thread_reports = Arc::clone(&reports);
+    dispatcher.on_unhandled(move |event| {
+        thread_reports.try_lock().unwrap().push(event_name(event));
+    });
+    dispatcher.dead_letter_capacity(2);
+    dispatcher.add_listener_filtered(Event::VariantA, &listener, |_| true);
+    dispatcher.add_weak_fn(Event::VariantB, &target, |received: &mut usize, _: &Event| {
+        *received += 1;
+
+        None
+    });
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    drop(listener);
+    drop(target);
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(*reports.try_lock().unwrap(), ["A", "B"]);
+    assert_eq!(
+        dispatcher
+            .take_dead_letters()
+            .iter()
+            .map(event_name)
+            .collect::<Vec<_>>(),
+        ["A", "B"]
+    );
+}
+
+#[test]
+fn filtered_listeners_are_not_locked_for_rejected_events() {
+    struct CountingLock<D> {
+        lock_counter: AtomicUsize,
+        listener: Mutex<D>,
+    }
+
+    impl<D: Send + 'static> ListenerLock for CountingLock<D> {
+        type Listener = D;
+
+        fn with_listener<R, F: FnOnce(&mut D) -> R>(&self, function: F) -> R {
+            self.lock_counter.fetch_add(1, Ordering::SeqCst);
+
+            function(&mut self.listener.lock().unwrap())
+        }
+    }
+
+    let listener = Arc::new(CountingLock {
+        lock_counter: AtomicUsize::new(0),
+        listener: Mutex::new(EventListener {
+            received_variant_a: false,
+            received_variant_b: false,
+        }),
+    });
+    let accepting = Arc::new(AtomicBool::new(false));
+    let mut dispatcher = EventDispatcher::<Event>::default();
+
+    let predicate_accepting = Arc::clone(&accepting);
+    let id = dispatcher.add_listener_filtered(Event::VariantA, &listener, move |_| {
+        predicate_accepting.load(Ordering::SeqCst)
+    });
+
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    // Locked once for subscribing only.
+    assert_eq!(listener.lock_counter.load(Ordering::SeqCst), 1);
+    assert!(!listener.listener.lock().unwrap().received_variant_a);
+
+    accepting.store(true, Ordering::SeqCst);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    assert_eq!(listener.lock_counter.load(Ordering::SeqCst), 2);
+    assert!(listener.listener.lock().unwrap().received_variant_a);
+    assert!(dispatcher.remove_listener(id));
+}
+
+#[test]
+fn filtered_fns_and_dropped_filtered_listeners() {
+    let listener = Arc::new(Mutex::new(EventListener {
+        received_variant_a: false,
+        received_variant_b: false,
+    }));
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = EventDispatcher::<Event>::default();
+
+    let id = dispatcher.add_listener_filtered(Event::VariantA, &listener, |_| true);
+
+    let fn_counter = Arc::clone(&counter);
+    dispatcher.add_fn_filtered(
+        Event::VariantB,
+        Box::new(move |_: &Event| {
+            fn_counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        }),
+        |event| *event == Event::VariantA,
+    );
+
+    drop(listener);
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(counter.load(Ordering::SeqCst), 0);
+    assert!(!dispatcher.remove_listener(id));
+}
+
+#[test]
+fn rejected_events_are_not_dispatched() {
+    struct ReadWriteCountingLock<D> {
+        reads: AtomicUsize,
+        writes: AtomicUsize,
+        listener: RwLock<D>,
+    }
+
+    impl<D: Send + Sync + 'static> ListenerLock for ReadWriteCountingLock<D> {
+        type Listener = D;
+
+        fn with_listener<R, F: FnOnce(&mut D) -> R>(&self, function: F) -> R {
+            self.writes.fetch_add(1, Ordering::SeqCst);
+
+            function(&mut self.listener.write().unwrap())
+        }
+
+        fn with_listener_ref<R, F: FnOnce(&D) -> R>(&self, function: F) -> Option<R> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+
+            Some(function(&self.listener.read().unwrap()))
+        }
+    }
+
+    struct AcceptingListener {
+        received: Vec<&'static str>,
+    }
+
+    impl Listener<Event> for AcceptingListener {
+        fn on_event(&mut self, event: &Event) -> Option<SyncDispatcherRequest> {
+            self.received.push(event_name(event));
+
+            None
+        }
+
+        fn accepts(&self, event: &Event) -> bool {
+            *event == Event::VariantA
+        }
+    }
+
+    let listener = Arc::new(ReadWriteCountingLock {
+        reads: AtomicUsize::new(0),
+        writes: AtomicUsize::new(0),
+        listener: RwLock::new(AcceptingListener { received: vec![] }),
+    });
+    let mut dispatcher = EventDispatcher::<Event>::default();
+    dispatcher.add_listener_for(vec![Event::VariantA, Event::VariantB], &listener);
+    dispatcher.add_owned_listener(Event::VariantB, AcceptingListener { received: vec![] });
+    let subscription_writes = listener.writes.load(Ordering::SeqCst);
+
+    dispatcher.dispatch_event(&Event::VariantB);
+    assert_eq!(listener.writes.load(Ordering::SeqCst), subscription_writes);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    assert_eq!(listener.writes.load(Ordering::SeqCst), subscription_writes + 1);
+    assert_eq!(listener.reads.load(Ordering::SeqCst), 2);
+    assert_eq!(listener.listener.read().unwrap().received, ["A"]);
+}
+
+#[test]
+fn event_hierarchy_dispatches_to_ancestors() {
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Input {
+        Any,
+        Key,
+        KeyDown,
+        Mouse,
+    }
+
+    impl EventHierarchy for Input {
+        fn parent(&self) -> Option<Input> {
+            match *self {
+                Input::Any => None,
+                Input::Key | Input::Mouse => Some(Input::Any),
+                Input::KeyDown => Some(Input::Key),
+            }
+        }
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = EventDispatcher::<Input>::default();
+
+    dispatcher.use_event_hierarchy();
+    dispatcher.add_fn(Input::Any, recording(&record, "any", || None));
+    dispatcher.add_fn(Input::Key, recording(&record, "key", || None));
+    dispatcher.add_fn(Input::KeyDown, recording(&record, "key down", || None));
+    dispatcher.add_global_fn(recording(&record, "global", || None));
+
+    dispatcher.dispatch_event(&Input::KeyDown);
+    dispatcher.dispatch_event(&Input::Mouse);
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [
+            "key down KeyDown",
+            "key KeyDown",
+            "any KeyDown",
+            "global KeyDown",
+            "any Mouse",
+            "global Mouse",
+        ]
+    );
+
+    record.try_lock().unwrap().clear();
+    dispatcher.add_fn(
+        Input::Key,
+        Box::new(|_: &Input| Some(SyncDispatcherRequest::StopListeningAndPropagation)),
+    );
+
+    dispatcher.dispatch_event(&Input::KeyDown);
+    dispatcher.dispatch_event(&Input::Key);
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [
+            "key down KeyDown",
+            "key KeyDown",
+            "key Key",
+            "any Key",
+            "global Key",
+        ]
+    );
+}
+
+#[test]
+fn cyclic_event_hierarchies_are_cut_off() {
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Path {
+        Loop,
+        Deep(usize),
+    }
+
+    impl EventHierarchy for Path {
+        fn parent(&self) -> Option<Path> {
+            match *self {
+                Path::Loop => Some(Path::Loop),
+                Path::Deep(depth) => Some(Path::Deep(depth + 1)),
+            }
+        }
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = EventDispatcher::<Path>::default();
+
+    let reported = Arc::clone(&errors);
+    dispatcher.on_emit_error(move |path, error| {
+        reported.try_lock().unwrap().push((path.clone(), error.to_string()));
+    });
+    dispatcher.use_event_hierarchy();
+    dispatcher.max_dispatch_depth(2);
+    dispatcher.add_fn(Path::Loop, recording(&record, "loop", || None));
+    dispatcher.add_fn(Path::Deep(2), recording(&record, "deep 2", || None));
+    dispatcher.add_fn(Path::Deep(3), recording(&record, "deep 3", || None));
+
+    dispatcher.dispatch_event(&Path::Loop);
+    dispatcher.dispatch_event(&Path::Deep(0));
+
+    assert_eq!(*record.try_lock().unwrap(), ["loop Loop", "deep 2 Deep(0)"]);
+    assert_eq!(
+        *errors.try_lock().unwrap(),
+        [
+            (Path::Loop, DispatchError::Cycle.to_string()),
+            (Path::Deep(0), DispatchError::DepthExceeded(2).to_string()),
+        ]
+    );
+}
+
+#[test]
+fn range_listeners_receive_events_inside_their_range() {
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    struct Code(u16);
+
+    fn recording(
+        record: &Record,
+        name: &'static str,
+    ) -> RecordingFn<Code, SyncDispatcherRequest> {
+        let record = Arc::clone(record);
+
+        Box::new(move |code: &Code| {
+            record.try_lock().unwrap().push(format!("{} {}", name, code.0));
+
+            None
+        })
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = EventDispatcher::<Code>::default();
+
+    let unhandled_record = Arc::clone(&record);
+    dispatcher.on_unhandled(move |code| {
+        unhandled_record.try_lock().unwrap().push(format!("unhandled {}", code.0));
+    });
+    dispatcher.add_fn_range(Code(200).., recording(&record, "200.."));
+    dispatcher.add_fn_range(Code(150)..=Code(200), recording(&record, "150..=200"));
+    dispatcher.add_fn_range(Code(100)..Code(200), recording(&record, "100..200"));
+    dispatcher.add_fn_range(..Code(150), recording(&record, "..150"));
+    dispatcher.add_fn(Code(150), recording(&record, "150"));
+    dispatcher.add_global_fn(recording(&record, "global"));
+
+    dispatcher.dispatch_event(&Code(150));
+    dispatcher.dispatch_event(&Code(200));
+    dispatcher.dispatch_event(&Code(50));
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [
+            "150 150",
+            "100..200 150",
+            "150..=200 150",
+            "global 150",
+            "150..=200 200",
+            "200.. 200",
+            "global 200",
+            "..150 50",
+            "global 50",
+        ]
+    );
+}
+
+#[test]
+fn range_listeners_stop_listening_and_propagation() {
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    struct Code(u16);
+
+    struct OneShotListener {
+        received: Vec<Code>,
+    }
+
+    impl Listener<Code> for OneShotListener {
+        fn on_event(&mut self, code: &Code) -> Option<SyncDispatcherRequest> {
+            self.received.push(code.clone());
+
+            Some(SyncDispatcherRequest::StopListeningAndPropagation)
+        }
+    }
+
+    let listener = Arc::new(Mutex::new(OneShotListener { received: vec![] }));
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut dispatcher = EventDispatcher::<Code>::default();
+
+    dispatcher.add_listener_range(Code(0)..Code(10), &listener);
+
+    let fn_counter = Arc::clone(&counter);
+    dispatcher.add_fn_range(
+        Code(5)..,
+        Box::new(move |_: &Code| {
+            fn_counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        }),
+    );
+
+    dispatcher.dispatch_event(&Code(5));
+    dispatcher.dispatch_event(&Code(6));
+    dispatcher.dispatch_event(&Code(20));
+
+    assert_eq!(listener.try_lock().unwrap().received, [Code(5)]);
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn range_listeners_are_removable_and_notified_about_their_lifecycle() {
+    #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    struct Code(u16);
+
+    struct RangeListener {
+        record: Vec<String>,
+    }
+
+    impl Listener<Code> for RangeListener {
+        fn on_event(&mut self, code: &Code) -> Option<SyncDispatcherRequest> {
+            self.record.push(format!("event {}", code.0));
+
+            None
+        }
+
+        fn on_subscribe(&mut self, code: &Code) {
+            self.record.push(format!("subscribed {}", code.0));
+        }
+
+        fn on_unsubscribe(&mut self, code: &Code, reason: UnsubscribeReason) {
+            self.record.push(format!("unsubscribed {} {:?}", code.0, reason));
+        }
+    }
+
+    let listener = Arc::new(Mutex::new(RangeListener { record: vec![] }));
+    let mut dispatcher = EventDispatcher::<Code>::default();
+
+    let bounded = dispatcher.add_listener_range(Code(100)..Code(200), &listener);
+    let unbounded = dispatcher.add_listener_range(..Code(150), &listener);
+
+    dispatcher.dispatch_event(&Code(120));
+    assert!(dispatcher.remove_listener(bounded));
+    assert!(dispatcher.remove_listener(unbounded));
+    assert!(!dispatcher.remove_listener(bounded));
+    dispatcher.dispatch_event(&Code(120));
+
+    assert_eq!(
+        listener.try_lock().unwrap().record,
+        [
+            "subscribed 100",
+            "event 120",
+            "event 120",
+            "unsubscribed 100 Removed",
+        ]
+    );
+}
+
+#[test]
+fn envelopes_carry_metadata_and_propagate_correlation() {
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Order {
+        Placed,
+        Paid,
+        Shipped,
+    }
+
+    struct AuditListener {
+        envelopes: Vec<Envelope<Order>>,
+    }
+
+    impl EnvelopeListener<Order> for AuditListener {
+        fn on_envelope(
+            &mut self,
+            envelope: &Envelope<Order>,
+        ) -> Option<SyncDispatcherRequest> {
+            self.envelopes.push(envelope.clone());
+
+            None
+        }
+    }
+
+    let listener = Arc::new(Mutex::new(AuditListener { envelopes: vec![] }));
+    let mut dispatcher = EventDispatcher::<Order>::default();
+
+    dispatcher.clock(|| UNIX_EPOCH + Duration::from_secs(42));
+    dispatcher.source_id("shop");
+    dispatcher.add_context_fn(
+        Order::Placed,
+        Box::new(|_: &Order, context: &mut DispatchContext<Order>| {
+            context.enqueue(Order::Shipped).unwrap();
+            context.dispatch(Order::Paid).unwrap();
+
+            None
+        }),
+    );
+
+    for order in &[Order::Placed, Order::Paid, Order::Shipped] {
+        dispatcher.add_envelope_listener(order.clone(), &listener);
+    }
+
+    dispatcher.dispatch_event(&Order::Placed);
+    dispatcher.dispatch_correlated(&Order::Paid, CorrelationId::from(7));
+
+    let envelopes = &listener.try_lock().unwrap().envelopes;
+    let received: Vec<_> = envelopes
+        .iter()
+        .map(|envelope| (envelope.event().clone(), envelope.sequence()))
+        .collect();
+
+    assert_eq!(
+        received,
+        [
+            (Order::Placed, 0),
+            (Order::Paid, 1),
+            (Order::Shipped, 2),
+            (Order::Paid, 3),
+        ]
+    );
+    assert!(envelopes.iter().all(|envelope| envelope.source_id() == Some("shop")));
+    assert!(
+        envelopes
+            .iter()
+            .all(|envelope| envelope.timestamp() == UNIX_EPOCH + Duration::from_secs(42))
+    );
+    assert_eq!(envelopes[0].correlation_id(), envelopes[1].correlation_id());
+    assert_eq!(envelopes[0].correlation_id(), envelopes[2].correlation_id());
+    assert_eq!(envelopes[3].correlation_id(), CorrelationId::from(7));
+}
+
+#[test]
+fn envelope_fns_and_dropped_envelope_listeners() {
+    struct SilentListener;
+
+    impl EnvelopeListener<Event> for SilentListener {
+        fn on_envelope(&mut self, _: &Envelope<Event>) -> Option<SyncDispatcherRequest> {
+            None
+        }
+    }
+
+    let sequences = Arc::new(Mutex::new(Vec::new()));
+    let listener = Arc::new(Mutex::new(SilentListener));
+    let mut dispatcher = EventDispatcher::<Event>::default();
+
+    let fn_sequences = Arc::clone(&sequences);
+    dispatcher.add_envelope_fn(
+        Event::VariantA,
+        Box::new(move |envelope: &Envelope<Event>| {
+            fn_sequences.try_lock().unwrap().push(envelope.sequence());
+
+            None
+        }),
+    );
+    let id = dispatcher.add_envelope_listener(Event::VariantB, &listener);
+
+    dispatcher.dispatch_event(&Event::VariantA);
+    dispatcher.dispatch_event(&Event::VariantB);
+    dispatcher.dispatch_event(&Event::VariantA);
+
+    drop(listener);
+    dispatcher.dispatch_event(&Event::VariantB);
+
+    assert_eq!(*sequences.try_lock().unwrap(), [0, 2]);
+    assert!(!dispatcher.remove_listener(id));
+}
+
+#[test]
+fn trace_records_causal_tree_of_follow_ups() {
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Storm {
+        Save,
+        Validate,
+        Persist,
+        Notify,
+    }
+
+    struct ValidatingListener;
+
+    impl Listener<Storm, ListenerRequest<Storm>> for ValidatingListener {
+        fn on_event(&mut self, _: &Storm) -> Option<ListenerRequest<Storm>> {
+            None
+        }
+
+        fn on_event_with_context(
+            &mut self,
+            _: &Storm,
+            context: &mut DispatchContext<Storm>,
+        ) -> Option<ListenerRequest<Storm>> {
+            context.dispatch(Storm::Validate).unwrap();
+            context.enqueue(Storm::Persist).unwrap();
+
+            None
+        }
+    }
+
+    struct NotifyingListener;
+
+    impl Listener<Storm, ListenerRequest<Storm>> for NotifyingListener {
+        fn on_event(&mut self, _: &Storm) -> Option<ListenerRequest<Storm>> {
+            Some(ListenerRequest::Emit(Storm::Notify))
+        }
+    }
+
+    let listener = Arc::new(Mutex::new(ValidatingListener));
+    let mut dispatcher = EventDispatcher::<Storm, ListenerRequest<Storm>>::default();
+
+    assert!(dispatcher.take_trace().events().is_empty());
+    dispatcher.enable_tracing();
+    dispatcher.add_listener(Storm::Save, &listener);
+    let id = dispatcher.add_owned_listener(Storm::Persist, NotifyingListener);
+    dispatcher.add_fn(Storm::Validate, Box::new(|_: &Storm| None));
+
+    dispatcher.dispatch_event(&Storm::Save);
+
+    let trace = dispatcher.take_trace();
+    let events: Vec<_> = trace.events().iter().map(|traced| traced.event().clone()).collect();
+    assert_eq!(
+        events,
+        [Storm::Save, Storm::Validate, Storm::Persist, Storm::Notify]
+    );
+
+    let save = trace.roots().next().unwrap();
+    assert_eq!(trace.roots().count(), 1);
+    assert_eq!(save.listeners().len(), 1);
+    assert!(save.listeners()[0].is(&listener));
+
+    let follow_ups: Vec<_> = trace.follow_ups(save.id()).collect();
+    assert_eq!(follow_ups.len(), 2);
+    assert_eq!(follow_ups[0].listeners(), [TracedListener::Fn]);
+    assert!(follow_ups[1].caused_by().unwrap().1.is(&listener));
+    assert_eq!(follow_ups[1].listeners(), [TracedListener::Owned(id)]);
+
+    let notify = trace.follow_ups(follow_ups[1].id()).next().unwrap();
+    assert_eq!(notify.event(), &Storm::Notify);
+    assert_eq!(
+        notify.caused_by(),
+        Some((follow_ups[1].id(), TracedListener::Owned(id)))
+    );
+    assert!(notify.listeners().is_empty());
+
+    let dot = trace.to_dot();
+    assert!(dot.starts_with("digraph trace {\n    0 [label=\"Save\"];\n"));
+    assert!(dot.contains(&format!("    2 -> 3 [label=\"Owned({:?})\"];\n", id)));
+
+    dispatcher.dispatch_event(&Storm::Validate);
+    let trace = dispatcher.take_trace();
+    assert_eq!(trace.events().len(), 1);
+    assert_eq!(trace.events()[0].id(), 4);
+}
+
+#[test]
+fn vetoed_events_are_not_dispatched() {
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum Document {
+        Any,
+        Close,
+        Save,
+    }
+
+    impl EventHierarchy for Document {
+        fn parent(&self) -> Option<Document> {
+            match *self {
+                Document::Any => None,
+                Document::Close | Document::Save => Some(Document::Any),
+            }
+        }
+    }
+
+    struct ReadOnlyGuard {
+        read_only: bool,
+        asked: usize,
+    }
+
+    impl VetoListener<Document> for ReadOnlyGuard {
+        fn before_event(&mut self, _: &Document) -> Result<(), Veto> {
+            self.asked += 1;
+
+            if self.read_only {
+                Err(Veto::new("read-only"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    let guard = Arc::new(Mutex::new(ReadOnlyGuard {
+        read_only: false,
+        asked: 0,
+    }));
+    let closed = Arc::new(AtomicUsize::new(0));
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher = EventDispatcher::<Document>::default();
+    dispatcher.use_event_hierarchy();
+
+    let dead_reports = Arc::clone(&reports);
+    dispatcher.on_dead_listener(move |dead| {
+        dead_reports
+            .try_lock()
+            .unwrap()
+            .push((dead.event().clone(), dead.missed_dispatch()));
+    });
+
+    dispatcher.add_veto_fn(
+        Document::Close,
+        Box::new(|_: &Document| Err(Veto::new("unsaved changes"))),
+    );
+    dispatcher.add_veto_listener(Document::Any, &guard);
+    let counter = Arc::clone(&closed);
+    dispatcher.add_fn(
+        Document::Close,
+        Box::new(move |_: &Document| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            None
+        }),
+    );
+
+    assert_eq!(
+        dispatcher.dispatch_cancellable(&Document::Close),
+        Err(Veto::new("unsaved changes"))
+    );
+    assert_eq!(guard.try_lock().unwrap().asked, 0);
+    assert_eq!(closed.load(Ordering::SeqCst), 0);
+
+    assert_eq!(dispatcher.dispatch_cancellable(&Document::Save), Ok(()));
+    guard.try_lock().unwrap().read_only = true;
+    let veto = dispatcher.dispatch_cancellable(&Document::Save).unwrap_err();
+    assert_eq!(veto.reason(), "read-only");
+    assert_eq!(guard.try_lock().unwrap().asked, 2);
+
+    dispatcher.dispatch_event(&Document::Close);
+    assert_eq!(closed.load(Ordering::SeqCst), 1);
+
+    drop(guard);
+    assert_eq!(dispatcher.dispatch_cancellable(&Document::Save), Ok(()));
+    assert_eq!(dispatcher.dispatch_cancellable(&Document::Save), Ok(()));
+    assert_eq!(*reports.try_lock().unwrap(), [(Document::Any, true)]);
+}
@@ -0,0 +1,269 @@
+extern crate hey_listen;
+
+use hey_listen::{DispatchContext, FilterError, Listener, SyncDispatcherRequest,
+                 TopicDispatcher, UnsubscribeReason};
+use std::sync::{Arc, Mutex};
+
+type Record = Arc<Mutex<Vec<String>>>;
+type RecordingFn = Box<Fn(&String) -> Option<SyncDispatcherRequest> + Send + Sync>;
+
+fn recording(
+    record: &Record,
+    name: &'static str,
+) -> RecordingFn {
+    let record = Arc::clone(record);
+
+    Box::new(move |topic: &String| {
+        record.try_lock().unwrap().push(format!("{} {}", name, topic));
+
+        None
+    })
+}
+
+#[test]
+fn wildcards_match_levels_of_topics() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher: TopicDispatcher = TopicDispatcher::default();
+
+    dispatcher.add_fn("#", recording(&record, "#")).unwrap();
+    dispatcher.add_fn("sensor/#", recording(&record, "sensor/#")).unwrap();
+    dispatcher.add_fn("sensor/+/temp", recording(&record, "sensor/+/temp")).unwrap();
+    dispatcher.add_fn("sensor/42/temp", recording(&record, "sensor/42/temp")).unwrap();
+    dispatcher.add_fn("sensor/+", recording(&record, "sensor/+")).unwrap();
+    dispatcher.add_fn("other/#", recording(&record, "other/#")).unwrap();
+
+    dispatcher.dispatch_event("sensor/42/temp");
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [
+            "sensor/42/temp sensor/42/temp",
+            "sensor/+/temp sensor/42/temp",
+            "sensor/# sensor/42/temp",
+            "# sensor/42/temp",
+        ]
+    );
+
+    record.try_lock().unwrap().clear();
+    dispatcher.dispatch_event("sensor");
+    dispatcher.dispatch_event("sensor/7");
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [
+            "sensor/# sensor",
+            "# sensor",
+            "sensor/+ sensor/7",
+            "sensor/# sensor/7",
+            "# sensor/7",
+        ]
+    );
+}
+
+#[test]
+fn stop_listening_and_propagation_across_filters() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher: TopicDispatcher = TopicDispatcher::default();
+
+    dispatcher
+        .add_fn(
+            "sensor/+/temp",
+            Box::new(|_: &String| Some(SyncDispatcherRequest::StopListeningAndPropagation)),
+        )
+        .unwrap();
+    dispatcher.add_fn("sensor/#", recording(&record, "sensor/#")).unwrap();
+
+    dispatcher.dispatch_event("sensor/1/temp");
+    dispatcher.dispatch_event("sensor/2/temp");
+
+    assert_eq!(*record.try_lock().unwrap(), ["sensor/# sensor/2/temp"]);
+}
+
+#[test]
+fn listeners_receive_topics_and_follow_ups() {
+    struct TopicListener {
+        received: Vec<String>,
+        unsubscribed_from: Option<String>,
+    }
+
+    impl Listener<String> for TopicListener {
+        fn on_event(&mut self, topic: &String) -> Option<SyncDispatcherRequest> {
+            self.received.push(topic.clone());
+
+            if self.received.len() == 2 {
+                Some(SyncDispatcherRequest::StopListening)
+            } else {
+                None
+            }
+        }
+
+        fn on_unsubscribe(&mut self, filter: &String, reason: UnsubscribeReason) {
+            assert_eq!(reason, UnsubscribeReason::StopListening);
+            self.unsubscribed_from = Some(filter.clone());
+        }
+    }
+
+    let listener = Arc::new(Mutex::new(TopicListener {
+        received: vec![],
+        unsubscribed_from: None,
+    }));
+    let mut dispatcher: TopicDispatcher = TopicDispatcher::default();
+
+    dispatcher.add_listener("alarm/+", &listener).unwrap();
+    dispatcher
+        .add_context_fn(
+            "sensor/+/temp",
+            Box::new(|topic: &String, context: &mut DispatchContext<String>| {
+                let sensor = topic.split('/').nth(1).unwrap();
+                context
+                    .dispatch(format!("alarm/{}", sensor))
+                    .expect("Alarms cause no cycle.");
+
+                None
+            }),
+        )
+        .unwrap();
+
+    dispatcher.dispatch_event("sensor/1/temp");
+    dispatcher.dispatch_event("sensor/2/temp");
+    dispatcher.dispatch_event("alarm/3");
+
+    let listener = listener.try_lock().unwrap();
+    assert_eq!(listener.received, ["alarm/1", "alarm/2"]);
+    assert_eq!(listener.unsubscribed_from, Some("alarm/+".to_string()));
+}
+
+#[test]
+fn nested_dispatches_reach_listeners_of_matching_filters() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher: TopicDispatcher = TopicDispatcher::default();
+
+    let forwarding_record = Arc::clone(&record);
+    dispatcher
+        .add_context_fn(
+            "sensor/+",
+            Box::new(move |topic: &String, context: &mut DispatchContext<String>| {
+                forwarding_record
+                    .try_lock()
+                    .unwrap()
+                    .push(format!("sensor/+ {}", topic));
+
+                if topic == "sensor/1" {
+                    context.dispatch("sensor/2".to_string()).unwrap();
+                }
+
+                None
+            }),
+        )
+        .unwrap();
+    dispatcher.add_fn("sensor/#", recording(&record, "sensor/#")).unwrap();
+
+    dispatcher.dispatch_event("sensor/1");
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        [
+            "sensor/+ sensor/1",
+            "sensor/+ sensor/2",
+            "sensor/# sensor/2",
+            "sensor/# sensor/1",
+        ]
+    );
+}
+
+#[test]
+fn remove_owned_listener_by_id() {
+    struct CountingListener {
+        counter: Arc<Mutex<usize>>,
+    }
+
+    impl Listener<String> for CountingListener {
+        fn on_event(&mut self, _: &String) -> Option<SyncDispatcherRequest> {
+            *self.counter.try_lock().unwrap() += 1;
+
+            None
+        }
+    }
+
+    let counter = Arc::new(Mutex::new(0));
+    let mut dispatcher: TopicDispatcher = TopicDispatcher::default();
+
+    let id = dispatcher
+        .add_owned_listener(
+            "sensor/#",
+            CountingListener {
+                counter: Arc::clone(&counter),
+            },
+        )
+        .unwrap();
+
+    dispatcher.dispatch_event("sensor/1/temp");
+    assert!(dispatcher.remove_listener(id));
+    assert!(!dispatcher.remove_listener(id));
+    dispatcher.dispatch_event("sensor/1/temp");
+
+    assert_eq!(*counter.try_lock().unwrap(), 1);
+}
+
+#[test]
+fn dropped_listeners_are_reported_with_their_filter() {
+    struct SilentListener;
+
+    impl Listener<String> for SilentListener {
+        fn on_event(&mut self, _: &String) -> Option<SyncDispatcherRequest> {
+            None
+        }
+    }
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let listener = Arc::new(Mutex::new(SilentListener));
+    let mut dispatcher: TopicDispatcher = TopicDispatcher::default();
+
+    let thread_reports = Arc::clone(&reports);
+    dispatcher.on_dead_listener(move |dead| {
+        thread_reports.try_lock().unwrap().push(dead.event().clone());
+    });
+    dispatcher.add_listener("sensor/+", &listener).unwrap();
+
+    drop(listener);
+    dispatcher.dispatch_event("sensor/1");
+    dispatcher.dispatch_event("sensor/2");
+
+    assert_eq!(*reports.try_lock().unwrap(), ["sensor/+"]);
+}
+
+#[test]
+fn misplaced_wildcards_are_rejected() {
+    let mut dispatcher: TopicDispatcher = TopicDispatcher::default();
+
+    assert_eq!(
+        dispatcher.add_fn("sensor/#/temp", Box::new(|_: &String| None)),
+        Err(FilterError::MultiLevelWildcardNotLast("sensor/#/temp".to_string()))
+    );
+    assert_eq!(
+        dispatcher.add_fn("sensor+/temp", Box::new(|_: &String| None)),
+        Err(FilterError::WildcardWithinLevel("sensor+/temp".to_string()))
+    );
+    assert!(dispatcher.add_fn("+/#", Box::new(|_: &String| None)).is_ok());
+}
+
+#[test]
+fn asterisks_alias_single_level_wildcards() {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let mut dispatcher: TopicDispatcher = TopicDispatcher::default();
+
+    dispatcher.add_fn("sensor/*/temp", recording(&record, "sensor/*/temp")).unwrap();
+    dispatcher.add_fn("sensor/+/temp", recording(&record, "sensor/+/temp")).unwrap();
+
+    dispatcher.dispatch_event("sensor/42/temp");
+    dispatcher.dispatch_event("sensor/42");
+
+    assert_eq!(
+        *record.try_lock().unwrap(),
+        ["sensor/+/temp sensor/42/temp", "sensor/*/temp sensor/42/temp"]
+    );
+    assert_eq!(
+        dispatcher.add_fn("sensor*/temp", Box::new(|_: &String| None)),
+        Err(FilterError::WildcardWithinLevel("sensor*/temp".to_string()))
+    );
+}